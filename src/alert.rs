@@ -0,0 +1,323 @@
+use crate::check::{parse, Condition};
+use crate::config::*;
+use crate::util::{http_post, split_http_url};
+use crate::view::View;
+use crate::Opt;
+use anyhow::{anyhow, bail, Error};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::io::Write;
+use std::process::Stdio;
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Count of notifications waiting for the worker, more of them are dropped
+const QUEUE_LEN: usize = 64;
+
+/// Count of processes shown in the body of a desktop notification
+const DESKTOP_PROCESSES: usize = 5;
+
+#[derive(Clone)]
+struct Notify {
+    webhook: Option<(String, String)>,
+    exec: Option<String>,
+    desktop: bool,
+}
+
+struct Rule {
+    name: String,
+    condition: Condition,
+    notify: Notify,
+}
+
+/// Evaluates `[[alerts]]` rules on each update of watch mode, and notifies processes matching them.
+///
+/// A process is notified once when it starts to match a rule, and again only after it stops matching.
+/// Processes which start to match a rule at the same update are notified together.
+/// Notifications are sent by a background worker, and the last error is kept to be reported at exit.
+pub struct Alerter {
+    rules: Vec<Rule>,
+    firing: HashSet<(usize, i32)>,
+    sender: SyncSender<(Notify, String, Value)>,
+    error: Arc<Mutex<Option<String>>>,
+}
+
+impl Alerter {
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        let mut rules = Vec::new();
+        for alert in &config.alerts {
+            let condition = parse(&alert.condition)?;
+            if let Condition::Compare { field, .. } = &condition {
+                if field.eq_ignore_ascii_case("count") {
+                    bail!(
+                        "count can't be used by alerts because they are evaluated for each process: {}",
+                        alert.condition
+                    );
+                }
+            }
+            let webhook = match &alert.notify.webhook {
+                Some(x) => Some(split_http_url(x).map_err(|e| {
+                    anyhow!("invalid webhook of alert \"{}\": {e}", alert.condition)
+                })?),
+                None => None,
+            };
+            if webhook.is_none() && alert.notify.exec.is_none() && !alert.notify.desktop {
+                bail!("alert \"{}\" has no notify target", alert.condition);
+            }
+            rules.push(Rule {
+                name: alert.condition.clone(),
+                condition,
+                notify: Notify {
+                    webhook,
+                    exec: alert.notify.exec.clone(),
+                    desktop: alert.notify.desktop,
+                },
+            });
+        }
+
+        let (sender, receiver) = mpsc::sync_channel::<(Notify, String, Value)>(QUEUE_LEN);
+        let error = Arc::new(Mutex::new(None));
+        let worker_error = error.clone();
+        thread::spawn(move || {
+            for (notify, name, payload) in receiver {
+                if let Err(e) = send(&notify, &name, &payload) {
+                    *worker_error.lock().unwrap() = Some(e.to_string());
+                }
+            }
+        });
+
+        Ok(Self {
+            rules,
+            firing: HashSet::new(),
+            sender,
+            error,
+        })
+    }
+
+    /// Evaluates the rules, which changes `visible_pids` of the view, so it must be called before `View::filter`.
+    pub fn evaluate(&mut self, view: &mut View, opt: &Opt, config: &Config) -> Result<(), Error> {
+        let mut opt = opt.clone();
+        let mut firing = HashSet::new();
+        for (i, rule) in self.rules.iter().enumerate() {
+            let keyword = match &rule.condition {
+                Condition::Exists(x) => x,
+                Condition::Compare { keyword, .. } => keyword,
+            };
+            opt.keyword = keyword.split_whitespace().map(String::from).collect();
+            view.filter(&opt, config);
+            view.fit_width(config);
+
+            let mut matches = Vec::new();
+            for pid in &view.visible_pids {
+                let value = match &rule.condition {
+                    Condition::Exists(_) => None,
+                    Condition::Compare {
+                        field,
+                        op,
+                        threshold,
+                        ..
+                    } => {
+                        let column = view.find_column(config, field).ok_or_else(|| {
+                            anyhow!("column \"{field}\" of alert is not found in columns, it can be added by --insert")
+                        })?;
+                        match column.column.raw_value(*pid) {
+                            Some(x) if op.eval(x, *threshold) => Some(x),
+                            _ => continue,
+                        }
+                    }
+                };
+
+                firing.insert((i, *pid));
+                if !self.firing.contains(&(i, *pid)) {
+                    matches.push(json!({
+                        "value": value,
+                        "process": view.row_json(*pid),
+                    }));
+                }
+            }
+            if !matches.is_empty() {
+                let payload = json!({
+                    "alert": rule.name,
+                    "matches": matches,
+                });
+                self.notify(rule, payload);
+            }
+        }
+        self.firing = firing;
+        Ok(())
+    }
+
+    fn notify(&self, rule: &Rule, payload: Value) {
+        match self
+            .sender
+            .try_send((rule.notify.clone(), rule.name.clone(), payload))
+        {
+            Ok(()) => (),
+            Err(TrySendError::Full(_)) => {
+                *self.error.lock().unwrap() = Some(String::from(
+                    "alert notifications were dropped because previous ones were not finished",
+                ));
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                *self.error.lock().unwrap() =
+                    Some(String::from("alert notification worker stopped"));
+            }
+        }
+    }
+
+    pub fn last_error(&self) -> Option<String> {
+        self.error.lock().unwrap().clone()
+    }
+}
+
+/// Sends a notification to the targets of the rule, and returns the last error.
+fn send(notify: &Notify, name: &str, payload: &Value) -> Result<(), Error> {
+    let mut ret = Ok(());
+    if notify.desktop {
+        let summary = format!("procs: {name}");
+        let matches = payload["matches"].as_array().map_or(&[][..], |x| x);
+        let mut lines: Vec<String> = matches
+            .iter()
+            .take(DESKTOP_PROCESSES)
+            .map(|x| {
+                let process = &x["process"];
+                let mut line = format!("PID {}", process["pid"]);
+                if let Some(x) = process["columns"]["Command"].as_str() {
+                    line.push_str(&format!(" {x}"));
+                }
+                if let Some(x) = x["value"].as_f64() {
+                    line.push_str(&format!(" ( {x} )"));
+                }
+                line
+            })
+            .collect();
+        if matches.len() > DESKTOP_PROCESSES {
+            lines.push(format!("and {} more", matches.len() - DESKTOP_PROCESSES));
+        }
+        if let Err(e) = desktop(&summary, &lines.join("\n")) {
+            ret = Err(anyhow!("failed to show desktop notification: {e}"));
+        }
+    }
+
+    let body = Value::to_string(payload);
+    if let Some((origin, path)) = &notify.webhook {
+        if let Err(e) = http_post(origin, path, &body) {
+            ret = Err(e);
+        }
+    }
+    if let Some(cmd) = &notify.exec {
+        if let Err(e) = exec(cmd, &body) {
+            ret = Err(anyhow!("failed to execute alert command: {e}"));
+        }
+    }
+    ret
+}
+
+/// Executes the command by shell with the payload as a line of stdin.
+fn exec(cmd: &str, body: &str) -> Result<(), Error> {
+    #[cfg(not(target_os = "windows"))]
+    let mut command = std::process::Command::new("sh");
+    #[cfg(not(target_os = "windows"))]
+    command.arg("-c").arg(cmd);
+    #[cfg(target_os = "windows")]
+    let mut command = std::process::Command::new("cmd");
+    #[cfg(target_os = "windows")]
+    command.arg("/C").arg(cmd);
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The command may exit without reading stdin
+        let _ = writeln!(stdin, "{body}");
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("{cmd}: {status}");
+    }
+    Ok(())
+}
+
+/// Shows a notification by D-Bus ( org.freedesktop.Notifications ).
+#[cfg(all(any(target_os = "linux", target_os = "android"), feature = "dbus"))]
+fn desktop(summary: &str, body: &str) -> Result<(), Error> {
+    use std::collections::HashMap;
+    use zbus::zvariant::Value;
+
+    let conn = zbus::blocking::Connection::session()?;
+    let actions: Vec<&str> = Vec::new();
+    let hints: HashMap<&str, Value> = HashMap::new();
+    conn.call_method(
+        Some("org.freedesktop.Notifications"),
+        "/org/freedesktop/Notifications",
+        Some("org.freedesktop.Notifications"),
+        "Notify",
+        &("procs", 0u32, "", summary, body, actions, hints, -1i32),
+    )?;
+    Ok(())
+}
+
+/// Shows a notification by `notify-send` of libnotify, because D-Bus is disabled at build time.
+#[cfg(not(any(
+    all(any(target_os = "linux", target_os = "android"), feature = "dbus"),
+    target_os = "macos",
+    target_os = "windows"
+)))]
+fn desktop(summary: &str, body: &str) -> Result<(), Error> {
+    let status = std::process::Command::new("notify-send")
+        .arg("--app-name=procs")
+        .arg(summary)
+        .arg(body)
+        .status()?;
+    if !status.success() {
+        bail!("notify-send: {status}");
+    }
+    Ok(())
+}
+
+/// Shows a notification of Notification Center through AppleScript.
+#[cfg(target_os = "macos")]
+fn desktop(summary: &str, body: &str) -> Result<(), Error> {
+    // Strings are passed as arguments to avoid escaping in the script
+    let status = std::process::Command::new("osascript")
+        .arg("-e")
+        .arg("on run argv\ndisplay notification (item 2 of argv) with title (item 1 of argv)\nend run")
+        .arg(summary)
+        .arg(body)
+        .status()?;
+    if !status.success() {
+        bail!("osascript: {status}");
+    }
+    Ok(())
+}
+
+/// Shows a toast notification through WinRT API of PowerShell.
+#[cfg(target_os = "windows")]
+fn desktop(summary: &str, body: &str) -> Result<(), Error> {
+    // Strings are passed by environment variables to avoid escaping in the script
+    let script = r#"
+[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] | Out-Null
+$template = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02)
+$text = $template.GetElementsByTagName("text")
+$text.Item(0).AppendChild($template.CreateTextNode($env:PROCS_SUMMARY)) | Out-Null
+$text.Item(1).AppendChild($template.CreateTextNode($env:PROCS_BODY)) | Out-Null
+$toast = [Windows.UI.Notifications.ToastNotification]::new($template)
+[Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier("procs").Show($toast)
+"#;
+    let status = std::process::Command::new("powershell")
+        .arg("-NoProfile")
+        .arg("-NonInteractive")
+        .arg("-Command")
+        .arg(script)
+        .env("PROCS_SUMMARY", summary)
+        .env("PROCS_BODY", body)
+        .stdout(Stdio::null())
+        .status()?;
+    if !status.success() {
+        bail!("powershell: {status}");
+    }
+    Ok(())
+}
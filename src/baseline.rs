@@ -0,0 +1,281 @@
+use crate::columns::identity::identity;
+use crate::process::{boot_time, ProcessInfo, ProcessTask};
+use anyhow::{anyhow, Error};
+use once_cell::sync::OnceCell;
+use procfs::WithCurrentSystemInfo;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+static BASELINE: OnceCell<Baseline> = OnceCell::new();
+
+/// A snapshot of processes which current processes are compared with.
+///
+/// Each line of the baseline file is `<pid>\t<start time>\t<rss>\t<cpu time>\t<identity>\t<command>`,
+/// where the start time is in seconds since the epoch, RSS is in bytes, and CPU time is in milliseconds.
+pub struct Baseline {
+    entries: Vec<Entry>,
+    /// Indices of entries by PID and start time
+    by_start: HashMap<(i32, u64), usize>,
+    /// Indices of entries by identity in order of the file
+    by_identity: HashMap<String, Vec<usize>>,
+}
+
+pub struct Entry {
+    pub pid: i32,
+    pub start: u64,
+    pub rss: u64,
+    pub cpu_time: u64,
+    pub identity: String,
+    pub command: String,
+}
+
+/// A current process to be compared with entries.
+pub struct Sample {
+    pub pid: i32,
+    pub start: u64,
+    pub rss: u64,
+    pub cpu_time: u64,
+    identity: Option<String>,
+}
+
+/// How a current process corresponds to the baseline.
+pub enum Match<'a> {
+    /// The same process ( same PID and start time )
+    Same(&'a Entry),
+    /// Another instance of the same identity ( ex. restarted by deployment )
+    Restarted(&'a Entry),
+    New,
+}
+
+/// Result of matching current processes with the baseline.
+pub struct Comparison<'a> {
+    /// Matches by PID of current processes
+    pub matches: HashMap<i32, Match<'a>>,
+    /// Entries which are not matched with any current process
+    pub gone: Vec<&'a Entry>,
+}
+
+/// Loads the baseline file once, which is shared by columns and views of watch mode.
+pub fn load(path: &Path) -> Result<&'static Baseline, Error> {
+    BASELINE.get_or_try_init(|| Baseline::load(path))
+}
+
+pub fn get() -> Option<&'static Baseline> {
+    BASELINE.get()
+}
+
+/// Records processes as a baseline, and threads are skipped because they share the memory of the process.
+pub fn save(path: &Path, proc: &[ProcessInfo]) -> Result<(), Error> {
+    let boot_time = boot_time_secs();
+    let mut file = io::BufWriter::new(fs::File::create(path)?);
+    for p in proc {
+        if let ProcessTask::Process { .. } = p.curr_proc {
+            let e = Entry::new(p, boot_time);
+            writeln!(
+                file,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                e.pid, e.start, e.rss, e.cpu_time, e.identity, e.command
+            )?;
+        }
+    }
+    file.flush()?;
+    Ok(())
+}
+
+impl Entry {
+    fn new(proc: &ProcessInfo, boot_time: u64) -> Self {
+        let command = proc.curr_proc.cmdline().unwrap_or_default().join(" ");
+        let command = if command.is_empty() {
+            format!("[{}]", proc.curr_proc.stat().comm)
+        } else {
+            command
+        };
+        let (rss, cpu_time) = usage(proc);
+        Self {
+            pid: proc.pid,
+            start: start_time(proc, boot_time),
+            rss,
+            cpu_time,
+            identity: identity(proc),
+            // Tabs and newlines would break the line format
+            command: command.replace(['\t', '\n'], " "),
+        }
+    }
+}
+
+/// RSS in bytes and CPU time in milliseconds.
+fn usage(proc: &ProcessInfo) -> (u64, u64) {
+    let stat = proc.curr_proc.stat();
+    let cpu_time = (stat.utime + stat.stime) * 1000 / procfs::ticks_per_second();
+    (stat.rss_bytes().get(), cpu_time)
+}
+
+/// Start time in seconds since the epoch, which is comparable across runs unlike ticks since boot.
+fn start_time(proc: &ProcessInfo, boot_time: u64) -> u64 {
+    boot_time + proc.curr_proc.stat().starttime / procfs::ticks_per_second()
+}
+
+pub fn boot_time_secs() -> u64 {
+    boot_time().map(|x| x.timestamp() as u64).unwrap_or(0)
+}
+
+impl Baseline {
+    fn load(path: &Path) -> Result<Self, Error> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| anyhow!("failed to read baseline {}: {e}", path.display()))?;
+        let mut entries = Vec::new();
+        for (i, line) in content.lines().enumerate() {
+            let entry = Baseline::parse(line)
+                .ok_or_else(|| anyhow!("invalid baseline {} at line {}", path.display(), i + 1))?;
+            entries.push(entry);
+        }
+        Ok(Self::new(entries))
+    }
+
+    fn new(entries: Vec<Entry>) -> Self {
+        let mut by_start = HashMap::new();
+        let mut by_identity: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, e) in entries.iter().enumerate() {
+            by_start.entry((e.pid, e.start)).or_insert(i);
+            by_identity.entry(e.identity.clone()).or_default().push(i);
+        }
+        Self {
+            entries,
+            by_start,
+            by_identity,
+        }
+    }
+
+    fn parse(line: &str) -> Option<Entry> {
+        let mut fields = line.splitn(6, '\t');
+        Some(Entry {
+            pid: fields.next()?.parse().ok()?,
+            start: fields.next()?.parse().ok()?,
+            rss: fields.next()?.parse().ok()?,
+            cpu_time: fields.next()?.parse().ok()?,
+            identity: fields.next()?.to_string(),
+            command: fields.next()?.to_string(),
+        })
+    }
+
+    /// Samples the process to be compared, and the identity is built only if it's not found by PID and start time
+    /// because it's costly.
+    pub fn sample(&self, proc: &ProcessInfo, boot_time: u64) -> Sample {
+        let start = start_time(proc, boot_time);
+        let identity = if self.by_start.contains_key(&(proc.pid, start)) {
+            None
+        } else {
+            Some(identity(proc))
+        };
+        let (rss, cpu_time) = usage(proc);
+        Sample {
+            pid: proc.pid,
+            start,
+            rss,
+            cpu_time,
+            identity,
+        }
+    }
+
+    /// Matches samples with entries.
+    ///
+    /// Samples are matched by PID and start time at first, and then the rest by identity in order,
+    /// so that restarted processes are compared with the previous instances.
+    pub fn compare(&self, samples: &[Sample]) -> Comparison<'_> {
+        let mut matches = HashMap::new();
+        let mut used = HashSet::new();
+        let mut rest = Vec::new();
+        for s in samples {
+            if let Some(&i) = self.by_start.get(&(s.pid, s.start)) {
+                used.insert(i);
+                matches.insert(s.pid, Match::Same(&self.entries[i]));
+            } else {
+                rest.push(s);
+            }
+        }
+        for s in rest {
+            let found = s
+                .identity
+                .as_ref()
+                .and_then(|x| self.by_identity.get(x))
+                .and_then(|x| x.iter().find(|i| !used.contains(*i)).copied());
+            if let Some(i) = found {
+                used.insert(i);
+                matches.insert(s.pid, Match::Restarted(&self.entries[i]));
+            } else {
+                matches.insert(s.pid, Match::New);
+            }
+        }
+        let gone = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !used.contains(i))
+            .map(|(_, x)| x)
+            .collect();
+        Comparison { matches, gone }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let e = Baseline::parse("200\t1700000060\t204800\t13000\tworker.service:/usr/bin/python3#0000abcd\tpython3 worker.py\tx")
+            .unwrap();
+        assert_eq!(e.pid, 200);
+        assert_eq!(e.rss, 204800);
+        assert_eq!(e.cpu_time, 13000);
+        assert_eq!(e.identity, "worker.service:/usr/bin/python3#0000abcd");
+        assert_eq!(e.command, "python3 worker.py\tx");
+        assert!(Baseline::parse("200\t1700000060\t204800").is_none());
+        assert!(Baseline::parse("pid\t1\t2\t3\ta\tb").is_none());
+    }
+
+    #[test]
+    fn test_compare() {
+        let entry = |pid, start, identity: &str| Entry {
+            pid,
+            start,
+            rss: 0,
+            cpu_time: 0,
+            identity: identity.to_string(),
+            command: String::new(),
+        };
+        let sample = |pid, start, identity: Option<&str>| Sample {
+            pid,
+            start,
+            rss: 0,
+            cpu_time: 0,
+            identity: identity.map(|x| x.to_string()),
+        };
+        let baseline = Baseline::new(vec![
+            entry(100, 10, "sshd"),
+            entry(200, 20, "worker"),
+            entry(201, 21, "worker"),
+            entry(300, 30, "cron"),
+        ]);
+        let comparison = baseline.compare(&[
+            sample(100, 10, None),
+            sample(210, 40, Some("worker")),
+            sample(211, 41, Some("worker")),
+            sample(212, 42, Some("worker")),
+        ]);
+        let matched = |pid| match comparison.matches.get(&pid) {
+            Some(Match::Same(e)) => format!("same {}", e.pid),
+            Some(Match::Restarted(e)) => format!("restarted {}", e.pid),
+            Some(Match::New) => String::from("new"),
+            None => String::new(),
+        };
+        assert_eq!(matched(100), "same 100");
+        assert_eq!(matched(210), "restarted 200");
+        assert_eq!(matched(211), "restarted 201");
+        assert_eq!(matched(212), "new");
+        let gone: Vec<_> = comparison.gone.iter().map(|x| x.pid).collect();
+        assert_eq!(gone, [300]);
+    }
+}
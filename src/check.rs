@@ -0,0 +1,269 @@
+use crate::config::*;
+use crate::util::unbytify;
+use crate::view::View;
+use crate::Opt;
+use anyhow::{anyhow, bail, Error};
+use std::fmt;
+
+/// Exit codes of Nagios plugins
+const STATUS_OK: i32 = 0;
+const STATUS_WARNING: i32 = 1;
+const STATUS_CRITICAL: i32 = 2;
+const STATUS_UNKNOWN: i32 = 3;
+
+#[derive(Debug, PartialEq)]
+pub enum Condition {
+    /// `exists:<keyword>`
+    Exists(String),
+    /// `<kind>(<keyword>) <op> <threshold>`
+    Compare {
+        field: String,
+        keyword: String,
+        op: Op,
+        threshold: f64,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Op {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Op {
+    // Longer operators are tried at first
+    const LIST: [(&'static str, Op); 6] = [
+        ("<=", Op::Le),
+        (">=", Op::Ge),
+        ("==", Op::Eq),
+        ("!=", Op::Ne),
+        ("<", Op::Lt),
+        (">", Op::Gt),
+    ];
+
+    pub fn eval(&self, x: f64, y: f64) -> bool {
+        match self {
+            Op::Lt => x < y,
+            Op::Le => x <= y,
+            Op::Gt => x > y,
+            Op::Ge => x >= y,
+            Op::Eq => x == y,
+            Op::Ne => x != y,
+        }
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = Op::LIST.iter().find(|(_, x)| x == self).unwrap().0;
+        write!(f, "{s}")
+    }
+}
+
+struct Outcome {
+    passed: bool,
+    message: String,
+    perfdata: String,
+}
+
+/// Evaluates conditions like a Nagios plugin, and exits with its status code.
+///
+/// The status is CRITICAL if any of `critical` fails, WARNING if any of `warning` fails, and UNKNOWN on errors.
+pub fn run_check(opt: &mut Opt, config: &Config, critical: &[String], warning: &[String]) -> ! {
+    let (code, line) = match check(opt, config, critical, warning) {
+        Ok(x) => x,
+        Err(e) => (STATUS_UNKNOWN, format!("PROCS UNKNOWN - {e}")),
+    };
+    println!("{line}");
+    std::process::exit(code);
+}
+
+fn check(
+    opt: &mut Opt,
+    config: &Config,
+    critical: &[String],
+    warning: &[String],
+) -> Result<(i32, String), Error> {
+    let critical = critical
+        .iter()
+        .map(|x| parse(x))
+        .collect::<Result<Vec<_>, _>>()?;
+    let warning = warning
+        .iter()
+        .map(|x| parse(x))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // All conditions are evaluated against the same snapshot
+    let mut view = View::new(opt, config, false)?;
+
+    let mut code = STATUS_OK;
+    let mut failed = Vec::new();
+    let mut passed = Vec::new();
+    let mut perfdata = Vec::new();
+    for (conds, status) in [(critical, STATUS_CRITICAL), (warning, STATUS_WARNING)] {
+        for cond in conds {
+            let outcome = evaluate(&mut view, opt, config, &cond)?;
+            if outcome.passed {
+                passed.push(outcome.message);
+            } else {
+                code = code.max(status);
+                failed.push(outcome.message);
+            }
+            perfdata.push(outcome.perfdata);
+        }
+    }
+
+    let status = match code {
+        STATUS_OK => "OK",
+        STATUS_WARNING => "WARNING",
+        _ => "CRITICAL",
+    };
+    // Failed conditions are shown at first because status bars may cut the line
+    failed.append(&mut passed);
+    let line = format!(
+        "PROCS {status} - {} | {}",
+        failed.join(", "),
+        perfdata.join(" ")
+    );
+    Ok((code, line))
+}
+
+fn evaluate(
+    view: &mut View,
+    opt: &mut Opt,
+    config: &Config,
+    cond: &Condition,
+) -> Result<Outcome, Error> {
+    let keyword = match cond {
+        Condition::Exists(x) => x,
+        Condition::Compare { keyword, .. } => keyword,
+    };
+    opt.keyword = keyword.split_whitespace().map(String::from).collect();
+    view.filter(opt, config);
+    view.fit_width(config);
+    let count = view.visible_pids.len();
+
+    match cond {
+        Condition::Exists(keyword) => Ok(Outcome {
+            passed: count > 0,
+            message: format!("{count} processes match {keyword:?}"),
+            perfdata: format!("'count({keyword})'={count}"),
+        }),
+        Condition::Compare {
+            field,
+            keyword,
+            op,
+            threshold,
+        } => {
+            let value = if field.eq_ignore_ascii_case("count") {
+                count as f64
+            } else {
+                let column = view.find_column(config, field).ok_or_else(|| {
+                    anyhow!(
+                        "column \"{field}\" is not found in columns, it can be added by --insert"
+                    )
+                })?;
+                // Processes are summed up, because a service may consist of some processes
+                let mut sum = 0.0;
+                for pid in &view.visible_pids {
+                    match column.column.raw_value(*pid) {
+                        Some(x) => sum += x,
+                        None => {
+                            // Empty cells are skipped, but cells like text or codes can't be compared
+                            if let Some(x) = column
+                                .column
+                                .display_content(*pid, &ConfigColumnAlign::Left)
+                                .filter(|x| !x.trim().is_empty())
+                            {
+                                bail!("column \"{field}\" is not numeric: {:?}", x.trim());
+                            }
+                        }
+                    }
+                }
+                sum
+            };
+            let passed = op.eval(value, *threshold);
+            let message = if passed {
+                format!("{field}({keyword}) = {value}")
+            } else {
+                format!("{field}({keyword}) = {value} ( expected {op} {threshold} )")
+            };
+            Ok(Outcome {
+                passed,
+                message,
+                perfdata: format!("'{field}({keyword})'={value}"),
+            })
+        }
+    }
+}
+
+pub fn parse(s: &str) -> Result<Condition, Error> {
+    let s = s.trim();
+    if let Some(keyword) = s.strip_prefix("exists:") {
+        if keyword.trim().is_empty() {
+            bail!("keyword is empty: {s:?}");
+        }
+        return Ok(Condition::Exists(keyword.trim().to_string()));
+    }
+
+    let invalid = || {
+        anyhow!("invalid condition: {s:?}, expected \"exists:<keyword>\" or \"<kind>(<keyword>) <op> <number>\"")
+    };
+    let (field, rest) = s.split_once('(').ok_or_else(invalid)?;
+    let (keyword, rest) = rest.split_once(')').ok_or_else(invalid)?;
+    let rest = rest.trim();
+    let (op, threshold) = Op::LIST
+        .into_iter()
+        .find_map(|(x, op)| rest.strip_prefix(x).map(|threshold| (op, threshold)))
+        .ok_or_else(invalid)?;
+    let threshold = unbytify(threshold.trim()).ok_or_else(invalid)?;
+    if field.trim().is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(Condition::Compare {
+        field: field.trim().to_string(),
+        keyword: keyword.trim().to_string(),
+        op,
+        threshold,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("exists:nginx").unwrap(),
+            Condition::Exists(String::from("nginx"))
+        );
+        assert_eq!(
+            parse("cpu(nginx) < 80").unwrap(),
+            Condition::Compare {
+                field: String::from("cpu"),
+                keyword: String::from("nginx"),
+                op: Op::Lt,
+                threshold: 80.0,
+            }
+        );
+        assert_eq!(
+            parse("rss(postgres)>=1.5G").unwrap(),
+            Condition::Compare {
+                field: String::from("rss"),
+                keyword: String::from("postgres"),
+                op: Op::Ge,
+                threshold: 1.5 * 1024.0 * 1024.0 * 1024.0,
+            }
+        );
+        assert!(parse("exists:").is_err());
+        assert!(parse("cpu(nginx)").is_err());
+        assert!(parse("cpu(nginx) ~ 1").is_err());
+        assert!(parse("(nginx) < 1").is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::config::*;
+use crate::process::procfs_root;
+use crate::util::{get_theme, ArgPagerMode};
+use crate::view::View;
+use crate::Opt;
+use anyhow::{bail, Context, Error};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::process::Command;
+
+/// Dumps the process tree of `pid` to `images_dir` by CRIU after previewing it.
+pub fn run_checkpoint(
+    opt: &mut Opt,
+    config: &Config,
+    pid: i32,
+    images_dir: &Path,
+) -> Result<(), Error> {
+    let criu = which::which("criu").context("criu is not found in PATH")?;
+
+    opt.tree = true;
+    opt.pager = Some(ArgPagerMode::Disable);
+    opt.keyword = vec![];
+
+    let theme = get_theme(opt, config);
+    let mut view = View::new(opt, config, false)?;
+    view.filter(opt, config);
+
+    let mut pids = vec![pid];
+    get_child_pids(&view.child_pids, pid, &mut pids);
+    view.visible_pids.retain(|x| pids.contains(x));
+    view.auxiliary_pids.clear();
+    if view.visible_pids.is_empty() {
+        bail!("process {} is not found", pid);
+    }
+
+    println!("Process tree to be dumped:");
+    view.adjust(config, &HashMap::new());
+    view.display(opt, config, &theme)?;
+
+    println!();
+    println!("Open resources:");
+    for pid in &pids {
+        for target in get_fd_targets(*pid) {
+            println!("  {pid:>7} {target}");
+        }
+    }
+
+    println!();
+    print!(
+        "Dump the process tree of PID {} to {:?} by criu? [y/N] ",
+        pid, images_dir
+    );
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    if !matches!(answer.trim(), "y" | "Y" | "yes") {
+        println!("Canceled");
+        return Ok(());
+    }
+
+    fs::create_dir_all(images_dir).context(format!("failed to create {images_dir:?}"))?;
+    let status = Command::new(criu)
+        .arg("dump")
+        .arg("--tree")
+        .arg(pid.to_string())
+        .arg("--images-dir")
+        .arg(images_dir)
+        .arg("--shell-job")
+        .status()
+        .context("failed to execute criu")?;
+    if !status.success() {
+        bail!("criu dump failed ({})", status);
+    }
+
+    println!("Dumped to {images_dir:?}");
+    Ok(())
+}
+
+fn get_child_pids(child_pids: &HashMap<i32, Vec<i32>>, pid: i32, ret: &mut Vec<i32>) {
+    if let Some(pids) = child_pids.get(&pid) {
+        for x in pids {
+            if !ret.contains(x) {
+                ret.push(*x);
+                get_child_pids(child_pids, *x, ret);
+            }
+        }
+    }
+}
+
+fn get_fd_targets(pid: i32) -> Vec<String> {
+    let mut ret = Vec::new();
+    if let Ok(entries) = fs::read_dir(procfs_root().join(pid.to_string()).join("fd")) {
+        for entry in entries.flatten() {
+            if let Ok(target) = fs::read_link(entry.path()) {
+                ret.push(format!(
+                    "fd {:<4} {}",
+                    entry.file_name().to_string_lossy(),
+                    target.to_string_lossy()
+                ));
+            }
+        }
+    }
+    ret.sort();
+    ret
+}
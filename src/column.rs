@@ -1,9 +1,37 @@
 use crate::config::{Config, ConfigColumnAlign, ConfigSortOrder};
 use crate::process::ProcessInfo;
+use crate::util::{bytify, format_duration, parse_time};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use std::time::Instant;
 
 pub trait Column {
     fn add(&mut self, proc: &ProcessInfo);
 
+    /// Called after all processes are added, for columns which depend on other processes.
+    fn finish(&mut self) {}
+
+    /// Whether contents of each process are determined by the process alone,
+    /// so rows can be rendered before all processes are added.
+    fn streamable(&self) -> bool {
+        true
+    }
+
+    /// Drops contents of added processes after they are rendered.
+    fn clear(&mut self) {}
+
+    /// Whether the column keeps state across refreshes of watch mode ( ex. samples of counters ).
+    ///
+    /// Such a column is cleared and reused by the next view instead of being rebuilt.
+    fn persistent(&self) -> bool {
+        false
+    }
+
+    /// Time of the sample shown for the process if it couldn't be refreshed this time ( ex. smaps_rollup read failed ).
+    fn stale_since(&self, _pid: i32) -> Option<Instant> {
+        None
+    }
+
     fn available(&self) -> bool {
         true
     }
@@ -12,6 +40,17 @@ pub trait Column {
         true
     }
 
+    /// Content as a number in the unit of the formatted content ( ex. bytes, percent, seconds ),
+    /// which is used by aggregates, checks and metrics instead of parsing the rounded formatted content.
+    fn raw_value(&self, _pid: i32) -> Option<f64> {
+        None
+    }
+
+    /// How a value in the unit of `raw_value` is formatted ( ex. totals of aggregates ).
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(0)
+    }
+
     fn display_header(
         &self,
         align: &ConfigColumnAlign,
@@ -22,7 +61,12 @@ pub trait Column {
     fn display_content(&self, pid: i32, align: &ConfigColumnAlign) -> Option<String>;
     fn find_partial(&self, pid: i32, keyword: &str, content_to_lowercase: bool) -> bool;
     fn find_exact(&self, pid: i32, keyword: &str, content_to_lowercase: bool) -> bool;
-    fn sorted_pid(&self, order: &ConfigSortOrder) -> Vec<i32>;
+    /// Ties are sorted by `tie_break` ( ranks of processes by another column ), and then by PID.
+    fn sorted_pid(
+        &self,
+        order: &ConfigSortOrder,
+        tie_break: Option<&HashMap<i32, usize>>,
+    ) -> Vec<i32>;
     fn apply_visible(&mut self, visible_pids: &[i32]);
     fn reset_width(
         &mut self,
@@ -35,6 +79,194 @@ pub trait Column {
     fn get_width(&self) -> usize;
 }
 
+/// Conversion of raw contents to numbers for `Column::raw_value`.
+///
+/// Non-numeric contents ( ex. strings, timestamps ) have no value.
+pub trait RawValue {
+    fn raw_value(&self) -> Option<f64>;
+}
+
+macro_rules! raw_value_as_f64 {
+    ($($x:ty),*) => {
+        $(
+            impl RawValue for $x {
+                fn raw_value(&self) -> Option<f64> {
+                    Some(*self as f64)
+                }
+            }
+        )*
+    };
+}
+
+raw_value_as_f64!(u32, u64, usize, i32, i64);
+
+impl RawValue for chrono::Duration {
+    fn raw_value(&self) -> Option<f64> {
+        Some(self.num_milliseconds() as f64 / 1000.0)
+    }
+}
+
+impl RawValue for std::time::Duration {
+    fn raw_value(&self) -> Option<f64> {
+        Some(self.as_secs_f64())
+    }
+}
+
+impl<T: RawValue> RawValue for Option<T> {
+    fn raw_value(&self) -> Option<f64> {
+        self.as_ref().and_then(|x| x.raw_value())
+    }
+}
+
+macro_rules! raw_value_none {
+    ($($x:ty),*) => {
+        $(
+            impl RawValue for $x {
+                fn raw_value(&self) -> Option<f64> {
+                    None
+                }
+            }
+        )*
+    };
+}
+
+raw_value_none!(
+    bool,
+    String,
+    (String, String),
+    chrono::DateTime<chrono::Local>
+);
+
+/// Format of a value in the unit of `Column::raw_value`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueFormat {
+    /// Number with the decimal places.
+    Number(usize),
+    /// Bytes with or without unit suffix ( ex. `1.5M` ).
+    Bytes(bool),
+    /// Seconds as a clock ( ex. `02:45:40` ).
+    Time,
+    /// Seconds with the largest unit ( ex. `1.5hours` ).
+    Duration,
+}
+
+impl ValueFormat {
+    pub fn format(&self, value: f64) -> String {
+        let sign = if value < 0.0 { "-" } else { "" };
+        match self {
+            ValueFormat::Number(decimals) => format!("{value:.decimals$}"),
+            ValueFormat::Bytes(unit_suffix) => {
+                format!("{sign}{}", bytify(value.abs().round() as u64, *unit_suffix))
+            }
+            ValueFormat::Time => format!("{sign}{}", parse_time(value.abs().round() as u64)),
+            ValueFormat::Duration => format!(
+                "{sign}{}",
+                format_duration(chrono::Duration::milliseconds(
+                    (value.abs() * 1000.0).round() as i64
+                ))
+            ),
+        }
+    }
+}
+
+/// Formatted contents of a column.
+///
+/// Many cells have the same string ( ex. user names, states ),
+/// so each distinct string is stored once and cells refer it by index.
+#[derive(Default)]
+pub struct Contents {
+    cells: HashMap<i32, u32>,
+    strings: Vec<Rc<str>>,
+    index: HashMap<Rc<str>, u32>,
+}
+
+impl Contents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, pid: i32, content: String) {
+        let idx = if let Some(idx) = self.index.get(content.as_str()) {
+            *idx
+        } else {
+            let idx = self.strings.len() as u32;
+            let content: Rc<str> = Rc::from(content);
+            self.strings.push(content.clone());
+            self.index.insert(content, idx);
+            idx
+        };
+        self.cells.insert(pid, idx);
+    }
+
+    pub fn get(&self, pid: &i32) -> Option<&str> {
+        self.cells
+            .get(pid)
+            .map(|idx| self.strings[*idx as usize].as_ref())
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.strings.clear();
+        self.index.clear();
+    }
+}
+
+/// Last samples of each process, which are shown instead while the source can't be read.
+///
+/// A column using it should be `persistent` to keep samples across refreshes of watch mode.
+pub struct Samples<T> {
+    /// Start time of the process to detect PID reuse, the sample, and the time of it
+    samples: HashMap<i32, (u64, T, Instant)>,
+    stale: HashSet<i32>,
+    seen: HashSet<i32>,
+}
+
+impl<T: Clone> Samples<T> {
+    pub fn new() -> Self {
+        Self {
+            samples: HashMap::new(),
+            stale: HashSet::new(),
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Records `curr` if it was read, or returns the last sample of the same process.
+    pub fn update(&mut self, pid: i32, start: u64, curr: Option<T>) -> Option<T> {
+        self.seen.insert(pid);
+        if let Some(x) = curr {
+            self.stale.remove(&pid);
+            self.samples.insert(pid, (start, x.clone(), Instant::now()));
+            return Some(x);
+        }
+        match self.samples.get(&pid) {
+            Some((x, sample, _)) if *x == start => {
+                self.stale.insert(pid);
+                Some(sample.clone())
+            }
+            _ => {
+                self.stale.remove(&pid);
+                self.samples.remove(&pid);
+                None
+            }
+        }
+    }
+
+    pub fn stale_since(&self, pid: i32) -> Option<Instant> {
+        if self.stale.contains(&pid) {
+            self.samples.get(&pid).map(|x| x.2)
+        } else {
+            None
+        }
+    }
+
+    /// Drops samples of processes which were not updated since the last call.
+    pub fn finish(&mut self) {
+        let seen = std::mem::take(&mut self.seen);
+        self.samples.retain(|pid, _| seen.contains(pid));
+        self.stale.retain(|pid| seen.contains(pid));
+    }
+}
+
 #[macro_export]
 macro_rules! column_default_display_header {
     () => {
@@ -122,11 +354,25 @@ macro_rules! column_default_find_exact {
 #[macro_export]
 macro_rules! column_default_sorted_pid {
     ($x:ty) => {
-        fn sorted_pid(&self, order: &$crate::config::ConfigSortOrder) -> Vec<i32> {
+        fn sorted_pid(
+            &self,
+            order: &$crate::config::ConfigSortOrder,
+            tie_break: Option<&std::collections::HashMap<i32, usize>>,
+        ) -> Vec<i32> {
             let mut contents: Vec<(&i32, &$x)> = self.raw_contents.iter().collect();
-            contents.sort_by_key(|&(_x, y)| y);
-            if matches!(*order, $crate::config::ConfigSortOrder::Descending) {
-                contents.reverse()
+            let tie = |p1: &i32, p2: &i32| {
+                tie_break
+                    .map(|x| x.get(p1).cmp(&x.get(p2)))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then(p1.cmp(p2))
+            };
+            match *order {
+                $crate::config::ConfigSortOrder::Ascending => {
+                    contents.sort_by(|(p1, x1), (p2, x2)| x1.cmp(x2).then_with(|| tie(p1, p2)))
+                }
+                $crate::config::ConfigSortOrder::Descending => {
+                    contents.sort_by(|(p1, x1), (p2, x2)| x2.cmp(x1).then_with(|| tie(p1, p2)))
+                }
             }
             contents.iter().map(|(x, _y)| **x).collect()
         }
@@ -140,6 +386,16 @@ macro_rules! column_default_apply_visible {
     };
 }
 
+#[macro_export]
+macro_rules! column_default_clear {
+    () => {
+        fn clear(&mut self) {
+            self.fmt_contents.clear();
+            self.raw_contents.clear();
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! column_default_reset_width {
     () => {
@@ -182,7 +438,7 @@ macro_rules! column_default_update_width {
     () => {
         fn update_width(&mut self, pid: i32, max_width: Option<usize>) {
             if let Some(content) = self.fmt_contents.get(&pid) {
-                let content_len = unicode_width::UnicodeWidthStr::width(content.as_str());
+                let content_len = unicode_width::UnicodeWidthStr::width(content);
                 self.width = cmp::max(content_len, self.width);
                 if let Some(max_width) = max_width {
                     self.width = std::cmp::min(self.width, max_width);
@@ -201,8 +457,50 @@ macro_rules! column_default_get_width {
     };
 }
 
+/// Raw contents divided by the scale, which is used for fixed-point contents ( ex. `1000.0` for `usage * 1000` ).
+///
+/// Processes without formatted contents have no value even if they have raw contents as a placeholder.
+#[macro_export]
+macro_rules! column_default_raw_value {
+    () => {
+        $crate::column_default_raw_value!(1.0);
+    };
+    ($scale:expr) => {
+        fn raw_value(&self, pid: i32) -> Option<f64> {
+            if self.fmt_contents.get(&pid).map_or(true, |x| x.is_empty()) {
+                return None;
+            }
+            let value = $crate::column::RawValue::raw_value(self.raw_contents.get(&pid)?)?;
+            Some(value / $scale)
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! column_default {
+    ($x:ty) => {
+        $crate::column_default!($x, 1.0);
+    };
+    ($x:ty, $scale:expr) => {
+        $crate::column_default_display_header!();
+        $crate::column_default_display_unit!();
+        $crate::column_default_display_content!();
+        $crate::column_default_find_partial!();
+        $crate::column_default_find_exact!();
+        $crate::column_default_sorted_pid!($x);
+        $crate::column_default_apply_visible!();
+        $crate::column_default_clear!();
+        $crate::column_default_reset_width!();
+        $crate::column_default_update_width!();
+        $crate::column_default_get_width!();
+        $crate::column_default_raw_value!($scale);
+    };
+}
+
+/// Same as `column_default`, but raw contents are codes of textual contents ( ex. signal masks, policies ),
+/// so they have no numeric value.
+#[macro_export]
+macro_rules! column_default_text {
     ($x:ty) => {
         $crate::column_default_display_header!();
         $crate::column_default_display_unit!();
@@ -211,8 +509,44 @@ macro_rules! column_default {
         $crate::column_default_find_exact!();
         $crate::column_default_sorted_pid!($x);
         $crate::column_default_apply_visible!();
+        $crate::column_default_clear!();
         $crate::column_default_reset_width!();
         $crate::column_default_update_width!();
         $crate::column_default_get_width!();
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples() {
+        let mut samples = Samples::new();
+        assert_eq!(samples.update(1, 100, Some(10)), Some(10));
+        assert_eq!(samples.stale_since(1), None);
+        assert_eq!(samples.update(1, 100, None), Some(10));
+        assert!(samples.stale_since(1).is_some());
+        samples.finish();
+
+        // The PID is reused by another process
+        assert_eq!(samples.update(1, 200, None), None);
+        assert_eq!(samples.stale_since(1), None);
+        samples.finish();
+
+        assert_eq!(samples.update(2, 100, Some(20)), Some(20));
+        samples.finish();
+        samples.finish();
+        assert_eq!(samples.update(2, 100, None), None);
+    }
+
+    #[test]
+    fn test_value_format() {
+        assert_eq!(ValueFormat::Number(1).format(12.34), "12.3");
+        assert_eq!(ValueFormat::Number(0).format(-3.0), "-3");
+        assert_eq!(ValueFormat::Bytes(true).format(1536.0), "1.500K");
+        assert_eq!(ValueFormat::Bytes(false).format(-1536.0), "-1536");
+        assert_eq!(ValueFormat::Time.format(3661.0), "01:01:01");
+        assert_eq!(ValueFormat::Duration.format(5400.0), "1.5hours");
+    }
+}
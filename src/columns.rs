@@ -1,3 +1,6 @@
+pub mod platform;
+pub use self::platform::{kind_platforms, remove_unsupported_columns, Platform, KIND_PLATFORMS};
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 include!("./columns/os_linux.rs");
 #[cfg(target_os = "macos")]
@@ -0,0 +1,52 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use procfs::process::FDTarget;
+use std::cmp;
+use std::collections::HashMap;
+
+/// Count of file descriptors of an anonymous inode ( ex. `[eventpoll]` ), which is shared by `Epoll`, `TimerFd`
+/// and `EventFd` columns.
+pub struct AnonInode {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    inode: &'static str,
+}
+
+impl AnonInode {
+    pub fn new(header: Option<String>, default_header: &str, inode: &'static str) -> Self {
+        let header = header.unwrap_or_else(|| String::from(default_header));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            inode,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for AnonInode {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let (fmt_content, raw_content) = if let Ok(fds) = proc.curr_proc.fd() {
+            let count = fds
+                .iter()
+                .filter(|x| matches!(x.target, FDTarget::AnonInode(ref x) if x == self.inode))
+                .count() as u64;
+            (format!("{count}"), count)
+        } else {
+            (String::new(), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u64);
+}
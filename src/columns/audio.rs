@@ -0,0 +1,150 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Clone, Copy, Default)]
+struct Streams {
+    play: u64,
+    rec: u64,
+}
+
+pub struct Audio {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    streams: HashMap<i32, Streams>,
+}
+
+impl Audio {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Audio"));
+        let unit = String::new();
+
+        let mut streams = HashMap::new();
+        collect_alsa_streams(&crate::process::procfs_root().join("asound"), &mut streams);
+        // Clients of sound servers are PIDs of self, so they can't be matched with other procfs.
+        if crate::process::is_local_procfs() {
+            collect_pulse_streams(&mut streams);
+        }
+
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            streams,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for Audio {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let (fmt_content, raw_content) = if let Some(x) = self.streams.get(&proc.pid) {
+            let mut fmt = Vec::new();
+            if x.play != 0 {
+                fmt.push(format!("play:{}", x.play));
+            }
+            if x.rec != 0 {
+                fmt.push(format!("rec:{}", x.rec));
+            }
+            (fmt.join(","), x.play + x.rec)
+        } else {
+            (String::new(), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u64);
+}
+
+/// Collects running PCM substreams opened through ALSA directly ( ex. JACK, sound servers ).
+fn collect_alsa_streams(asound: &Path, streams: &mut HashMap<i32, Streams>) {
+    let cards = if let Ok(x) = fs::read_dir(asound) {
+        x
+    } else {
+        return;
+    };
+    for card in cards.flatten() {
+        if !card.file_name().to_string_lossy().starts_with("card") {
+            continue;
+        }
+        for pcm in fs::read_dir(card.path()).into_iter().flatten().flatten() {
+            let pcm_name = pcm.file_name().to_string_lossy().into_owned();
+            if !pcm_name.starts_with("pcm") {
+                continue;
+            }
+            let capture = pcm_name.ends_with('c');
+            for sub in fs::read_dir(pcm.path()).into_iter().flatten().flatten() {
+                let status = fs::read_to_string(sub.path().join("status")).unwrap_or_default();
+                if !status.lines().any(|x| x.trim() == "state: RUNNING") {
+                    continue;
+                }
+                let pid = status.lines().find_map(|x| {
+                    x.strip_prefix("owner_pid")
+                        .and_then(|x| x.trim().strip_prefix(':'))
+                        .and_then(|x| x.trim().parse::<i32>().ok())
+                });
+                if let Some(pid) = pid {
+                    let entry = streams.entry(pid).or_default();
+                    if capture {
+                        entry.rec += 1;
+                    } else {
+                        entry.play += 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Collects active streams of PulseAudio clients, which includes PipeWire clients through pipewire-pulse.
+fn collect_pulse_streams(streams: &mut HashMap<i32, Streams>) {
+    let pactl = if let Ok(x) = which::which("pactl") {
+        x
+    } else {
+        return;
+    };
+    for (kind, capture) in [("sink-inputs", false), ("source-outputs", true)] {
+        let output = Command::new(&pactl)
+            .env("LC_ALL", "C")
+            .args(["list", kind])
+            .output();
+        let output = if let Ok(x) = output {
+            String::from_utf8_lossy(&x.stdout).into_owned()
+        } else {
+            continue;
+        };
+
+        let mut pid = None;
+        let mut corked = false;
+        // A trailing empty entry flushes the last stream
+        for line in output.lines().chain(std::iter::once("")) {
+            let line = line.trim();
+            if line.is_empty() {
+                if let (Some(pid), false) = (pid.take(), corked) {
+                    let entry: &mut Streams = streams.entry(pid).or_default();
+                    if capture {
+                        entry.rec += 1;
+                    } else {
+                        entry.play += 1;
+                    }
+                }
+                corked = false;
+            } else if line == "Corked: yes" {
+                corked = true;
+            } else if let Some(x) = line.strip_prefix("application.process.id = ") {
+                pid = x.trim_matches('"').parse::<i32>().ok();
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use procfs::net::{TcpNetEntry, TcpState};
+use procfs::process::FDTarget;
+use std::cmp;
+use std::collections::HashMap;
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+
+/// Directories which any user can write executables to
+const WRITABLE_DIRS: &[&str] = &["/tmp/", "/var/tmp/", "/dev/shm/"];
+
+pub struct Audit {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, usize>,
+    width: usize,
+    tcp_entry: Vec<TcpNetEntry>,
+}
+
+impl Audit {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Audit"));
+        let unit = String::new();
+        let mut tcp_entry = Vec::new();
+        for file in ["net/tcp", "net/tcp6"] {
+            if let Ok(x) = crate::process::read_procfs_si::<procfs::net::TcpNetEntries>(file) {
+                tcp_entry.extend(x.0);
+            }
+        }
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            tcp_entry,
+        }
+    }
+
+    fn listen_any(&self, proc: &ProcessInfo) -> bool {
+        let fds = if let Ok(x) = proc.curr_proc.fd() {
+            x
+        } else {
+            return false;
+        };
+        fds.iter().any(|fd| {
+            if let FDTarget::Socket(inode) = fd.target {
+                self.tcp_entry.iter().any(|x| {
+                    x.inode == inode
+                        && x.state == TcpState::Listen
+                        && x.local_address.ip().is_unspecified()
+                })
+            } else {
+                false
+            }
+        })
+    }
+}
+
+impl Column for Audit {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let root = proc.curr_proc.owner() == 0;
+
+        let mut findings = Vec::new();
+        // exe of other users' processes can't be read without privilege
+        if let Ok(exe) = proc.curr_proc.exe() {
+            let path = exe.to_string_lossy();
+            let (path, deleted) = if let Some(x) = path.strip_suffix(" (deleted)") {
+                (x, true)
+            } else {
+                (path.as_ref(), false)
+            };
+            if deleted {
+                findings.push("deleted-exe");
+            }
+            if WRITABLE_DIRS.iter().any(|x| path.starts_with(x)) {
+                findings.push("tmp-exe");
+            } else if !deleted && writable_by_others(Path::new(path), root) {
+                findings.push("writable-exe");
+            }
+        }
+        if root && self.listen_any(proc) {
+            findings.push("root-listen-any");
+        }
+
+        let raw_content = findings.len();
+        let fmt_content = findings.join(",");
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(usize);
+}
+
+/// Checks whether the executable can be replaced by other users.
+///
+/// The file and its directory must not be world-writable, and those of root processes must be owned by root.
+fn writable_by_others(exe: &Path, root: bool) -> bool {
+    let paths = [Some(exe), exe.parent()];
+    paths.into_iter().flatten().any(|x| {
+        if let Ok(meta) = x.metadata() {
+            meta.mode() & 0o002 != 0 || (root && meta.uid() != 0)
+        } else {
+            false
+        }
+    })
+}
@@ -0,0 +1,69 @@
+use crate::baseline::{self, Baseline as Snapshot, Match, Sample};
+use crate::process::{ProcessInfo, ProcessTask};
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Baseline {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    baseline: Option<&'static Snapshot>,
+    boot_time: u64,
+    samples: Vec<Sample>,
+}
+
+impl Baseline {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Baseline"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            baseline: baseline::get(),
+            boot_time: baseline::boot_time_secs(),
+            samples: Vec::new(),
+        }
+    }
+}
+
+impl Column for Baseline {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // Threads are not recorded in the baseline
+        if let (Some(baseline), ProcessTask::Process { .. }) = (self.baseline, &proc.curr_proc) {
+            self.samples.push(baseline.sample(proc, self.boot_time));
+        }
+    }
+
+    fn streamable(&self) -> bool {
+        false
+    }
+
+    fn finish(&mut self) {
+        let baseline = if let Some(x) = self.baseline {
+            x
+        } else {
+            return;
+        };
+        for (pid, x) in baseline.compare(&self.samples).matches {
+            let content = match x {
+                Match::Same(_) => "",
+                Match::Restarted(_) => "restarted",
+                Match::New => "new",
+            };
+            self.fmt_contents.insert(pid, String::from(content));
+            self.raw_contents.insert(pid, String::from(content));
+        }
+    }
+
+    fn available(&self) -> bool {
+        self.baseline.is_some()
+    }
+
+    column_default!(String);
+}
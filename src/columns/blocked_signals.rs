@@ -0,0 +1,91 @@
+use crate::process::ProcessInfo;
+use crate::{Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct BlockedSignals {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    /// Full names of signals for search
+    search_contents: HashMap<i32, String>,
+}
+
+impl BlockedSignals {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Blocked"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            search_contents: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for BlockedSignals {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let mask = if let Some(ref status) = proc.curr_status {
+            status.sigblk
+        } else {
+            0
+        };
+        let names = crate::process::signal_names(mask);
+        let fmt_content = crate::process::abbr_signal_names(&names);
+        // Sorted by the count of signals
+        let raw_content = u64::from(mask.count_ones());
+        let search_content = names.join(" ");
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+        self.search_contents.insert(proc.pid, search_content);
+    }
+
+    // A part of signals is shown, but all signals are searched
+    fn find_partial(&self, pid: i32, keyword: &str, content_to_lowercase: bool) -> bool {
+        if let Some(content) = self.search_contents.get(&pid) {
+            if content_to_lowercase {
+                content.to_ascii_lowercase().contains(keyword)
+            } else {
+                content.contains(keyword)
+            }
+        } else {
+            false
+        }
+    }
+
+    fn find_exact(&self, pid: i32, keyword: &str, content_to_lowercase: bool) -> bool {
+        if let Some(content) = self.search_contents.get(&pid) {
+            content.split(' ').any(|x| {
+                if content_to_lowercase {
+                    x.to_ascii_lowercase() == keyword
+                } else {
+                    x == keyword
+                }
+            })
+        } else {
+            false
+        }
+    }
+
+    fn clear(&mut self) {
+        self.fmt_contents.clear();
+        self.raw_contents.clear();
+        self.search_contents.clear();
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_sorted_pid!(u64);
+    crate::column_default_apply_visible!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
@@ -1,5 +1,5 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents};
 use regex::Regex;
 use std::cmp;
 use std::collections::HashMap;
@@ -7,7 +7,7 @@ use std::collections::HashMap;
 pub struct Ccgroup {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
     pat_user: Regex,
@@ -24,7 +24,7 @@ impl Ccgroup {
         let header = header.unwrap_or_else(|| String::from("Cgroup (compressed)"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
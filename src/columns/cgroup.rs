@@ -1,26 +1,36 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{Column, Contents};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use once_cell::sync::Lazy;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use regex::Regex;
 use std::cmp;
 use std::collections::HashMap;
 
+/// Container IDs in scope names ( ex. `docker-<64 hex>.scope` )
+#[cfg(any(target_os = "linux", target_os = "android"))]
+static PAT_ID: Lazy<Regex> = Lazy::new(|| Regex::new(r"[0-9a-f]{32,}").unwrap());
+
 pub struct Cgroup {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
+    abbr: bool,
 }
 
 impl Cgroup {
-    pub fn new(header: Option<String>) -> Self {
+    pub fn new(header: Option<String>, abbr: bool) -> Self {
         let header = header.unwrap_or_else(|| String::from("Cgroup"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
             unit,
+            abbr,
         }
     }
 }
@@ -28,18 +38,63 @@ impl Cgroup {
 #[cfg(any(target_os = "linux", target_os = "android"))]
 impl Column for Cgroup {
     fn add(&mut self, proc: &ProcessInfo) {
-        let fmt_content = if let Ok(cgroups) = &proc.curr_proc.cgroups() {
+        // The unified hierarchy of cgroup v2 is preferred, and the last one is used on cgroup v1
+        let raw_content = if let Ok(cgroups) = &proc.curr_proc.cgroups() {
             cgroups
-                .last()
+                .iter()
+                .find(|x| x.hierarchy == 0 && x.controllers.is_empty())
+                .or_else(|| cgroups.last())
                 .map_or_else(|| "".to_string(), |x| x.pathname.to_string())
         } else {
             "".to_string()
         };
-        let raw_content = fmt_content.clone();
+        let fmt_content = if self.abbr {
+            abbreviate(&raw_content)
+        } else {
+            raw_content.clone()
+        };
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(String);
+    fn find_partial(&self, pid: i32, keyword: &str, content_to_lowercase: bool) -> bool {
+        // The full path is searched because abbreviated parts should be matched too
+        if let Some(content) = self.raw_contents.get(&pid) {
+            if content_to_lowercase {
+                content.to_ascii_lowercase().contains(keyword)
+            } else {
+                content.contains(keyword)
+            }
+        } else {
+            false
+        }
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_exact!();
+    crate::column_default_sorted_pid!(String);
+    crate::column_default_apply_visible!();
+    crate::column_default_clear!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
+
+/// Abbreviates the cgroup path to the innermost two levels ( ex. `…/app.slice/foo.scope` ),
+/// and long IDs in them are shortened to 12 characters.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn abbreviate(path: &str) -> String {
+    let parts: Vec<_> = path
+        .split('/')
+        .filter(|x| !x.is_empty())
+        .map(|x| PAT_ID.replace_all(x, |c: &regex::Captures| c[0][..12].to_string()))
+        .collect();
+    if parts.len() <= 2 {
+        format!("/{}", parts.join("/"))
+    } else {
+        format!("…/{}", parts[parts.len() - 2..].join("/"))
+    }
 }
@@ -0,0 +1,92 @@
+use crate::column::Samples;
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Instant;
+
+pub struct CgroupCpuMax {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    cache: HashMap<PathBuf, Option<u64>>,
+    samples: Samples<Option<u64>>,
+}
+
+impl CgroupCpuMax {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("CPU Max"));
+        let unit = String::from("[CPU]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            cache: HashMap::new(),
+            samples: Samples::new(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for CgroupCpuMax {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // The last limit is shown if the cgroup of the process can't be read any more
+        let start = proc.curr_proc.stat().starttime;
+        let limit = proc.curr_proc.cgroup2_dir().map(|dir| {
+            *self
+                .cache
+                .entry(dir)
+                .or_insert_with_key(|dir| get_cpu_max(dir))
+        });
+        let limit = self.samples.update(proc.pid, start, limit).flatten();
+
+        // Unlimited is sorted as the largest value
+        let (fmt_content, raw_content) = if let Some(limit) = limit {
+            (format!("{:.2}", limit as f64 / 1000.0), limit)
+        } else {
+            (String::new(), u64::MAX)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn finish(&mut self) {
+        // Limits can be changed, so they are read again at the next refresh
+        self.cache.clear();
+        self.samples.finish();
+    }
+
+    fn persistent(&self) -> bool {
+        true
+    }
+
+    fn stale_since(&self, pid: i32) -> Option<Instant> {
+        self.samples.stale_since(pid)
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(2)
+    }
+
+    column_default!(u64, 1000.0);
+}
+
+/// Returns the effective CPU limit in milli-CPU, which is the minimum limit in the ancestors.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_cpu_max(dir: &std::path::Path) -> Option<u64> {
+    crate::process::read_cgroup2_hierarchy(dir, "cpu.max")
+        .iter()
+        .filter_map(|x| {
+            let mut x = x.split_whitespace();
+            let quota: u64 = x.next()?.parse().ok()?;
+            let period: u64 = x.next()?.parse().ok()?;
+            (period != 0).then(|| quota * 1000 / period)
+        })
+        .min()
+}
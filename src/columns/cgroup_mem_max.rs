@@ -0,0 +1,90 @@
+use crate::column::Samples;
+use crate::process::ProcessInfo;
+use crate::util::bytify;
+use crate::{column_default, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Instant;
+
+pub struct CgroupMemMax {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    cache: HashMap<PathBuf, Option<u64>>,
+    samples: Samples<Option<u64>>,
+    unit_suffix: bool,
+}
+
+impl CgroupMemMax {
+    pub fn new(header: Option<String>, unit_suffix: bool) -> Self {
+        let header = header.unwrap_or_else(|| String::from("MEM Max"));
+        let unit = String::from("[bytes]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            unit_suffix,
+            cache: HashMap::new(),
+            samples: Samples::new(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for CgroupMemMax {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // The last limit is shown if the cgroup of the process can't be read any more
+        let start = proc.curr_proc.stat().starttime;
+        let limit = proc.curr_proc.cgroup2_dir().map(|dir| {
+            *self
+                .cache
+                .entry(dir)
+                .or_insert_with_key(|dir| get_memory_max(dir))
+        });
+        let limit = self.samples.update(proc.pid, start, limit).flatten();
+
+        // Unlimited is sorted as the largest value
+        let (fmt_content, raw_content) = if let Some(limit) = limit {
+            (bytify(limit, self.unit_suffix), limit)
+        } else {
+            (String::new(), u64::MAX)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn finish(&mut self) {
+        // Limits can be changed, so they are read again at the next refresh
+        self.cache.clear();
+        self.samples.finish();
+    }
+
+    fn persistent(&self) -> bool {
+        true
+    }
+
+    fn stale_since(&self, pid: i32) -> Option<Instant> {
+        self.samples.stale_since(pid)
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
+    column_default!(u64);
+}
+
+/// Returns the effective memory limit, which is the minimum limit in the ancestors.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_memory_max(dir: &std::path::Path) -> Option<u64> {
+    crate::process::read_cgroup2_hierarchy(dir, "memory.max")
+        .iter()
+        .filter_map(|x| x.parse().ok())
+        .min()
+}
@@ -1,12 +1,12 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct Command {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
 }
@@ -16,13 +16,18 @@ impl Command {
         let header = header.unwrap_or_else(|| String::from("Command"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
             unit,
         }
     }
+
+    /// Command of the added process, which is used by columns referring other processes.
+    pub fn get(&self, pid: i32) -> Option<&String> {
+        self.raw_contents.get(&pid)
+    }
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
@@ -0,0 +1,208 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+#[cfg(feature = "docker")]
+use dockworker::container::ContainerFilters;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::cmp;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+#[cfg(feature = "docker")]
+use tokio::runtime::Runtime;
+
+/// Container IDs in cgroup paths of Docker, Podman, containerd and CRI-O
+/// ( ex. `/system.slice/docker-<64 hex>.scope`, `/docker/<64 hex>`, `libpod-<64 hex>.scope` )
+static PAT_CONTAINER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?:^|/)(?:docker[-/]|libpod-|cri-containerd-|crio-)([0-9a-f]{64})(?:\.scope)?(?:/|$)",
+    )
+    .unwrap()
+});
+
+pub struct Container {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    names: HashMap<String, String>,
+}
+
+impl Container {
+    pub fn new(header: Option<String>, docker_path: &str) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Container"));
+        let unit = String::new();
+
+        // Container runtimes of other systems can't be queried, so IDs are shown as is
+        let mut names = HashMap::new();
+        if crate::process::is_local_procfs() {
+            let mut reachable = false;
+            for path in runtime_sockets(docker_path) {
+                reachable |= query_names(&path, &mut names);
+            }
+            if !reachable {
+                read_docker_state(Path::new("/var/lib/docker/containers"), &mut names);
+                read_podman_state(
+                    Path::new("/var/lib/containers/storage/overlay-containers/containers.json"),
+                    &mut names,
+                );
+                if let Some(dirs) = directories::BaseDirs::new() {
+                    read_podman_state(
+                        &dirs
+                            .data_local_dir()
+                            .join("containers/storage/overlay-containers/containers.json"),
+                        &mut names,
+                    );
+                }
+            }
+        }
+
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            names,
+        }
+    }
+}
+
+impl Column for Container {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let id = if let Ok(cgroups) = proc.curr_proc.cgroups() {
+            cgroups
+                .iter()
+                .find(|x| x.hierarchy == 0 && x.controllers.is_empty())
+                .or_else(|| cgroups.last())
+                .and_then(|x| container_id(&x.pathname).map(String::from))
+        } else {
+            None
+        };
+        let fmt_content = match id {
+            Some(id) => match self.names.get(&id) {
+                Some(name) => name.clone(),
+                None => id[..12].to_string(),
+            },
+            None => String::new(),
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+/// The innermost container is used for nested containers
+fn container_id(path: &str) -> Option<&str> {
+    PAT_CONTAINER
+        .captures_iter(path)
+        .last()
+        .and_then(|x| x.get(1))
+        .map(|x| x.as_str())
+}
+
+/// Sockets of Docker and Podman, which serves Docker compatible API
+fn runtime_sockets(docker_path: &str) -> Vec<String> {
+    let mut ret = vec![
+        docker_path.to_string(),
+        String::from("unix:///run/podman/podman.sock"),
+    ];
+    if let Ok(dir) = std::env::var("XDG_RUNTIME_DIR") {
+        ret.push(format!("unix://{dir}/podman/podman.sock"));
+    }
+    ret
+}
+
+#[cfg(feature = "docker")]
+fn query_names(path: &str, names: &mut HashMap<String, String>) -> bool {
+    // dockworker doesn't check the existence of the socket until the first request
+    if !Path::new(path.trim_start_matches("unix://")).exists() {
+        return false;
+    }
+    let docker = if let Ok(x) = dockworker::Docker::connect_with_unix(path) {
+        x
+    } else {
+        return false;
+    };
+    let rt = if let Ok(x) = Runtime::new() {
+        x
+    } else {
+        return false;
+    };
+    if let Ok(cont) = rt.block_on(docker.list_containers(None, None, None, ContainerFilters::new()))
+    {
+        for c in cont {
+            if let Some(name) = c.Names.first() {
+                // remove the first letter '/' from container name
+                names.insert(c.Id, name.trim_start_matches('/').to_string());
+            }
+        }
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(not(feature = "docker"))]
+fn query_names(_path: &str, _names: &mut HashMap<String, String>) -> bool {
+    false
+}
+
+/// Reads `<id>/config.v2.json` of Docker, which is readable by root only.
+fn read_docker_state(dir: &Path, names: &mut HashMap<String, String>) {
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let config = if let Ok(x) = fs::read(entry.path().join("config.v2.json")) {
+            x
+        } else {
+            continue;
+        };
+        if let Ok(config) = serde_json::from_slice::<serde_json::Value>(&config) {
+            if let (Some(id), Some(name)) = (config["ID"].as_str(), config["Name"].as_str()) {
+                names
+                    .entry(id.to_string())
+                    .or_insert_with(|| name.trim_start_matches('/').to_string());
+            }
+        }
+    }
+}
+
+/// Reads `containers.json` of containers/storage, which is shared by Podman, Buildah and CRI-O.
+fn read_podman_state(path: &Path, names: &mut HashMap<String, String>) {
+    let state = if let Ok(x) = fs::read(path) {
+        x
+    } else {
+        return;
+    };
+    if let Ok(serde_json::Value::Array(containers)) = serde_json::from_slice(&state) {
+        for c in containers {
+            if let (Some(id), Some(name)) = (c["id"].as_str(), c["names"][0].as_str()) {
+                names
+                    .entry(id.to_string())
+                    .or_insert_with(|| name.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::container_id;
+
+    #[test]
+    fn test_container_id() {
+        let id = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        for path in [
+            format!("/system.slice/docker-{id}.scope"),
+            format!("/docker/{id}"),
+            format!("/user.slice/user-1000.slice/user@1000.service/user.slice/libpod-{id}.scope/container"),
+            format!("/kubepods.slice/kubepods-pod1.slice/cri-containerd-{id}.scope"),
+        ] {
+            assert_eq!(container_id(&path), Some(id));
+        }
+        assert_eq!(container_id("/user.slice/user-1000.slice/session-1.scope"), None);
+    }
+}
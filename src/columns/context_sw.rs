@@ -1,27 +1,29 @@
 use crate::process::ProcessInfo;
 use crate::util::bytify;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents, ValueFormat};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct ContextSw {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, u64>,
     width: usize,
+    unit_suffix: bool,
 }
 
 impl ContextSw {
-    pub fn new(header: Option<String>) -> Self {
+    pub fn new(header: Option<String>, unit_suffix: bool) -> Self {
         let header = header.unwrap_or_else(|| String::from("ContextSw"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
             unit,
+            unit_suffix,
         }
     }
 }
@@ -29,16 +31,11 @@ impl ContextSw {
 #[cfg(any(target_os = "linux", target_os = "android"))]
 impl Column for ContextSw {
     fn add(&mut self, proc: &ProcessInfo) {
-        let (fmt_content, raw_content) = if let Some(ref status) = proc.curr_status {
-            if status.voluntary_ctxt_switches.is_some()
-                && status.nonvoluntary_ctxt_switches.is_some()
-            {
-                let sw = status.voluntary_ctxt_switches.unwrap()
-                    + status.nonvoluntary_ctxt_switches.unwrap();
-                (bytify(sw), sw)
-            } else {
-                (String::new(), 0)
-            }
+        let sw = proc.curr_status.as_ref().and_then(|status| {
+            Some(status.voluntary_ctxt_switches? + status.nonvoluntary_ctxt_switches?)
+        });
+        let (fmt_content, raw_content) = if let Some(sw) = sw {
+            (bytify(sw, self.unit_suffix), sw)
         } else {
             (String::new(), 0)
         };
@@ -47,6 +44,10 @@ impl Column for ContextSw {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
 
@@ -54,12 +55,16 @@ impl Column for ContextSw {
 impl Column for ContextSw {
     fn add(&mut self, proc: &ProcessInfo) {
         let raw_content = proc.curr_task.ptinfo.pti_csw as u64;
-        let fmt_content = bytify(raw_content);
+        let fmt_content = bytify(raw_content, self.unit_suffix);
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
 
@@ -68,11 +73,15 @@ impl Column for ContextSw {
     fn add(&mut self, proc: &ProcessInfo) {
         let raw_content =
             (proc.curr_proc.info.rusage.nvcsw + proc.curr_proc.info.rusage.nivcsw) as u64;
-        let fmt_content = bytify(raw_content);
+        let fmt_content = bytify(raw_content, self.unit_suffix);
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
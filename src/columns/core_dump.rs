@@ -0,0 +1,74 @@
+use crate::process::{is_local_procfs, procfs_root, ProcessInfo};
+use crate::util::bytify;
+use crate::{column_default, Column, Contents, ValueFormat};
+use procfs::process::LimitValue;
+use std::cmp;
+use std::collections::HashMap;
+use std::fs;
+
+pub struct CoreDump {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    suid_dumpable: bool,
+    unit_suffix: bool,
+}
+
+impl CoreDump {
+    pub fn new(header: Option<String>, unit_suffix: bool) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Core Dump"));
+        let unit = String::new();
+        // Undumpable processes are dumped as root if `fs.suid_dumpable` is 2 ( suidsafe )
+        let suid_dumpable = fs::read_to_string(procfs_root().join("sys/fs/suid_dumpable"))
+            .map(|x| x.trim() == "2")
+            .unwrap_or(false);
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            suid_dumpable,
+            unit_suffix,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for CoreDump {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = if let Ok(limits) = proc.curr_proc.limits() {
+            let limit = match limits.max_core_file_size.soft_limit {
+                LimitValue::Unlimited => String::from("unlimited"),
+                LimitValue::Value(x) => bytify(x, self.unit_suffix),
+            };
+            let euid = proc.curr_status.as_ref().map(|x| x.euid);
+            match (euid, proc.curr_proc.dump_owner()) {
+                // Entries of /proc/<pid> of an undumpable process are owned by root instead of the effective UID.
+                // The owner of a copied /proc tree is meaningless, and root processes can't be distinguished.
+                (Some(euid), Some(0)) if is_local_procfs() && euid != 0 => {
+                    if self.suid_dumpable {
+                        format!("suid {limit}")
+                    } else {
+                        format!("no {limit}")
+                    }
+                }
+                _ => format!("yes {limit}"),
+            }
+        } else {
+            String::new()
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
+    column_default!(String);
+}
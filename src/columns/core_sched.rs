@@ -0,0 +1,73 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+// from include/uapi/linux/prctl.h
+const PR_SCHED_CORE: libc::c_int = 62;
+const PR_SCHED_CORE_GET: libc::c_int = 0;
+const PIDTYPE_PID: libc::c_int = 0;
+
+pub struct CoreSched {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+}
+
+impl CoreSched {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("CoreSched"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for CoreSched {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // The cookie can't be got if PID is not in the PID namespace of self
+        let cookie = if crate::process::is_local_procfs() {
+            get_cookie(proc.pid)
+        } else {
+            None
+        };
+
+        // Cookie 0 means that the process doesn't belong to any core scheduling group
+        let (fmt_content, raw_content) = match cookie {
+            Some(x) if x != 0 => (format!("{x:#x}"), x),
+            _ => (String::new(), 0),
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u64);
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_cookie(pid: i32) -> Option<u64> {
+    let mut cookie: u64 = 0;
+    let ret = unsafe {
+        libc::prctl(
+            PR_SCHED_CORE,
+            PR_SCHED_CORE_GET,
+            pid as libc::c_ulong,
+            PIDTYPE_PID as libc::c_ulong,
+            &mut cookie as *mut u64 as libc::c_ulong,
+        )
+    };
+    if ret == 0 {
+        Some(cookie)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,102 @@
+use crate::process::{ProcessInfo, ProcessTask};
+use crate::{Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+
+pub struct CpuShare {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u32>,
+    width: usize,
+    /// Total of the visible processes, which shares are relative to
+    total: f64,
+    thread_pids: HashSet<i32>,
+}
+
+impl CpuShare {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("CPU Share"));
+        let unit = String::from("[%]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            total: 0.0,
+            header,
+            unit,
+            thread_pids: HashSet::new(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for CpuShare {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let curr_stat = proc.curr_proc.stat();
+        let prev_stat = &proc.prev_stat;
+
+        let curr_time = curr_stat.utime + curr_stat.stime;
+        let prev_time = prev_stat.utime + prev_stat.stime;
+        let usage_ms = (curr_time - prev_time) * 1000 / procfs::ticks_per_second();
+        let interval_ms = proc.interval.as_secs() * 1000 + u64::from(proc.interval.subsec_millis());
+        let usage = usage_ms as f64 * 100.0 / interval_ms as f64;
+
+        // Contents are shares in the visible processes, so they are formatted by `apply_visible`
+        self.raw_contents.insert(proc.pid, (usage * 1000.0) as u32);
+        if let ProcessTask::Task { .. } = proc.curr_proc {
+            self.thread_pids.insert(proc.pid);
+        }
+    }
+
+    fn streamable(&self) -> bool {
+        false
+    }
+
+    fn apply_visible(&mut self, visible_pids: &[i32]) {
+        // Threads are included in the usage of the process, so they are not totaled
+        let total: u64 = visible_pids
+            .iter()
+            .filter(|x| !self.thread_pids.contains(x))
+            .filter_map(|x| self.raw_contents.get(x))
+            .map(|x| u64::from(*x))
+            .sum();
+        self.total = total as f64;
+        self.fmt_contents.clear();
+        for pid in visible_pids {
+            if let Some(x) = self.raw_contents.get(pid) {
+                let share = if total == 0 {
+                    0.0
+                } else {
+                    f64::from(*x) * 100.0 / total as f64
+                };
+                self.fmt_contents.insert(*pid, format!("{share:.1}"));
+            }
+        }
+    }
+
+    fn raw_value(&self, pid: i32) -> Option<f64> {
+        let x = self.raw_contents.get(&pid)?;
+        self.fmt_contents.get(&pid)?;
+        if self.total == 0.0 {
+            Some(0.0)
+        } else {
+            Some(f64::from(*x) * 100.0 / self.total)
+        }
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_find_exact!();
+    crate::column_default_sorted_pid!(u32);
+    crate::column_default_clear!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
@@ -1,12 +1,12 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, util, Column};
+use crate::{column_default, util, Column, Contents, ValueFormat};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct CpuTime {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, u64>,
     width: usize,
 }
@@ -16,7 +16,7 @@ impl CpuTime {
         let header = header.unwrap_or_else(|| String::from("CPU Time"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -38,6 +38,10 @@ impl Column for CpuTime {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Time
+    }
+
     column_default!(u64);
 }
 
@@ -55,6 +59,10 @@ impl Column for CpuTime {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Time
+    }
+
     column_default!(u64);
 }
 
@@ -70,6 +78,10 @@ impl Column for CpuTime {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Time
+    }
+
     column_default!(u64);
 }
 
@@ -89,5 +101,9 @@ impl Column for CpuTime {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Time
+    }
+
     column_default!(u64);
 }
@@ -0,0 +1,103 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, util, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct CpuTimeSys {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+}
+
+impl CpuTimeSys {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Sys Time"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for CpuTimeSys {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let time_sec = proc.curr_proc.stat().stime / procfs::ticks_per_second();
+
+        let fmt_content = util::parse_time(time_sec);
+        let raw_content = time_sec;
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Time
+    }
+
+    column_default!(u64);
+}
+
+#[cfg(target_os = "macos")]
+impl Column for CpuTimeSys {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let time_sec = proc.curr_task.ptinfo.pti_total_system / 1_000_000_000u64;
+
+        let fmt_content = util::parse_time(time_sec);
+        let raw_content = time_sec;
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Time
+    }
+
+    column_default!(u64);
+}
+
+#[cfg(target_os = "windows")]
+impl Column for CpuTimeSys {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let time_sec = proc.cpu_info.curr_sys / 10_000_000u64;
+
+        let fmt_content = util::parse_time(time_sec);
+        let raw_content = time_sec;
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Time
+    }
+
+    column_default!(u64);
+}
+
+#[cfg(target_os = "freebsd")]
+impl Column for CpuTimeSys {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let time_sec = (proc.curr_proc.info.rusage.stime.sec
+            + proc.curr_proc.info.rusage.stime.usec / 1_000_000) as u64;
+
+        let fmt_content = util::parse_time(time_sec);
+        let raw_content = time_sec;
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Time
+    }
+
+    column_default!(u64);
+}
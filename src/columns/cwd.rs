@@ -0,0 +1,123 @@
+use crate::process::ProcessInfo;
+use crate::util::abbr_path;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+pub struct Cwd {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    home: Option<PathBuf>,
+}
+
+impl Cwd {
+    pub fn new(header: Option<String>, abbr: bool) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Cwd"));
+        let unit = String::new();
+        // Home directory of self is not meaningful for other procfs
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let abbr = abbr && crate::process::is_local_procfs();
+        let home = if abbr {
+            directories::BaseDirs::new().map(|x| x.home_dir().to_path_buf())
+        } else {
+            None
+        };
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            home,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for Cwd {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let raw_content = if let Ok(x) = proc.curr_proc.cwd() {
+            x.to_string_lossy().into_owned()
+        } else {
+            String::new()
+        };
+        let fmt_content = abbr_path(&raw_content, self.home.as_deref());
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+#[cfg(target_os = "macos")]
+impl Column for Cwd {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let raw_content = get_cwd(proc.pid).unwrap_or_default();
+        let fmt_content = abbr_path(&raw_content, self.home.as_deref());
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+#[cfg(target_os = "macos")]
+fn get_cwd(pid: i32) -> Option<String> {
+    let mut info: libc::proc_vnodepathinfo = unsafe { std::mem::zeroed() };
+    let size = std::mem::size_of::<libc::proc_vnodepathinfo>() as libc::c_int;
+    let ret = unsafe {
+        libc::proc_pidinfo(
+            pid,
+            libc::PROC_PIDVNODEPATHINFO,
+            0,
+            &mut info as *mut libc::proc_vnodepathinfo as *mut libc::c_void,
+            size,
+        )
+    };
+    if ret != size {
+        return None;
+    }
+    let path = unsafe { std::ffi::CStr::from_ptr(info.pvi_cdir.vip_path.as_ptr() as *const libc::c_char) };
+    Some(path.to_string_lossy().into_owned())
+}
+
+#[cfg(target_os = "freebsd")]
+impl Column for Cwd {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let raw_content = get_cwd(proc.pid).unwrap_or_default();
+        let fmt_content = abbr_path(&raw_content, self.home.as_deref());
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+#[cfg(target_os = "freebsd")]
+fn get_cwd(pid: i32) -> Option<String> {
+    let mut info: libc::kinfo_file = unsafe { std::mem::zeroed() };
+    let mut size = std::mem::size_of::<libc::kinfo_file>();
+    let mut mib = [libc::CTL_KERN, libc::KERN_PROC, libc::KERN_PROC_CWD, pid];
+    let ret = unsafe {
+        libc::sysctl(
+            mib.as_mut_ptr(),
+            mib.len() as libc::c_uint,
+            &mut info as *mut libc::kinfo_file as *mut libc::c_void,
+            &mut size,
+            std::ptr::null(),
+            0,
+        )
+    };
+    if ret != 0 || size == 0 {
+        return None;
+    }
+    let path = unsafe { std::ffi::CStr::from_ptr(info.kf_path.as_ptr()) };
+    Some(path.to_string_lossy().into_owned())
+}
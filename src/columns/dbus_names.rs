@@ -0,0 +1,85 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+use zbus::blocking::fdo::DBusProxy;
+use zbus::blocking::Connection;
+
+pub struct DbusNames {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    names: HashMap<i32, Vec<String>>,
+}
+
+impl DbusNames {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("D-Bus Names"));
+        let unit = String::new();
+
+        // Owners of D-Bus names are PIDs of self, so they can't be matched with other procfs.
+        let mut names: HashMap<i32, Vec<String>> = HashMap::new();
+        if crate::process::is_local_procfs() {
+            for conn in [Connection::system(), Connection::session()]
+                .into_iter()
+                .flatten()
+            {
+                collect_names(&conn, &mut names);
+            }
+        }
+        for x in names.values_mut() {
+            x.sort();
+            x.dedup();
+        }
+
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            names,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for DbusNames {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = if let Some(names) = self.names.get(&proc.pid) {
+            names.join(",")
+        } else {
+            String::new()
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+fn collect_names(conn: &Connection, names: &mut HashMap<i32, Vec<String>>) {
+    let proxy = if let Ok(x) = DBusProxy::new(conn) {
+        x
+    } else {
+        return;
+    };
+    if let Ok(list) = proxy.list_names() {
+        for name in list {
+            // Unique names ( ex. :1.23 ) are not meaningful for users
+            if name.starts_with(':') || name.as_str() == "org.freedesktop.DBus" {
+                continue;
+            }
+            if let Ok(pid) = proxy.get_connection_unix_process_id(name.inner().clone()) {
+                names
+                    .entry(pid as i32)
+                    .or_default()
+                    .push(name.as_str().to_string());
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::baseline::{self, Baseline, Match, Sample};
+use crate::process::{ProcessInfo, ProcessTask};
+use crate::util::bytify;
+use crate::{column_default, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct DeltaRss {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, i64>,
+    width: usize,
+    unit_suffix: bool,
+    baseline: Option<&'static Baseline>,
+    boot_time: u64,
+    samples: Vec<Sample>,
+}
+
+impl DeltaRss {
+    pub fn new(header: Option<String>, unit_suffix: bool) -> Self {
+        let header = header.unwrap_or_else(|| String::from("ΔRSS"));
+        let unit = String::from("[bytes]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            unit_suffix,
+            baseline: baseline::get(),
+            boot_time: baseline::boot_time_secs(),
+            samples: Vec::new(),
+        }
+    }
+}
+
+impl Column for DeltaRss {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // Threads are not recorded in the baseline
+        if let (Some(baseline), ProcessTask::Process { .. }) = (self.baseline, &proc.curr_proc) {
+            self.samples.push(baseline.sample(proc, self.boot_time));
+        }
+    }
+
+    fn streamable(&self) -> bool {
+        false
+    }
+
+    fn finish(&mut self) {
+        let baseline = if let Some(x) = self.baseline {
+            x
+        } else {
+            return;
+        };
+        let matches = baseline.compare(&self.samples).matches;
+        for s in &self.samples {
+            // New processes are shown as empty
+            let (raw_content, fmt_content) = match matches.get(&s.pid) {
+                Some(Match::Same(e) | Match::Restarted(e)) => {
+                    let delta = s.rss as i64 - e.rss as i64;
+                    let sign = if delta > 0 { "+" } else if delta < 0 { "-" } else { "" };
+                    let fmt = bytify(delta.unsigned_abs(), self.unit_suffix);
+                    (delta, format!("{sign}{fmt}"))
+                }
+                _ => (0, String::new()),
+            };
+            self.fmt_contents.insert(s.pid, fmt_content);
+            self.raw_contents.insert(s.pid, raw_content);
+        }
+    }
+
+    fn available(&self) -> bool {
+        self.baseline.is_some()
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
+    column_default!(i64);
+}
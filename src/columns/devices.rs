@@ -0,0 +1,90 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use procfs::process::FDTarget;
+use std::cmp;
+use std::collections::HashMap;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use std::os::unix::fs::FileTypeExt;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use std::path::Path;
+
+/// Devices which almost all processes open, so they are not shown
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const IGNORED_DEVICES: &[&str] = &[
+    "/dev/null",
+    "/dev/zero",
+    "/dev/full",
+    "/dev/random",
+    "/dev/urandom",
+    "/dev/tty",
+    "/dev/ptmx",
+    "/dev/console",
+];
+
+pub struct Devices {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+}
+
+impl Devices {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Devices"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for Devices {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = if let Ok(fds) = proc.curr_proc.fd() {
+            let mut devices = Vec::new();
+            for fd in fds {
+                if let FDTarget::Path(x) = fd.target {
+                    if is_char_device(&x) {
+                        devices.push(x.to_string_lossy().into_owned());
+                    }
+                }
+            }
+            devices.sort();
+            devices.dedup();
+            devices.join(",")
+        } else {
+            String::new()
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn is_char_device(path: &Path) -> bool {
+    if !path.starts_with("/dev")
+        || path.starts_with("/dev/pts")
+        || IGNORED_DEVICES.iter().any(|x| path == Path::new(x))
+    {
+        return false;
+    }
+    if crate::process::is_local_procfs() {
+        std::fs::metadata(path)
+            .map(|x| x.file_type().is_char_device())
+            .unwrap_or(false)
+    } else {
+        // Devices of other systems can't be checked, so directories for files are excluded only
+        !path.starts_with("/dev/shm") && !path.starts_with("/dev/mqueue")
+    }
+}
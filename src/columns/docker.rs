@@ -1,5 +1,5 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents};
 use dockworker::container::ContainerFilters;
 use std::cmp;
 use std::collections::HashMap;
@@ -8,7 +8,7 @@ use tokio::runtime::Runtime;
 pub struct Docker {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
     #[cfg(any(target_os = "linux", target_os = "android"))]
@@ -42,7 +42,7 @@ impl Docker {
             available = false;
         }
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -83,7 +83,7 @@ impl Docker {
             available = false;
         }
         Docker {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -1,12 +1,12 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct Empty {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
 }
@@ -16,7 +16,7 @@ impl Empty {
         let header = String::new();
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
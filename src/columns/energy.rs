@@ -0,0 +1,107 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::process::WakeupSample;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use std::sync::Arc;
+
+/// CPU usage [%] equivalent to 1 wakeup per second
+const WAKEUP_COST: f64 = 0.02;
+
+/// Estimates energy impact from CPU usage [%] and wakeups per second.
+fn energy_impact(usage: f64, wakeups: f64) -> f64 {
+    usage + wakeups * WAKEUP_COST
+}
+
+pub struct Energy {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    prev_wakeups: Arc<WakeupSample>,
+}
+
+impl Energy {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Energy"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            // The column is created before collecting processes,
+            // so the wakeup count of all processes is sampled here as the previous value.
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            prev_wakeups: crate::process::wakeup_sample(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for Energy {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let curr_stat = proc.curr_proc.stat();
+        let prev_stat = &proc.prev_stat;
+
+        let curr_time = curr_stat.utime + curr_stat.stime;
+        let prev_time = prev_stat.utime + prev_stat.stime;
+        let usage_ms = (curr_time - prev_time) * 1000 / procfs::ticks_per_second();
+        let interval_ms = proc.interval.as_secs() * 1000 + u64::from(proc.interval.subsec_millis());
+        let usage = usage_ms as f64 * 100.0 / interval_ms as f64;
+
+        let wakeups = self.prev_wakeups.rate(proc).unwrap_or(0.0);
+
+        let energy = energy_impact(usage, wakeups);
+        let fmt_content = format!("{energy:.1}");
+        let raw_content = (energy * 1000.0) as u64;
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u64, 1000.0);
+}
+
+#[cfg(target_os = "macos")]
+impl Column for Energy {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let curr_time =
+            proc.curr_task.ptinfo.pti_total_user + proc.curr_task.ptinfo.pti_total_system;
+        let prev_time =
+            proc.prev_task.ptinfo.pti_total_user + proc.prev_task.ptinfo.pti_total_system;
+        let usage_ms = (curr_time - prev_time) / 1000000u64;
+        let interval_ms = proc.interval.as_secs() * 1000 + u64::from(proc.interval.subsec_millis());
+        let usage = usage_ms as f64 * 100.0 / interval_ms as f64;
+
+        let wakeups = if let (Some(curr), Some(prev)) = (&proc.curr_res, &proc.prev_res) {
+            let curr = curr.ri_pkg_idle_wkups + curr.ri_interrupt_wkups;
+            let prev = prev.ri_pkg_idle_wkups + prev.ri_interrupt_wkups;
+            curr.saturating_sub(prev) as f64 * 1000.0 / interval_ms as f64
+        } else {
+            0.0
+        };
+
+        let energy = energy_impact(usage, wakeups);
+        let fmt_content = format!("{:.1}", energy);
+        let raw_content = (energy * 1000.0) as u64;
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u64, 1000.0);
+}
@@ -1,12 +1,12 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct Env {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
 }
@@ -16,7 +16,7 @@ impl Env {
         let header = header.unwrap_or_else(|| String::from("Env"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -29,7 +29,7 @@ impl Env {
 impl Column for Env {
     fn add(&mut self, proc: &ProcessInfo) {
         let mut fmt_content = String::new();
-        if let Ok(proc) = procfs::process::Process::new(proc.pid) {
+        if let Ok(proc) = crate::process::open_process(proc.pid) {
             if let Ok(envs) = proc.environ() {
                 for (k, v) in envs {
                     fmt_content.push_str(&format!(
@@ -0,0 +1,71 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct EnvVar {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    name: String,
+}
+
+impl EnvVar {
+    pub fn new(header: Option<String>, name: &str) -> Self {
+        let header = header.unwrap_or_else(|| String::from(name));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            name: String::from(name),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for EnvVar {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = crate::process::open_process(proc.pid)
+            .and_then(|x| x.environ())
+            .ok()
+            .and_then(|x| {
+                x.into_iter()
+                    .find(|(k, _)| k.as_os_str() == self.name.as_str())
+                    .map(|(_, v)| v.to_string_lossy().into_owned())
+            })
+            .unwrap_or_default();
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+#[cfg(target_os = "freebsd")]
+impl Column for EnvVar {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = proc
+            .curr_proc
+            .env
+            .iter()
+            .find_map(|x| {
+                x.split_once('=')
+                    .filter(|(k, _)| *k == self.name)
+                    .map(|(_, v)| v.to_string())
+            })
+            .unwrap_or_default();
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
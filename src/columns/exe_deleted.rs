@@ -0,0 +1,48 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct ExeDeleted {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, bool>,
+    width: usize,
+}
+
+impl ExeDeleted {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Deleted"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+impl Column for ExeDeleted {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // The link target has the suffix if the executable was removed or replaced by an upgrade.
+        // exe of other users' processes can't be read without privilege.
+        let raw_content = proc
+            .curr_proc
+            .exe()
+            .map(|x| x.to_string_lossy().ends_with(" (deleted)"))
+            .unwrap_or_default();
+        let fmt_content = if raw_content {
+            String::from("deleted")
+        } else {
+            String::new()
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(bool);
+}
@@ -0,0 +1,101 @@
+use crate::column::Samples;
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use sha2::{Digest, Sha256};
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::os::unix::fs::MetadataExt;
+use std::time::Instant;
+
+/// Executables are identified by device and inode
+type FileId = (u64, u64);
+
+/// Modification time and size to detect replaced contents
+type FileStamp = (i64, i64, u64);
+
+pub struct ExeHash {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    cache: HashMap<FileId, (FileStamp, String)>,
+    seen: HashSet<FileId>,
+    samples: Samples<String>,
+}
+
+impl ExeHash {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("SHA-256"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            cache: HashMap::new(),
+            seen: HashSet::new(),
+            samples: Samples::new(),
+        }
+    }
+
+    fn hash(&mut self, proc: &ProcessInfo) -> Option<String> {
+        // exe of other users' processes can't be read without privilege
+        let mut file = proc.curr_proc.exe_file().ok()?;
+        let meta = file.metadata().ok()?;
+        let id = (meta.dev(), meta.ino());
+        let stamp = (meta.mtime(), meta.mtime_nsec(), meta.size());
+        self.seen.insert(id);
+
+        // Many processes share the same executable, so each file is hashed once while it isn't modified
+        if let Some((x, hash)) = self.cache.get(&id) {
+            if *x == stamp {
+                return Some(hash.clone());
+            }
+        }
+
+        let mut hasher = Sha256::new();
+        io::copy(&mut file, &mut hasher).ok()?;
+        let hash = hasher
+            .finalize()
+            .iter()
+            .map(|x| format!("{x:02x}"))
+            .collect::<String>();
+        self.cache.insert(id, (stamp, hash.clone()));
+        Some(hash)
+    }
+}
+
+impl Column for ExeHash {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // The last hash is shown if the executable can't be read any more ( ex. unmounted )
+        let start = proc.curr_proc.stat().starttime;
+        let hash = self.hash(proc);
+        let fmt_content = self
+            .samples
+            .update(proc.pid, start, hash)
+            .unwrap_or_default();
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn finish(&mut self) {
+        let seen = std::mem::take(&mut self.seen);
+        self.cache.retain(|id, _| seen.contains(id));
+        self.samples.finish();
+    }
+
+    fn persistent(&self) -> bool {
+        true
+    }
+
+    fn stale_since(&self, pid: i32) -> Option<Instant> {
+        self.samples.stale_since(pid)
+    }
+
+    column_default!(String);
+}
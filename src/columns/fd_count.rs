@@ -0,0 +1,43 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct FdCount {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, Option<usize>>,
+    width: usize,
+}
+
+impl FdCount {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("FDs"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+impl Column for FdCount {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // Processes of other users can't be read without privilege, so they are sorted before 0
+        let raw_content = proc.curr_proc.fd_count().ok();
+        let fmt_content = if let Some(x) = raw_content {
+            format!("{x}")
+        } else {
+            String::new()
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(Option<usize>);
+}
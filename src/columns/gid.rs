@@ -1,14 +1,14 @@
 use crate::process::ProcessInfo;
 #[cfg(target_os = "windows")]
 use crate::util::format_sid;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct Gid {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, u32>,
     width: usize,
     #[allow(dead_code)]
@@ -20,7 +20,7 @@ impl Gid {
         let header = header.unwrap_or_else(|| String::from("GID"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
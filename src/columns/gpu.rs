@@ -0,0 +1,55 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Gpu {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u32>,
+    width: usize,
+    usage: HashMap<i32, u32>,
+}
+
+impl Gpu {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("GPU"));
+        let unit = String::from("[%]");
+
+        // PIDs reported by NVML are of the host, so they can't be matched with other procfs.
+        let usage = if crate::process::is_local_procfs() {
+            crate::gpu::gpu_usage()
+                .into_iter()
+                .filter_map(|(pid, x)| x.util.map(|x| (pid, x)))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            usage,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for Gpu {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let (fmt_content, raw_content) = if let Some(x) = self.usage.get(&proc.pid) {
+            (format!("{x}"), *x)
+        } else {
+            (String::new(), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u32);
+}
@@ -0,0 +1,62 @@
+use crate::process::ProcessInfo;
+use crate::util::bytify;
+use crate::{column_default, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct GpuMem {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    usage: HashMap<i32, u64>,
+    unit_suffix: bool,
+}
+
+impl GpuMem {
+    pub fn new(header: Option<String>, unit_suffix: bool) -> Self {
+        let header = header.unwrap_or_else(|| String::from("GPU Mem"));
+        let unit = String::from("[bytes]");
+
+        // PIDs reported by NVML are of the host, so they can't be matched with other procfs.
+        let usage = if crate::process::is_local_procfs() {
+            crate::gpu::gpu_usage()
+                .into_iter()
+                .filter_map(|(pid, x)| x.memory.map(|x| (pid, x)))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            usage,
+            unit_suffix,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for GpuMem {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let (fmt_content, raw_content) = if let Some(x) = self.usage.get(&proc.pid) {
+            (bytify(*x, self.unit_suffix), *x)
+        } else {
+            (String::new(), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
+    column_default!(u64);
+}
@@ -2,17 +2,15 @@ use crate::process::ProcessInfo;
 #[cfg(target_os = "windows")]
 use crate::util::format_sid;
 #[cfg(not(target_os = "windows"))]
-use crate::util::USERS_CACHE;
-use crate::{column_default, Column};
+use crate::util::get_group_by_gid;
+use crate::{column_default, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
-#[cfg(not(target_os = "windows"))]
-use uzers::Groups;
 
 pub struct Group {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
     #[allow(dead_code)]
@@ -24,7 +22,7 @@ impl Group {
         let header = header.unwrap_or_else(|| String::from("Group"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -39,7 +37,7 @@ impl Column for Group {
     fn add(&mut self, proc: &ProcessInfo) {
         let fmt_content = if let Some(ref status) = proc.curr_status {
             let gid = status.egid;
-            if let Some(group) = USERS_CACHE.with(|x| x.borrow_mut().get_group_by_gid(gid)) {
+            if let Some(group) = get_group_by_gid(gid) {
                 format!("{}", group.name().to_string_lossy())
             } else {
                 format!("{gid}")
@@ -61,7 +59,7 @@ impl Column for Group {
     fn add(&mut self, proc: &ProcessInfo) {
         let gid = proc.curr_task.pbsd.pbi_gid;
         let fmt_content =
-            if let Some(group) = USERS_CACHE.with(|x| x.borrow_mut().get_group_by_gid(gid)) {
+            if let Some(group) = get_group_by_gid(gid) {
                 format!("{}", group.name().to_string_lossy())
             } else {
                 format!("{}", gid)
@@ -106,7 +104,7 @@ impl Column for Group {
     fn add(&mut self, proc: &ProcessInfo) {
         let gid = proc.curr_proc.info.svgid;
         let fmt_content =
-            if let Some(group) = USERS_CACHE.with(|x| x.borrow_mut().get_group_by_gid(gid)) {
+            if let Some(group) = get_group_by_gid(gid) {
                 format!("{}", group.name().to_string_lossy())
             } else {
                 format!("{gid}")
@@ -1,14 +1,13 @@
 use crate::process::ProcessInfo;
-use crate::util::USERS_CACHE;
-use crate::{column_default, Column};
+use crate::util::get_group_by_gid;
+use crate::{column_default, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
-use uzers::Groups;
 
 pub struct GroupReal {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
 }
@@ -18,7 +17,7 @@ impl GroupReal {
         let header = header.unwrap_or_else(|| String::from("Real Group"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -32,7 +31,7 @@ impl Column for GroupReal {
     fn add(&mut self, proc: &ProcessInfo) {
         let fmt_content = if let Some(ref status) = proc.curr_status {
             let gid = status.rgid;
-            if let Some(group) = USERS_CACHE.with(|x| x.borrow_mut().get_group_by_gid(gid)) {
+            if let Some(group) = get_group_by_gid(gid) {
                 format!("{}", group.name().to_string_lossy())
             } else {
                 format!("{gid}")
@@ -54,7 +53,7 @@ impl Column for GroupReal {
     fn add(&mut self, proc: &ProcessInfo) {
         let gid = proc.curr_task.pbsd.pbi_rgid;
         let fmt_content =
-            if let Some(group) = USERS_CACHE.with(|x| x.borrow_mut().get_group_by_gid(gid)) {
+            if let Some(group) = get_group_by_gid(gid) {
                 format!("{}", group.name().to_string_lossy())
             } else {
                 format!("{}", gid)
@@ -73,7 +72,7 @@ impl Column for GroupReal {
     fn add(&mut self, proc: &ProcessInfo) {
         let gid = proc.curr_proc.info.rgid;
         let fmt_content =
-            if let Some(group) = USERS_CACHE.with(|x| x.borrow_mut().get_group_by_gid(gid)) {
+            if let Some(group) = get_group_by_gid(gid) {
                 format!("{}", group.name().to_string_lossy())
             } else {
                 format!("{gid}")
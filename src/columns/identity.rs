@@ -0,0 +1,88 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Identity {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+}
+
+impl Identity {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Identity"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for Identity {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = identity(proc);
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+/// Builds the identity of the process which is kept across restarts.
+pub fn identity(proc: &ProcessInfo) -> String {
+    let unit = proc.curr_proc.cgroups().ok().and_then(|cgroups| {
+        cgroups
+            .iter()
+            .find(|x| x.hierarchy == 0)
+            .or_else(|| cgroups.last())
+            .and_then(|x| systemd_unit(&x.pathname))
+    });
+    let exe = if let Ok(x) = proc.curr_proc.exe() {
+        x.to_string_lossy().into_owned()
+    } else {
+        format!("[{}]", proc.curr_proc.stat().comm)
+    };
+    let argv = proc.curr_proc.cmdline().unwrap_or_default();
+
+    let mut ret = String::new();
+    if let Some(unit) = unit {
+        ret.push_str(&unit);
+        ret.push(':');
+    }
+    ret.push_str(&exe);
+    ret.push_str(&format!("#{:08x}", argv_hash(&argv)));
+    ret
+}
+
+/// Gets the innermost systemd unit ( ex. `nginx.service` ) from the cgroup path.
+fn systemd_unit(path: &str) -> Option<String> {
+    const SUFFIXES: &[&str] = &[".service", ".scope", ".socket", ".mount", ".swap"];
+    path.rsplit('/')
+        .find(|x| SUFFIXES.iter().any(|s| x.ends_with(s)))
+        .map(|x| x.to_string())
+}
+
+/// Hashes argv by 32bit FNV-1a.
+///
+/// `DefaultHasher` is not stable across Rust releases, so a fixed algorithm is used to keep identities comparable.
+fn argv_hash(argv: &[String]) -> u32 {
+    let mut hash: u32 = 0x811c9dc5;
+    for arg in argv {
+        // NUL separates arguments as /proc/<pid>/cmdline
+        for b in arg.bytes().chain(std::iter::once(0)) {
+            hash ^= u32::from(b);
+            hash = hash.wrapping_mul(0x01000193);
+        }
+    }
+    hash
+}
@@ -0,0 +1,56 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use procfs::process::FDTarget;
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct InotifyWatches {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+}
+
+impl InotifyWatches {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Inotify"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for InotifyWatches {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let (fmt_content, raw_content) = if let Ok(fds) = proc.curr_proc.fd() {
+            let mut watches = 0;
+            for fd in fds {
+                if matches!(fd.target, FDTarget::AnonInode(ref x) if x == "inotify") {
+                    // Each watch is shown as a line of "inotify wd:..." in fdinfo
+                    if let Some(info) = crate::process::read_fdinfo(proc.pid, fd.fd) {
+                        watches += info
+                            .lines()
+                            .filter(|x| x.starts_with("inotify wd:"))
+                            .count() as u64;
+                    }
+                }
+            }
+            (format!("{watches}"), watches)
+        } else {
+            (String::new(), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u64);
+}
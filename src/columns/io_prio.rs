@@ -0,0 +1,69 @@
+use crate::process::ProcessInfo;
+use crate::{column_default_text, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+const IOPRIO_CLASS_SHIFT: libc::c_int = 13;
+const IOPRIO_CLASS_NONE: libc::c_int = 0;
+const IOPRIO_CLASS_RT: libc::c_int = 1;
+const IOPRIO_CLASS_BE: libc::c_int = 2;
+const IOPRIO_CLASS_IDLE: libc::c_int = 3;
+
+pub struct IoPrio {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u32>,
+    width: usize,
+    local: bool,
+}
+
+impl IoPrio {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("IO Prio"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            // ioprio_get takes PIDs of self, so they can't be matched with other procfs.
+            local: crate::process::is_local_procfs(),
+        }
+    }
+}
+
+impl Column for IoPrio {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let ioprio = if self.local {
+            unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, proc.pid) as libc::c_int }
+        } else {
+            -1
+        };
+
+        // Processes without I/O priority follow the best-effort level derived from nice value
+        let class = ioprio >> IOPRIO_CLASS_SHIFT;
+        let level = if class == IOPRIO_CLASS_NONE {
+            (proc.curr_proc.stat().nice + 20) / 5
+        } else {
+            i64::from(ioprio & 0xff)
+        };
+        let level = level.clamp(0, 7) as u32;
+
+        // Higher priorities are sorted first in descending order
+        let (fmt_content, raw_content) = match class {
+            _ if ioprio < 0 => (String::new(), 0),
+            IOPRIO_CLASS_RT => (format!("rt/{level}"), 30 - level),
+            IOPRIO_CLASS_NONE | IOPRIO_CLASS_BE => (format!("be/{level}"), 20 - level),
+            IOPRIO_CLASS_IDLE => (String::from("idle"), 1),
+            _ => (String::new(), 0),
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default_text!(u32);
+}
@@ -0,0 +1,166 @@
+use crate::columns::unit::systemd_unit;
+use crate::process::ProcessInfo;
+use crate::util::get_user_by_uid;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+const UID_NOT_SET: u32 = 0xffffffff;
+
+/// Commands ( truncated to 15 bytes by the kernel ) of ancestors which start processes on behalf of users,
+/// and the description of origins
+const LAUNCHERS: &[(&str, &str)] = &[
+    ("sshd", "sshd session"),
+    ("sshd-session", "sshd session"),
+    ("cron", "cron"),
+    ("crond", "cron"),
+    ("anacron", "cron"),
+    ("atd", "at"),
+    ("containerd-shim", "container"),
+    ("conmon", "container"),
+    ("gdm-session-wor", "desktop session"),
+    ("sddm-helper", "desktop session"),
+    ("lightdm", "desktop session"),
+    ("login", "console login"),
+    ("tmux: server", "tmux"),
+    ("screen", "screen"),
+];
+
+struct Origin {
+    ppid: i32,
+    comm: String,
+    login: Option<String>,
+    unit: Option<String>,
+}
+
+pub struct LaunchedBy {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    origins: HashMap<i32, Origin>,
+}
+
+impl LaunchedBy {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Launched By"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            origins: HashMap::new(),
+        }
+    }
+
+    fn describe(&self, pid: i32) -> String {
+        let origin = &self.origins[&pid];
+        let user = |x: &str| match &origin.login {
+            Some(login) => format!("{x} of {login}"),
+            None => String::from(x),
+        };
+
+        // The nearest launcher is used, so a cron job started in an SSH session is shown as cron
+        let mut ppid = origin.ppid;
+        let mut depth = 0;
+        while let Some(parent) = self.origins.get(&ppid) {
+            if let Some((_, x)) = LAUNCHERS.iter().find(|(comm, _)| parent.comm == *comm)
+            {
+                return user(x);
+            }
+            // PID namespaces may have a loop of PPIDs
+            depth += 1;
+            if ppid == parent.ppid || depth > 64 {
+                break;
+            }
+            ppid = parent.ppid;
+        }
+
+        if pid == 2 || origin.ppid == 2 {
+            return String::from("kernel");
+        }
+        match origin.unit.as_deref() {
+            Some(x) if x.starts_with("session-") => user("login session"),
+            Some(x) => format!("systemd unit {x}"),
+            None => String::new(),
+        }
+    }
+}
+
+impl Column for LaunchedBy {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let login = proc
+            .curr_proc
+            .loginuid()
+            .ok()
+            .filter(|x| *x != UID_NOT_SET)
+            .map(|uid| {
+                get_user_by_uid(uid)
+                    .map(|x| x.name().to_string_lossy().into_owned())
+                    .unwrap_or_else(|| format!("{uid}"))
+            });
+        let origin = Origin {
+            ppid: proc.ppid,
+            comm: proc.curr_proc.stat().comm.clone(),
+            login,
+            unit: systemd_unit(proc),
+        };
+        self.origins.insert(proc.pid, origin);
+    }
+
+    fn streamable(&self) -> bool {
+        false
+    }
+
+    fn finish(&mut self) {
+        let pids: Vec<_> = self.origins.keys().copied().collect();
+        for pid in pids {
+            let fmt_content = self.describe(pid);
+            let raw_content = fmt_content.clone();
+            self.fmt_contents.insert(pid, fmt_content);
+            self.raw_contents.insert(pid, raw_content);
+        }
+    }
+
+    column_default!(String);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        let mut column = LaunchedBy::new(None);
+        let mut add = |pid, ppid, comm: &str, login: Option<&str>, unit: Option<&str>| {
+            column.origins.insert(
+                pid,
+                Origin {
+                    ppid,
+                    comm: String::from(comm),
+                    login: login.map(String::from),
+                    unit: unit.map(String::from),
+                },
+            );
+        };
+        add(1, 0, "systemd", None, Some("init.scope"));
+        add(2, 0, "kthreadd", None, None);
+        add(3, 2, "kworker/0:0", None, None);
+        add(10, 1, "sshd", None, Some("ssh.service"));
+        add(11, 10, "sshd", Some("alice"), Some("session-1.scope"));
+        add(12, 11, "bash", Some("alice"), Some("session-1.scope"));
+        add(20, 1, "cron", None, Some("cron.service"));
+        add(21, 20, "cron", Some("bob"), Some("session-2.scope"));
+        add(22, 21, "backup.sh", Some("bob"), Some("session-2.scope"));
+        add(30, 1, "nginx", None, Some("nginx.service"));
+
+        assert_eq!(column.describe(3), "kernel");
+        assert_eq!(column.describe(10), "systemd unit ssh.service");
+        assert_eq!(column.describe(12), "sshd session of alice");
+        assert_eq!(column.describe(22), "cron of bob");
+        assert_eq!(column.describe(30), "systemd unit nginx.service");
+    }
+}
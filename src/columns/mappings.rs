@@ -0,0 +1,70 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use procfs::process::MMapPath;
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Mappings {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    warn_count: Option<u64>,
+}
+
+impl Mappings {
+    pub fn new(header: Option<String>, warn: u64) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Mappings"));
+        let unit = String::new();
+
+        // The count of all mappings is limited by vm.max_map_count,
+        // so processes having the ratio of `warn` percent are flagged.
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let warn_count = if warn != 0 {
+            std::fs::read_to_string(crate::process::procfs_root().join("sys/vm/max_map_count"))
+                .ok()
+                .and_then(|x| x.trim().parse::<u64>().ok())
+                .map(|x| x * warn / 100)
+        } else {
+            None
+        };
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        let warn_count = None;
+
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            warn_count,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for Mappings {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let (fmt_content, raw_content) = if let Ok(maps) = proc.curr_proc.maps() {
+            let files = maps
+                .iter()
+                .filter(|x| matches!(x.pathname, MMapPath::Path(_)))
+                .count() as u64;
+            let total = maps.len() as u64;
+            let fmt_content = match self.warn_count {
+                Some(x) if total >= x => format!("{files} !"),
+                _ => format!("{files}"),
+            };
+            (fmt_content, files)
+        } else {
+            (String::new(), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u64);
+}
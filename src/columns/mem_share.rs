@@ -0,0 +1,90 @@
+use crate::process::{ProcessInfo, ProcessTask};
+use crate::{Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct MemShare {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    /// Total of the visible processes, which shares are relative to
+    total: f64,
+}
+
+impl MemShare {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("MEM Share"));
+        let unit = String::from("[%]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            total: 0.0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for MemShare {
+    fn add(&mut self, proc: &ProcessInfo) {
+        use procfs::WithCurrentSystemInfo;
+
+        // Threads share the memory of the process, so they are not counted
+        if let ProcessTask::Process { .. } = proc.curr_proc {
+            let size = proc.curr_proc.stat().rss_bytes().get();
+            self.raw_contents.insert(proc.pid, size);
+        }
+    }
+
+    fn streamable(&self) -> bool {
+        false
+    }
+
+    fn apply_visible(&mut self, visible_pids: &[i32]) {
+        let total: u64 = visible_pids
+            .iter()
+            .filter_map(|x| self.raw_contents.get(x))
+            .sum();
+        self.total = total as f64;
+        self.fmt_contents.clear();
+        for pid in visible_pids {
+            if let Some(x) = self.raw_contents.get(pid) {
+                let share = if total == 0 {
+                    0.0
+                } else {
+                    *x as f64 * 100.0 / total as f64
+                };
+                self.fmt_contents.insert(*pid, format!("{share:.1}"));
+            }
+        }
+    }
+
+    fn raw_value(&self, pid: i32) -> Option<f64> {
+        let x = self.raw_contents.get(&pid)?;
+        self.fmt_contents.get(&pid)?;
+        if self.total == 0.0 {
+            Some(0.0)
+        } else {
+            Some(*x as f64 * 100.0 / self.total)
+        }
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_find_exact!();
+    crate::column_default_sorted_pid!(u64);
+    crate::column_default_clear!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
@@ -0,0 +1,56 @@
+use crate::process::ProcessInfo;
+use crate::util::CounterRate;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct MinFltRate {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    rate: CounterRate,
+}
+
+impl MinFltRate {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("MinorFaults"));
+        let unit = String::from("[/s]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            rate: CounterRate::default(),
+        }
+    }
+}
+
+impl Column for MinFltRate {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let raw_content = self.rate.rate(
+            proc.pid,
+            proc.curr_proc.stat().starttime,
+            proc.curr_proc.stat().minflt,
+            Some(proc.prev_stat.minflt),
+            proc.interval,
+        );
+        let fmt_content = raw_content.map(|x| format!("{x}")).unwrap_or_default();
+        let raw_content = raw_content.unwrap_or_default();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn finish(&mut self) {
+        self.rate.retain(&self.raw_contents);
+    }
+
+    fn persistent(&self) -> bool {
+        true
+    }
+
+    column_default!(u64);
+}
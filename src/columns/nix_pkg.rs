@@ -0,0 +1,104 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+const NIX_STORE: &str = "/nix/store/";
+
+/// Length of the hash part of store paths in base32
+const HASH_LEN: usize = 32;
+
+pub struct NixPkg {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, (String, String)>,
+    width: usize,
+}
+
+impl NixPkg {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Nix Package"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+impl Column for NixPkg {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // exe of other users' processes can't be read without privilege
+        let exe = proc.curr_proc.exe().ok();
+        let pkg = exe
+            .as_ref()
+            .and_then(|x| store_name(&x.to_string_lossy()).map(parse_drv_name));
+        let (name, version) = pkg.unwrap_or_default();
+        let fmt_content = format!("{name} {version}").trim_end().to_string();
+        let raw_content = (name, version);
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!((String, String));
+}
+
+/// Gets the name of the store path without the hash ( ex. `firefox-128.0.3` of `/nix/store/<hash>-firefox-128.0.3/bin/firefox` ).
+fn store_name(path: &str) -> Option<&str> {
+    let entry = path.strip_prefix(NIX_STORE)?.split('/').next()?;
+    let (hash, name) = entry.split_once('-')?;
+    (hash.len() == HASH_LEN && !name.is_empty()).then_some(name)
+}
+
+/// Splits the name into the package name and the version in the same way as `builtins.parseDrvName`,
+/// where the version starts at the first dash followed by a non-letter.
+fn parse_drv_name(name: &str) -> (String, String) {
+    let split = name.char_indices().find(|(i, c)| {
+        *c == '-'
+            && name[i + 1..]
+                .chars()
+                .next()
+                .is_some_and(|x| !x.is_ascii_alphabetic())
+    });
+    match split {
+        Some((i, _)) => (name[..i].to_string(), name[i + 1..].to_string()),
+        None => (name.to_string(), String::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_store_name() {
+        assert_eq!(
+            store_name("/nix/store/0c8wsz7hw5p6kxqbcyj4kfkp1gpzcvqa-firefox-128.0.3/lib/firefox/firefox"),
+            Some("firefox-128.0.3")
+        );
+        assert_eq!(store_name("/nix/store/short-firefox/bin/firefox"), None);
+        assert_eq!(store_name("/usr/bin/firefox"), None);
+    }
+
+    #[test]
+    fn test_parse_drv_name() {
+        let cases = [
+            ("firefox-128.0.3", ("firefox", "128.0.3")),
+            ("gnome-shell-46.2", ("gnome-shell", "46.2")),
+            ("python3-3.11.9-env", ("python3", "3.11.9-env")),
+            ("systemd-minimal-255.9", ("systemd-minimal", "255.9")),
+            ("hello", ("hello", "")),
+        ];
+        for (name, (pkg, version)) in cases {
+            assert_eq!(
+                parse_drv_name(name),
+                (String::from(pkg), String::from(version))
+            );
+        }
+    }
+}
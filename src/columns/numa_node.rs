@@ -0,0 +1,55 @@
+use crate::process::{cpu_node, ProcessInfo};
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct NumaNode {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u32>,
+    width: usize,
+}
+
+impl NumaNode {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("NUMA"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+impl Column for NumaNode {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // Nodes are ordered by allocated pages, so the first one is the main node
+        let mut nodes = proc.curr_proc.numa_pages().unwrap_or_default();
+        nodes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        let mut nodes: Vec<_> = nodes.into_iter().map(|(node, _)| node).collect();
+
+        // numa_maps can't be read without privilege, and threads and kernel threads have no maps,
+        // so the node of the CPU which executed the process last is used instead.
+        if nodes.is_empty() {
+            if let Some(x) = proc.curr_proc.stat().processor.and_then(cpu_node) {
+                nodes.push(x);
+            }
+        }
+
+        let raw_content = nodes.first().copied().unwrap_or_default();
+        let fmt_content = nodes
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u32);
+}
@@ -0,0 +1,42 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct OomScoreAdj {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, i32>,
+    width: usize,
+}
+
+impl OomScoreAdj {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("OOM Adj"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+impl Column for OomScoreAdj {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // Unreadable processes are sorted before -1000 which is the minimum
+        let (fmt_content, raw_content) = if let Ok(adj) = proc.curr_proc.oom_score_adj() {
+            (format!("{adj}"), adj)
+        } else {
+            (String::new(), i32::MIN)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(i32);
+}
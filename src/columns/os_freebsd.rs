@@ -1,9 +1,13 @@
 pub mod command;
 pub mod context_sw;
 pub mod cpu_time;
+pub mod cpu_time_sys;
+pub mod cpu_time_user;
+pub mod cwd;
 pub mod elapsed_time;
 pub mod empty;
 pub mod env;
+pub mod env_var;
 pub mod file_name;
 pub mod gid;
 pub mod gid_real;
@@ -15,6 +19,7 @@ pub mod maj_flt;
 pub mod min_flt;
 pub mod multi_slot;
 pub mod nice;
+pub mod parent_command;
 pub mod pgid;
 pub mod pid;
 pub mod ppid;
@@ -39,6 +44,8 @@ pub mod uid_real;
 pub mod uid_saved;
 pub mod usage_cpu;
 pub mod usage_mem;
+pub mod usage_sys;
+pub mod usage_user;
 pub mod user;
 pub mod user_real;
 pub mod user_saved;
@@ -54,9 +61,13 @@ pub mod write_bytes;
 pub use self::command::Command;
 pub use self::context_sw::ContextSw;
 pub use self::cpu_time::CpuTime;
+pub use self::cpu_time_sys::CpuTimeSys;
+pub use self::cpu_time_user::CpuTimeUser;
+pub use self::cwd::Cwd;
 pub use self::elapsed_time::ElapsedTime;
 pub use self::empty::Empty;
 pub use self::env::Env;
+pub use self::env_var::EnvVar;
 pub use self::file_name::FileName;
 pub use self::gid::Gid;
 pub use self::gid_real::GidReal;
@@ -68,6 +79,7 @@ pub use self::maj_flt::MajFlt;
 pub use self::min_flt::MinFlt;
 pub use self::multi_slot::MultiSlot;
 pub use self::nice::Nice;
+pub use self::parent_command::ParentCommand;
 pub use self::pgid::Pgid;
 pub use self::pid::Pid;
 pub use self::ppid::Ppid;
@@ -92,6 +104,8 @@ pub use self::uid_real::UidReal;
 pub use self::uid_saved::UidSaved;
 pub use self::usage_cpu::UsageCpu;
 pub use self::usage_mem::UsageMem;
+pub use self::usage_sys::UsageSys;
+pub use self::usage_user::UsageUser;
 pub use self::user::User;
 pub use self::user_real::UserReal;
 pub use self::user_saved::UserSaved;
@@ -105,6 +119,7 @@ pub use self::wchan::Wchan;
 pub use self::write_bytes::WriteBytes;
 
 use crate::column::Column;
+use crate::config::ConfigHistory;
 use once_cell::sync::Lazy;
 use serde_derive::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -118,9 +133,14 @@ pub enum ConfigColumnKind {
     Command,
     ContextSw,
     CpuTime,
+    CpuTimeSys,
+    CpuTimeUser,
+    Cwd,
+    Elapsed,
     ElapsedTime,
     Empty,
     Env,
+    EnvVar(String),
     FileName,
     Gid,
     GidReal,
@@ -132,6 +152,7 @@ pub enum ConfigColumnKind {
     MinFlt,
     MultiSlot,
     Nice,
+    ParentCommand,
     Pgid,
     Pid,
     Ppid,
@@ -156,6 +177,8 @@ pub enum ConfigColumnKind {
     UidSaved,
     UsageCpu,
     UsageMem,
+    UsageSys,
+    UsageUser,
     User,
     UserReal,
     UserSaved,
@@ -173,21 +196,34 @@ pub enum ConfigColumnKind {
 // gen_column
 // ---------------------------------------------------------------------------------------------------------------------
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_column(
     kind: &ConfigColumnKind,
     header: Option<String>,
+    unit_suffix: bool,
     _docker_path: &str,
     separator: &str,
     abbr_sid: bool,
+    abbr_path: bool,
+    _abbr_cgroup: bool,
     tree_symbols: &[String; 5],
+    _map_count_warn: u64,
+    _history: &ConfigHistory,
+    _smaps: bool,
+    start_time_format: &str,
 ) -> Box<dyn Column> {
     match kind {
         ConfigColumnKind::Command => Box::new(Command::new(header)),
-        ConfigColumnKind::ContextSw => Box::new(ContextSw::new(header)),
+        ConfigColumnKind::ContextSw => Box::new(ContextSw::new(header, unit_suffix)),
         ConfigColumnKind::CpuTime => Box::new(CpuTime::new(header)),
-        ConfigColumnKind::ElapsedTime => Box::new(ElapsedTime::new(header)),
+        ConfigColumnKind::CpuTimeSys => Box::new(CpuTimeSys::new(header)),
+        ConfigColumnKind::CpuTimeUser => Box::new(CpuTimeUser::new(header)),
+        ConfigColumnKind::Cwd => Box::new(Cwd::new(header, abbr_path)),
+        ConfigColumnKind::Elapsed => Box::new(ElapsedTime::new(header, true)),
+        ConfigColumnKind::ElapsedTime => Box::new(ElapsedTime::new(header, false)),
         ConfigColumnKind::Empty => Box::new(Empty::new()),
         ConfigColumnKind::Env => Box::new(Env::new(header)),
+        ConfigColumnKind::EnvVar(x) => Box::new(EnvVar::new(header, x)),
         ConfigColumnKind::FileName => Box::new(FileName::new(header)),
         ConfigColumnKind::Gid => Box::new(Gid::new(header, abbr_sid)),
         ConfigColumnKind::GidReal => Box::new(GidReal::new(header)),
@@ -199,12 +235,13 @@ pub fn gen_column(
         ConfigColumnKind::MinFlt => Box::new(MinFlt::new(header)),
         ConfigColumnKind::MultiSlot => Box::new(MultiSlot::new()),
         ConfigColumnKind::Nice => Box::new(Nice::new(header)),
+        ConfigColumnKind::ParentCommand => Box::new(ParentCommand::new(header)),
         ConfigColumnKind::Pgid => Box::new(Pgid::new(header)),
         ConfigColumnKind::Pid => Box::new(Pid::new(header)),
         ConfigColumnKind::Ppid => Box::new(Ppid::new(header)),
         ConfigColumnKind::Priority => Box::new(Priority::new(header)),
         ConfigColumnKind::Processor => Box::new(Processor::new(header)),
-        ConfigColumnKind::ReadBytes => Box::new(ReadBytes::new(header)),
+        ConfigColumnKind::ReadBytes => Box::new(ReadBytes::new(header, unit_suffix)),
         ConfigColumnKind::Separator => Box::new(Separator::new(separator)),
         ConfigColumnKind::Session => Box::new(Session::new(header)),
         ConfigColumnKind::ShdPnd => Box::new(ShdPnd::new(header)),
@@ -212,7 +249,7 @@ pub fn gen_column(
         ConfigColumnKind::SigBlk => Box::new(SigBlk::new(header)),
         ConfigColumnKind::SigCgt => Box::new(SigCgt::new(header)),
         ConfigColumnKind::SigIgn => Box::new(SigIgn::new(header)),
-        ConfigColumnKind::StartTime => Box::new(StartTime::new(header)),
+        ConfigColumnKind::StartTime => Box::new(StartTime::new(header, start_time_format)),
         ConfigColumnKind::State => Box::new(State::new(header)),
         ConfigColumnKind::Threads => Box::new(Threads::new(header)),
         ConfigColumnKind::Tree => Box::new(Tree::new(tree_symbols)),
@@ -223,17 +260,19 @@ pub fn gen_column(
         ConfigColumnKind::UidSaved => Box::new(UidSaved::new(header)),
         ConfigColumnKind::UsageCpu => Box::new(UsageCpu::new(header)),
         ConfigColumnKind::UsageMem => Box::new(UsageMem::new(header)),
+        ConfigColumnKind::UsageSys => Box::new(UsageSys::new(header)),
+        ConfigColumnKind::UsageUser => Box::new(UsageUser::new(header)),
         ConfigColumnKind::User => Box::new(User::new(header, abbr_sid)),
         ConfigColumnKind::UserReal => Box::new(UserReal::new(header)),
         ConfigColumnKind::UserSaved => Box::new(UserSaved::new(header)),
-        ConfigColumnKind::VmData => Box::new(VmData::new(header)),
-        ConfigColumnKind::VmExe => Box::new(VmExe::new(header)),
-        ConfigColumnKind::VmHwm => Box::new(VmHwm::new(header)),
-        ConfigColumnKind::VmRss => Box::new(VmRss::new(header)),
-        ConfigColumnKind::VmSize => Box::new(VmSize::new(header)),
-        ConfigColumnKind::VmStack => Box::new(VmStack::new(header)),
+        ConfigColumnKind::VmData => Box::new(VmData::new(header, unit_suffix)),
+        ConfigColumnKind::VmExe => Box::new(VmExe::new(header, unit_suffix)),
+        ConfigColumnKind::VmHwm => Box::new(VmHwm::new(header, unit_suffix)),
+        ConfigColumnKind::VmRss => Box::new(VmRss::new(header, unit_suffix)),
+        ConfigColumnKind::VmSize => Box::new(VmSize::new(header, unit_suffix)),
+        ConfigColumnKind::VmStack => Box::new(VmStack::new(header, unit_suffix)),
         ConfigColumnKind::Wchan => Box::new(Wchan::new(header)),
-        ConfigColumnKind::WriteBytes => Box::new(WriteBytes::new(header)),
+        ConfigColumnKind::WriteBytes => Box::new(WriteBytes::new(header, unit_suffix)),
     }
 }
 
@@ -256,12 +295,20 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::CpuTime,
                 ("CpuTime", "Cumulative CPU time"),
             ),
+            (ConfigColumnKind::CpuTimeSys, ("CpuTimeSys", "Cumulative CPU time in kernel mode")),
+            (ConfigColumnKind::CpuTimeUser, ("CpuTimeUser", "Cumulative CPU time in user mode")),
+            (ConfigColumnKind::Cwd, ("Cwd", "Current working directory")),
+            (ConfigColumnKind::Elapsed, ("Elapsed", "Elapsed time in the same format as CpuTime")),
             (
                 ConfigColumnKind::ElapsedTime,
                 ("ElapsedTime", "Elapsed time"),
             ),
             (ConfigColumnKind::Empty, ("Empty", "Empty")),
             (ConfigColumnKind::Env, ("Env", "Environment variables")),
+            (
+                ConfigColumnKind::EnvVar(String::new()),
+                ("Env:<name>", "Value of the environment variable <name>"),
+            ),
             (ConfigColumnKind::FileName, ("FileName", "File name")),
             (ConfigColumnKind::Gid, ("Gid", "Group ID")),
             (ConfigColumnKind::GidReal, ("GidReal", "Real group ID")),
@@ -288,6 +335,7 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ("MultiSlot", "Slot for `--insert` option"),
             ),
             (ConfigColumnKind::Nice, ("Nice", "Nice value")),
+            (ConfigColumnKind::ParentCommand, ("ParentCommand", "Command of the parent process")),
             (ConfigColumnKind::Pgid, ("Pgid", "Process group ID")),
             (ConfigColumnKind::Pid, ("Pid", "Process ID")),
             (ConfigColumnKind::Ppid, ("Ppid", "Parent process ID")),
@@ -332,6 +380,8 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::UsageMem,
                 ("UsageMem", "Memory utilization"),
             ),
+            (ConfigColumnKind::UsageSys, ("UsageSys", "CPU utilization in kernel mode")),
+            (ConfigColumnKind::UsageUser, ("UsageUser", "CPU utilization in user mode")),
             (ConfigColumnKind::User, ("User", "User name")),
             (ConfigColumnKind::UserReal, ("UserReal", "Real user name")),
             (
@@ -552,6 +602,18 @@ kind = "CpuTime"
 style = "BrightGreen"
 align = "Center"
 [[columns]]
+kind = "CpuTimeSys"
+style = "BrightGreen"
+[[columns]]
+kind = "CpuTimeUser"
+style = "BrightGreen"
+[[columns]]
+kind = "Cwd"
+style = "White"
+[[columns]]
+kind = "Elapsed"
+style = "BrightYellow"
+[[columns]]
 kind = "ElapsedTime"
 style = "BrightYellow"
 [[columns]]
@@ -594,6 +656,10 @@ style = "BrightWhite"
 kind = "Nice"
 style = "Red"
 [[columns]]
+kind = "ParentCommand"
+style = "BrightRed"
+align = "Left"
+[[columns]]
 kind = "Pgid"
 style = "Yellow"
 [[columns]]
@@ -660,6 +726,12 @@ style = "White"
 kind = "UsageMem"
 style = "White"
 [[columns]]
+kind = "UsageSys"
+style = "White"
+[[columns]]
+kind = "UsageUser"
+style = "White"
+[[columns]]
 kind = "User"
 style = "White"
 [[columns]]
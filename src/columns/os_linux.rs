@@ -1,37 +1,92 @@
+pub mod anon_inode;
+pub mod audio;
+pub mod audit;
+pub mod baseline;
+pub mod blocked_signals;
 pub mod ccgroup;
 pub mod cgroup;
+pub mod cgroup_cpu_max;
+pub mod cgroup_mem_max;
 pub mod command;
+pub mod container;
 pub mod context_sw;
+pub mod core_dump;
+pub mod core_sched;
+pub mod cpu_share;
 pub mod cpu_time;
+pub mod cpu_time_sys;
+pub mod cpu_time_user;
+pub mod cwd;
+#[cfg(feature = "dbus")]
+pub mod dbus_names;
+pub mod delta_cpu_time;
+pub mod delta_rss;
+pub mod devices;
 #[cfg(feature = "docker")]
 pub mod docker;
 pub mod eip;
 pub mod elapsed_time;
 pub mod empty;
+pub mod energy;
 pub mod env;
+pub mod env_var;
 pub mod esp;
+pub mod exe_deleted;
+pub mod exe_hash;
+pub mod fd_count;
 pub mod file_name;
 pub mod gid;
 pub mod gid_fs;
 pub mod gid_real;
 pub mod gid_saved;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+#[cfg(feature = "gpu")]
+pub mod gpu_mem;
 pub mod group;
 pub mod group_fs;
 pub mod group_real;
 pub mod group_saved;
+pub mod identity;
+pub mod inotify_watches;
+pub mod io_prio;
+pub mod launched_by;
 pub mod maj_flt;
+pub mod maj_flt_rate;
+pub mod mappings;
+pub mod mem_share;
 pub mod min_flt;
+pub mod min_flt_rate;
+pub mod mnt_ns;
 pub mod multi_slot;
+pub mod net_ns;
 pub mod nice;
+pub mod nix_pkg;
+pub mod numa_node;
+pub mod oom_score;
+pub mod oom_score_adj;
+pub mod parent_command;
+pub mod pending_signals;
 pub mod pgid;
 pub mod pid;
+pub mod pid_ns;
+pub mod pod;
 pub mod policy;
 pub mod ppid;
 pub mod priority;
 pub mod processor;
+pub mod pss;
 pub mod read_bytes;
+pub mod read_rate;
+pub mod restarts;
+pub mod retrans;
+pub mod root_dir;
 pub mod rt_priority;
+pub mod sandbox;
+pub mod sched_policy;
 pub mod sec_context;
+pub mod sec_label;
+pub mod seccomp;
 pub mod separator;
 pub mod session;
 pub mod shd_pnd;
@@ -43,9 +98,13 @@ pub mod slot;
 pub mod ssb;
 pub mod start_time;
 pub mod state;
+pub mod tcp_conn;
 pub mod tcp_port;
 pub mod threads;
+pub mod throttled;
 pub mod tree;
+pub mod tree_cpu;
+pub mod tree_rss;
 pub mod tree_slot;
 pub mod tty;
 pub mod udp_port;
@@ -54,13 +113,18 @@ pub mod uid_fs;
 pub mod uid_login;
 pub mod uid_real;
 pub mod uid_saved;
+pub mod unit;
 pub mod usage_cpu;
 pub mod usage_mem;
+pub mod usage_sys;
+pub mod usage_user;
 pub mod user;
 pub mod user_fs;
 pub mod user_login;
+pub mod user_ns;
 pub mod user_real;
 pub mod user_saved;
+pub mod uss;
 pub mod vm_data;
 pub mod vm_exe;
 pub mod vm_hwm;
@@ -73,44 +137,106 @@ pub mod vm_rss;
 pub mod vm_size;
 pub mod vm_stack;
 pub mod vm_swap;
+pub mod wakeups;
 pub mod wchan;
+#[cfg(feature = "x11")]
+pub mod window_titles;
 pub mod work_dir;
 pub mod write_bytes;
+pub mod write_rate;
+#[cfg(feature = "yara")]
+pub mod yara_hits;
+pub mod zombie_children;
 
+pub use self::anon_inode::AnonInode;
+pub use self::audio::Audio;
+pub use self::audit::Audit;
+pub use self::baseline::Baseline;
+pub use self::blocked_signals::BlockedSignals;
 pub use self::ccgroup::Ccgroup;
 pub use self::cgroup::Cgroup;
+pub use self::cgroup_cpu_max::CgroupCpuMax;
+pub use self::cgroup_mem_max::CgroupMemMax;
 pub use self::command::Command;
+pub use self::container::Container;
 pub use self::context_sw::ContextSw;
+pub use self::core_dump::CoreDump;
+pub use self::core_sched::CoreSched;
+pub use self::cpu_share::CpuShare;
 pub use self::cpu_time::CpuTime;
+pub use self::cpu_time_sys::CpuTimeSys;
+pub use self::cpu_time_user::CpuTimeUser;
+pub use self::cwd::Cwd;
+#[cfg(feature = "dbus")]
+pub use self::dbus_names::DbusNames;
+pub use self::delta_cpu_time::DeltaCpuTime;
+pub use self::delta_rss::DeltaRss;
+pub use self::devices::Devices;
 #[cfg(feature = "docker")]
 pub use self::docker::Docker;
 pub use self::eip::Eip;
 pub use self::elapsed_time::ElapsedTime;
 pub use self::empty::Empty;
+pub use self::energy::Energy;
 pub use self::env::Env;
+pub use self::env_var::EnvVar;
 pub use self::esp::Esp;
+pub use self::exe_deleted::ExeDeleted;
+pub use self::exe_hash::ExeHash;
+pub use self::fd_count::FdCount;
 pub use self::file_name::FileName;
 pub use self::gid::Gid;
 pub use self::gid_fs::GidFs;
 pub use self::gid_real::GidReal;
 pub use self::gid_saved::GidSaved;
+#[cfg(feature = "gpu")]
+pub use self::gpu::Gpu;
+#[cfg(feature = "gpu")]
+pub use self::gpu_mem::GpuMem;
 pub use self::group::Group;
 pub use self::group_fs::GroupFs;
 pub use self::group_real::GroupReal;
 pub use self::group_saved::GroupSaved;
+pub use self::identity::Identity;
+pub use self::inotify_watches::InotifyWatches;
+pub use self::io_prio::IoPrio;
+pub use self::launched_by::LaunchedBy;
 pub use self::maj_flt::MajFlt;
+pub use self::maj_flt_rate::MajFltRate;
+pub use self::mappings::Mappings;
+pub use self::mem_share::MemShare;
 pub use self::min_flt::MinFlt;
+pub use self::min_flt_rate::MinFltRate;
+pub use self::mnt_ns::MntNs;
 pub use self::multi_slot::MultiSlot;
+pub use self::net_ns::NetNs;
 pub use self::nice::Nice;
+pub use self::nix_pkg::NixPkg;
+pub use self::numa_node::NumaNode;
+pub use self::oom_score::OomScore;
+pub use self::oom_score_adj::OomScoreAdj;
+pub use self::parent_command::ParentCommand;
+pub use self::pending_signals::PendingSignals;
 pub use self::pgid::Pgid;
 pub use self::pid::Pid;
+pub use self::pid_ns::PidNs;
+pub use self::pod::Pod;
 pub use self::policy::Policy;
 pub use self::ppid::Ppid;
 pub use self::priority::Priority;
 pub use self::processor::Processor;
+pub use self::pss::Pss;
 pub use self::read_bytes::ReadBytes;
+pub use self::read_rate::ReadRate;
+pub use self::restarts::Restarts;
+pub use self::retrans::Retrans;
+pub use self::root_dir::RootDir;
 pub use self::rt_priority::RtPriority;
+pub use self::sandbox::Sandbox;
+pub use self::sched_policy::SchedPolicy;
 pub use self::sec_context::SecContext;
+pub use self::sec_label::SecLabel;
+pub use self::seccomp::Seccomp;
 pub use self::separator::Separator;
 pub use self::session::Session;
 pub use self::shd_pnd::ShdPnd;
@@ -122,9 +248,13 @@ pub use self::slot::Slot;
 pub use self::ssb::Ssb;
 pub use self::start_time::StartTime;
 pub use self::state::State;
+pub use self::tcp_conn::TcpConn;
 pub use self::tcp_port::TcpPort;
 pub use self::threads::Threads;
+pub use self::throttled::Throttled;
 pub use self::tree::Tree;
+pub use self::tree_cpu::TreeCpu;
+pub use self::tree_rss::TreeRss;
 pub use self::tree_slot::TreeSlot;
 pub use self::tty::Tty;
 pub use self::udp_port::UdpPort;
@@ -133,13 +263,18 @@ pub use self::uid_fs::UidFs;
 pub use self::uid_login::UidLogin;
 pub use self::uid_real::UidReal;
 pub use self::uid_saved::UidSaved;
+pub use self::unit::Unit;
 pub use self::usage_cpu::UsageCpu;
 pub use self::usage_mem::UsageMem;
+pub use self::usage_sys::UsageSys;
+pub use self::usage_user::UsageUser;
 pub use self::user::User;
 pub use self::user_fs::UserFs;
 pub use self::user_login::UserLogin;
+pub use self::user_ns::UserNs;
 pub use self::user_real::UserReal;
 pub use self::user_saved::UserSaved;
+pub use self::uss::Uss;
 pub use self::vm_data::VmData;
 pub use self::vm_exe::VmExe;
 pub use self::vm_hwm::VmHwm;
@@ -152,11 +287,19 @@ pub use self::vm_rss::VmRss;
 pub use self::vm_size::VmSize;
 pub use self::vm_stack::VmStack;
 pub use self::vm_swap::VmSwap;
+pub use self::wakeups::Wakeups;
 pub use self::wchan::Wchan;
+#[cfg(feature = "x11")]
+pub use self::window_titles::WindowTitles;
 pub use self::work_dir::WorkDir;
 pub use self::write_bytes::WriteBytes;
+pub use self::write_rate::WriteRate;
+#[cfg(feature = "yara")]
+pub use self::yara_hits::YaraHits;
+pub use self::zombie_children::ZombieChildren;
 
 use crate::column::Column;
+use crate::config::ConfigHistory;
 use once_cell::sync::Lazy;
 use serde_derive::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -167,39 +310,93 @@ use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ConfigColumnKind {
+    Audio,
+    Audit,
+    Baseline,
+    BlockedSignals,
     Ccgroup,
     Cgroup,
+    CgroupCpuMax,
+    CgroupMemMax,
     Command,
+    Container,
     ContextSw,
+    CoreDump,
+    CoreSched,
+    CpuShare,
     CpuTime,
+    CpuTimeSys,
+    CpuTimeUser,
+    Cwd,
+    DbusNames,
+    DeltaCpuTime,
+    DeltaRss,
+    Devices,
     Docker,
     Eip,
+    Elapsed,
     ElapsedTime,
     Empty,
+    Energy,
     Env,
+    EnvVar(String),
+    Epoll,
     Esp,
+    EventFd,
+    ExeDeleted,
+    ExeHash,
+    FdCount,
     FileName,
     Gid,
     GidFs,
     GidReal,
     GidSaved,
+    Gpu,
+    GpuMem,
     Group,
     GroupFs,
     GroupReal,
     GroupSaved,
+    Identity,
+    InotifyWatches,
+    IoPrio,
+    LaunchedBy,
     MajFlt,
+    MajFltRate,
+    Mappings,
+    MemShare,
     MinFlt,
+    MinFltRate,
+    MntNs,
     MultiSlot,
+    NetNs,
     Nice,
+    NixPkg,
+    NumaNode,
+    OomScore,
+    OomScoreAdj,
+    ParentCommand,
+    PendingSignals,
     Pgid,
     Pid,
+    PidNs,
+    Pod,
     Policy,
     Ppid,
     Priority,
     Processor,
+    Pss,
     ReadBytes,
+    ReadRate,
+    Restarts,
+    Retrans,
+    RootDir,
     RtPriority,
+    Sandbox,
+    SchedPolicy,
     SecContext,
+    SecLabel,
+    Seccomp,
     Separator,
     Session,
     ShdPnd,
@@ -211,9 +408,14 @@ pub enum ConfigColumnKind {
     Ssb,
     StartTime,
     State,
+    TcpConn,
     TcpPort,
     Threads,
+    Throttled,
+    TimerFd,
     Tree,
+    TreeCpu,
+    TreeRss,
     TreeSlot,
     Tty,
     UdpPort,
@@ -222,14 +424,19 @@ pub enum ConfigColumnKind {
     UidLogin,
     UidReal,
     UidSaved,
+    Unit,
     UsageCpu,
     UsageMem,
+    UsageSys,
+    UsageUser,
     User,
     UserFs,
     UserLogin,
+    UserNs,
     UserReal,
     UserSaved,
     Username,
+    Uss,
     VmData,
     VmExe,
     VmHwm,
@@ -242,60 +449,138 @@ pub enum ConfigColumnKind {
     VmSize,
     VmStack,
     VmSwap,
+    Wakeups,
     Wchan,
+    // `Windows` is the former name
+    #[serde(alias = "Windows")]
+    WindowTitles,
     WorkDir,
     WriteBytes,
+    WriteRate,
+    YaraHits,
+    ZombieChildren,
 }
 
 // ---------------------------------------------------------------------------------------------------------------------
 // gen_column
 // ---------------------------------------------------------------------------------------------------------------------
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_column(
     kind: &ConfigColumnKind,
     header: Option<String>,
-    _docker_path: &str,
+    unit_suffix: bool,
+    docker_path: &str,
     separator: &str,
     abbr_sid: bool,
+    abbr_path: bool,
+    abbr_cgroup: bool,
     tree_symbols: &[String; 5],
+    map_count_warn: u64,
+    history: &ConfigHistory,
+    smaps: bool,
+    start_time_format: &str,
 ) -> Box<dyn Column> {
     match kind {
+        ConfigColumnKind::Audio => Box::new(Audio::new(header)),
+        ConfigColumnKind::Audit => Box::new(Audit::new(header)),
+        ConfigColumnKind::Baseline => Box::new(Baseline::new(header)),
+        ConfigColumnKind::BlockedSignals => Box::new(BlockedSignals::new(header)),
         ConfigColumnKind::Ccgroup => Box::new(Ccgroup::new(header)),
-        ConfigColumnKind::Cgroup => Box::new(Cgroup::new(header)),
+        ConfigColumnKind::Cgroup => Box::new(Cgroup::new(header, abbr_cgroup)),
+        ConfigColumnKind::CgroupCpuMax => Box::new(CgroupCpuMax::new(header)),
+        ConfigColumnKind::CgroupMemMax => Box::new(CgroupMemMax::new(header, unit_suffix)),
         ConfigColumnKind::Command => Box::new(Command::new(header)),
-        ConfigColumnKind::ContextSw => Box::new(ContextSw::new(header)),
+        ConfigColumnKind::Container => Box::new(Container::new(header, docker_path)),
+        ConfigColumnKind::ContextSw => Box::new(ContextSw::new(header, unit_suffix)),
+        ConfigColumnKind::CoreDump => Box::new(CoreDump::new(header, unit_suffix)),
+        ConfigColumnKind::CoreSched => Box::new(CoreSched::new(header)),
+        ConfigColumnKind::CpuShare => Box::new(CpuShare::new(header)),
         ConfigColumnKind::CpuTime => Box::new(CpuTime::new(header)),
+        ConfigColumnKind::CpuTimeSys => Box::new(CpuTimeSys::new(header)),
+        ConfigColumnKind::CpuTimeUser => Box::new(CpuTimeUser::new(header)),
+        ConfigColumnKind::Cwd => Box::new(Cwd::new(header, abbr_path)),
+        #[cfg(feature = "dbus")]
+        ConfigColumnKind::DbusNames => Box::new(DbusNames::new(header)),
+        #[cfg(not(feature = "dbus"))]
+        ConfigColumnKind::DbusNames => Box::new(Empty::new()),
+        ConfigColumnKind::DeltaCpuTime => Box::new(DeltaCpuTime::new(header)),
+        ConfigColumnKind::DeltaRss => Box::new(DeltaRss::new(header, unit_suffix)),
+        ConfigColumnKind::Devices => Box::new(Devices::new(header)),
         #[cfg(feature = "docker")]
-        ConfigColumnKind::Docker => Box::new(Docker::new(header, _docker_path)),
+        ConfigColumnKind::Docker => Box::new(Docker::new(header, docker_path)),
         #[cfg(not(feature = "docker"))]
         ConfigColumnKind::Docker => Box::new(Empty::new()),
         ConfigColumnKind::Eip => Box::new(Eip::new(header)),
-        ConfigColumnKind::ElapsedTime => Box::new(ElapsedTime::new(header)),
+        ConfigColumnKind::Elapsed => Box::new(ElapsedTime::new(header, true)),
+        ConfigColumnKind::ElapsedTime => Box::new(ElapsedTime::new(header, false)),
         ConfigColumnKind::Empty => Box::new(Empty::new()),
+        ConfigColumnKind::Energy => Box::new(Energy::new(header)),
         ConfigColumnKind::Env => Box::new(Env::new(header)),
+        ConfigColumnKind::EnvVar(x) => Box::new(EnvVar::new(header, x)),
+        ConfigColumnKind::Epoll => Box::new(AnonInode::new(header, "Epoll", "[eventpoll]")),
         ConfigColumnKind::Esp => Box::new(Esp::new(header)),
+        ConfigColumnKind::EventFd => Box::new(AnonInode::new(header, "EventFd", "[eventfd]")),
+        ConfigColumnKind::ExeDeleted => Box::new(ExeDeleted::new(header)),
+        ConfigColumnKind::ExeHash => Box::new(ExeHash::new(header)),
+        ConfigColumnKind::FdCount => Box::new(FdCount::new(header)),
         ConfigColumnKind::FileName => Box::new(FileName::new(header)),
         ConfigColumnKind::Gid => Box::new(Gid::new(header, abbr_sid)),
         ConfigColumnKind::GidFs => Box::new(GidFs::new(header)),
         ConfigColumnKind::GidReal => Box::new(GidReal::new(header)),
         ConfigColumnKind::GidSaved => Box::new(GidSaved::new(header)),
+        #[cfg(feature = "gpu")]
+        ConfigColumnKind::Gpu => Box::new(Gpu::new(header)),
+        #[cfg(not(feature = "gpu"))]
+        ConfigColumnKind::Gpu => Box::new(Empty::new()),
+        #[cfg(feature = "gpu")]
+        ConfigColumnKind::GpuMem => Box::new(GpuMem::new(header, unit_suffix)),
+        #[cfg(not(feature = "gpu"))]
+        ConfigColumnKind::GpuMem => Box::new(Empty::new()),
         ConfigColumnKind::Group => Box::new(Group::new(header, abbr_sid)),
         ConfigColumnKind::GroupFs => Box::new(GroupFs::new(header)),
         ConfigColumnKind::GroupReal => Box::new(GroupReal::new(header)),
         ConfigColumnKind::GroupSaved => Box::new(GroupSaved::new(header)),
+        ConfigColumnKind::Identity => Box::new(Identity::new(header)),
+        ConfigColumnKind::InotifyWatches => Box::new(InotifyWatches::new(header)),
+        ConfigColumnKind::IoPrio => Box::new(IoPrio::new(header)),
+        ConfigColumnKind::LaunchedBy => Box::new(LaunchedBy::new(header)),
         ConfigColumnKind::MajFlt => Box::new(MajFlt::new(header)),
+        ConfigColumnKind::MajFltRate => Box::new(MajFltRate::new(header)),
+        ConfigColumnKind::Mappings => Box::new(Mappings::new(header, map_count_warn)),
+        ConfigColumnKind::MemShare => Box::new(MemShare::new(header)),
         ConfigColumnKind::MinFlt => Box::new(MinFlt::new(header)),
+        ConfigColumnKind::MinFltRate => Box::new(MinFltRate::new(header)),
+        ConfigColumnKind::MntNs => Box::new(MntNs::new(header)),
         ConfigColumnKind::MultiSlot => Box::new(MultiSlot::new()),
+        ConfigColumnKind::NetNs => Box::new(NetNs::new(header)),
         ConfigColumnKind::Nice => Box::new(Nice::new(header)),
+        ConfigColumnKind::NixPkg => Box::new(NixPkg::new(header)),
+        ConfigColumnKind::NumaNode => Box::new(NumaNode::new(header)),
+        ConfigColumnKind::OomScore => Box::new(OomScore::new(header)),
+        ConfigColumnKind::OomScoreAdj => Box::new(OomScoreAdj::new(header)),
+        ConfigColumnKind::ParentCommand => Box::new(ParentCommand::new(header)),
+        ConfigColumnKind::PendingSignals => Box::new(PendingSignals::new(header)),
         ConfigColumnKind::Pgid => Box::new(Pgid::new(header)),
         ConfigColumnKind::Pid => Box::new(Pid::new(header)),
+        ConfigColumnKind::PidNs => Box::new(PidNs::new(header)),
+        ConfigColumnKind::Pod => Box::new(Pod::new(header)),
         ConfigColumnKind::Policy => Box::new(Policy::new(header)),
         ConfigColumnKind::Ppid => Box::new(Ppid::new(header)),
         ConfigColumnKind::Priority => Box::new(Priority::new(header)),
         ConfigColumnKind::Processor => Box::new(Processor::new(header)),
-        ConfigColumnKind::ReadBytes => Box::new(ReadBytes::new(header)),
+        ConfigColumnKind::Pss => Box::new(Pss::new(header, unit_suffix, smaps)),
+        ConfigColumnKind::ReadBytes => Box::new(ReadBytes::new(header, unit_suffix)),
+        ConfigColumnKind::ReadRate => Box::new(ReadRate::new(header, unit_suffix)),
+        ConfigColumnKind::Restarts => Box::new(Restarts::new(header, history)),
+        ConfigColumnKind::Retrans => Box::new(Retrans::new(header)),
+        ConfigColumnKind::RootDir => Box::new(RootDir::new(header)),
         ConfigColumnKind::RtPriority => Box::new(RtPriority::new(header)),
+        ConfigColumnKind::Sandbox => Box::new(Sandbox::new(header)),
+        ConfigColumnKind::SchedPolicy => Box::new(SchedPolicy::new(header)),
         ConfigColumnKind::SecContext => Box::new(SecContext::new(header)),
+        ConfigColumnKind::SecLabel => Box::new(SecLabel::new(header)),
+        ConfigColumnKind::Seccomp => Box::new(Seccomp::new(header)),
         ConfigColumnKind::Separator => Box::new(Separator::new(separator)),
         ConfigColumnKind::Session => Box::new(Session::new(header)),
         ConfigColumnKind::ShdPnd => Box::new(ShdPnd::new(header)),
@@ -305,11 +590,16 @@ pub fn gen_column(
         ConfigColumnKind::SigIgn => Box::new(SigIgn::new(header)),
         ConfigColumnKind::SigPnd => Box::new(SigPnd::new(header)),
         ConfigColumnKind::Ssb => Box::new(Ssb::new(header)),
-        ConfigColumnKind::StartTime => Box::new(StartTime::new(header)),
+        ConfigColumnKind::StartTime => Box::new(StartTime::new(header, start_time_format)),
         ConfigColumnKind::State => Box::new(State::new(header)),
+        ConfigColumnKind::TcpConn => Box::new(TcpConn::new(header)),
         ConfigColumnKind::TcpPort => Box::new(TcpPort::new(header)),
         ConfigColumnKind::Threads => Box::new(Threads::new(header)),
+        ConfigColumnKind::Throttled => Box::new(Throttled::new(header)),
+        ConfigColumnKind::TimerFd => Box::new(AnonInode::new(header, "TimerFd", "[timerfd]")),
         ConfigColumnKind::Tree => Box::new(Tree::new(tree_symbols)),
+        ConfigColumnKind::TreeCpu => Box::new(TreeCpu::new(header)),
+        ConfigColumnKind::TreeRss => Box::new(TreeRss::new(header, unit_suffix, smaps)),
         ConfigColumnKind::TreeSlot => Box::new(TreeSlot::new()),
         ConfigColumnKind::Tty => Box::new(Tty::new(header)),
         ConfigColumnKind::UdpPort => Box::new(UdpPort::new(header)),
@@ -318,29 +608,45 @@ pub fn gen_column(
         ConfigColumnKind::UidLogin => Box::new(UidLogin::new(header)),
         ConfigColumnKind::UidReal => Box::new(UidReal::new(header)),
         ConfigColumnKind::UidSaved => Box::new(UidSaved::new(header)),
+        ConfigColumnKind::Unit => Box::new(Unit::new(header)),
         ConfigColumnKind::UsageCpu => Box::new(UsageCpu::new(header)),
         ConfigColumnKind::UsageMem => Box::new(UsageMem::new(header)),
+        ConfigColumnKind::UsageSys => Box::new(UsageSys::new(header)),
+        ConfigColumnKind::UsageUser => Box::new(UsageUser::new(header)),
         ConfigColumnKind::User => Box::new(User::new(header, abbr_sid)),
         ConfigColumnKind::UserFs => Box::new(UserFs::new(header)),
         ConfigColumnKind::UserLogin => Box::new(UserLogin::new(header)),
+        ConfigColumnKind::UserNs => Box::new(UserNs::new(header)),
         ConfigColumnKind::UserReal => Box::new(UserReal::new(header)),
         ConfigColumnKind::UserSaved => Box::new(UserSaved::new(header)),
         ConfigColumnKind::Username => Box::new(User::new(header, abbr_sid)),
-        ConfigColumnKind::VmData => Box::new(VmData::new(header)),
-        ConfigColumnKind::VmExe => Box::new(VmExe::new(header)),
-        ConfigColumnKind::VmHwm => Box::new(VmHwm::new(header)),
-        ConfigColumnKind::VmLib => Box::new(VmLib::new(header)),
-        ConfigColumnKind::VmLock => Box::new(VmLock::new(header)),
-        ConfigColumnKind::VmPeak => Box::new(VmPeak::new(header)),
-        ConfigColumnKind::VmPin => Box::new(VmPin::new(header)),
-        ConfigColumnKind::VmPte => Box::new(VmPte::new(header)),
-        ConfigColumnKind::VmRss => Box::new(VmRss::new(header)),
-        ConfigColumnKind::VmSize => Box::new(VmSize::new(header)),
-        ConfigColumnKind::VmStack => Box::new(VmStack::new(header)),
-        ConfigColumnKind::VmSwap => Box::new(VmSwap::new(header)),
+        ConfigColumnKind::Uss => Box::new(Uss::new(header, unit_suffix, smaps)),
+        ConfigColumnKind::VmData => Box::new(VmData::new(header, unit_suffix)),
+        ConfigColumnKind::VmExe => Box::new(VmExe::new(header, unit_suffix)),
+        ConfigColumnKind::VmHwm => Box::new(VmHwm::new(header, unit_suffix)),
+        ConfigColumnKind::VmLib => Box::new(VmLib::new(header, unit_suffix)),
+        ConfigColumnKind::VmLock => Box::new(VmLock::new(header, unit_suffix)),
+        ConfigColumnKind::VmPeak => Box::new(VmPeak::new(header, unit_suffix)),
+        ConfigColumnKind::VmPin => Box::new(VmPin::new(header, unit_suffix)),
+        ConfigColumnKind::VmPte => Box::new(VmPte::new(header, unit_suffix)),
+        ConfigColumnKind::VmRss => Box::new(VmRss::new(header, unit_suffix)),
+        ConfigColumnKind::VmSize => Box::new(VmSize::new(header, unit_suffix)),
+        ConfigColumnKind::VmStack => Box::new(VmStack::new(header, unit_suffix)),
+        ConfigColumnKind::VmSwap => Box::new(VmSwap::new(header, unit_suffix)),
+        ConfigColumnKind::Wakeups => Box::new(Wakeups::new(header)),
         ConfigColumnKind::Wchan => Box::new(Wchan::new(header)),
+        #[cfg(feature = "x11")]
+        ConfigColumnKind::WindowTitles => Box::new(WindowTitles::new(header)),
+        #[cfg(not(feature = "x11"))]
+        ConfigColumnKind::WindowTitles => Box::new(Empty::new()),
         ConfigColumnKind::WorkDir => Box::new(WorkDir::new(header)),
-        ConfigColumnKind::WriteBytes => Box::new(WriteBytes::new(header)),
+        ConfigColumnKind::WriteBytes => Box::new(WriteBytes::new(header, unit_suffix)),
+        ConfigColumnKind::WriteRate => Box::new(WriteRate::new(header, unit_suffix)),
+        #[cfg(feature = "yara")]
+        ConfigColumnKind::YaraHits => Box::new(YaraHits::new(header)),
+        #[cfg(not(feature = "yara"))]
+        ConfigColumnKind::YaraHits => Box::new(Empty::new()),
+        ConfigColumnKind::ZombieChildren => Box::new(ZombieChildren::new(header)),
     }
 }
 
@@ -351,40 +657,70 @@ pub fn gen_column(
 pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static str)>> =
     Lazy::new(|| {
         [
+            (ConfigColumnKind::Audio, ("Audio", "Audio streams playing/recording")),
+            (ConfigColumnKind::Audit, ("Audit", "Security findings of --audit")),
+            (ConfigColumnKind::Baseline, ("Baseline", "Change from the baseline of --baseline")),
+            (ConfigColumnKind::BlockedSignals, ("BlockedSignals", "Blocked signals by name")),
             (
                 ConfigColumnKind::Ccgroup,
                 ("Ccgroup", "Control group by compressed format"),
             ),
             (ConfigColumnKind::Cgroup, ("Cgroup", "Control group")),
+            (ConfigColumnKind::CgroupCpuMax, ("CgroupCpuMax", "Effective CPU limit of cgroup v2")),
+            (ConfigColumnKind::CgroupMemMax, ("CgroupMemMax", "Effective memory limit of cgroup v2")),
             (
                 ConfigColumnKind::Command,
                 ("Command", "Command with all arguments"),
             ),
+            (ConfigColumnKind::Container, ("Container", "Container name resolved from cgroup")),
             (
                 ConfigColumnKind::ContextSw,
                 ("ContextSw", "Context switch count"),
             ),
+            (ConfigColumnKind::CoreDump, ("CoreDump", "Dumpable state and core size limit")),
+            (ConfigColumnKind::CoreSched, ("CoreSched", "Core scheduling cookie")),
+            (ConfigColumnKind::CpuShare, ("CpuShare", "Share of CPU utilization in shown processes")),
             (
                 ConfigColumnKind::CpuTime,
                 ("CpuTime", "Cumulative CPU time"),
             ),
+            (ConfigColumnKind::CpuTimeSys, ("CpuTimeSys", "Cumulative CPU time in kernel mode")),
+            (ConfigColumnKind::CpuTimeUser, ("CpuTimeUser", "Cumulative CPU time in user mode")),
+            (ConfigColumnKind::Cwd, ("Cwd", "Current working directory")),
+            (ConfigColumnKind::DbusNames, ("DbusNames", "Well-known D-Bus names owned by the process")),
+            (ConfigColumnKind::DeltaCpuTime, ("DeltaCpuTime", "Difference of CPU time from the baseline")),
+            (ConfigColumnKind::DeltaRss, ("DeltaRss", "Difference of RSS from the baseline")),
+            (ConfigColumnKind::Devices, ("Devices", "Character devices held open")),
             (
                 ConfigColumnKind::Docker,
                 ("Docker", "Docker container name"),
             ),
             (ConfigColumnKind::Eip, ("Eip", "Instruction pointer")),
+            (ConfigColumnKind::Elapsed, ("Elapsed", "Elapsed time in the same format as CpuTime")),
             (
                 ConfigColumnKind::ElapsedTime,
                 ("ElapsedTime", "Elapsed time"),
             ),
             (ConfigColumnKind::Empty, ("Empty", "Empty")),
+            (ConfigColumnKind::Energy, ("Energy", "Estimated energy impact from CPU usage and wakeups")),
             (ConfigColumnKind::Env, ("Env", "Environment variables")),
+            (
+                ConfigColumnKind::EnvVar(String::new()),
+                ("Env:<name>", "Value of the environment variable <name>"),
+            ),
+            (ConfigColumnKind::Epoll, ("Epoll", "epoll instance count")),
             (ConfigColumnKind::Esp, ("Esp", "Stack pointer")),
+            (ConfigColumnKind::EventFd, ("EventFd", "eventfd count")),
+            (ConfigColumnKind::ExeDeleted, ("ExeDeleted", "Whether the executable is deleted or replaced")),
+            (ConfigColumnKind::ExeHash, ("ExeHash", "SHA-256 hash of the executable")),
+            (ConfigColumnKind::FdCount, ("FdCount", "Open file descriptor count")),
             (ConfigColumnKind::FileName, ("FileName", "File name")),
             (ConfigColumnKind::Gid, ("Gid", "Group ID")),
             (ConfigColumnKind::GidFs, ("GidFs", "File system group ID")),
             (ConfigColumnKind::GidReal, ("GidReal", "Real group ID")),
             (ConfigColumnKind::GidSaved, ("GidSaved", "Saved group ID")),
+            (ConfigColumnKind::Gpu, ("Gpu", "GPU utilization")),
+            (ConfigColumnKind::GpuMem, ("GpuMem", "GPU memory usage")),
             (ConfigColumnKind::Group, ("Group", "Group name")),
             (
                 ConfigColumnKind::GroupFs,
@@ -398,21 +734,39 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::GroupSaved,
                 ("GroupSaved", "Saved group name"),
             ),
+            (ConfigColumnKind::Identity, ("Identity", "Stable identity from unit, executable and argv hash")),
+            (ConfigColumnKind::InotifyWatches, ("InotifyWatches", "Inotify watch count")),
+            (ConfigColumnKind::IoPrio, ("IoPrio", "I/O scheduling class and priority")),
+            (ConfigColumnKind::LaunchedBy, ("LaunchedBy", "Origin of the process")),
             (
                 ConfigColumnKind::MajFlt,
                 ("MajFlt", "Major page fault count"),
             ),
+            (ConfigColumnKind::MajFltRate, ("MajFltRate", "Major page faults per second")),
+            (ConfigColumnKind::Mappings, ("Mappings", "File-backed memory mapping count")),
+            (ConfigColumnKind::MemShare, ("MemShare", "Share of resident set size in shown processes")),
             (
                 ConfigColumnKind::MinFlt,
                 ("MinFlt", "Minor page fault count"),
             ),
+            (ConfigColumnKind::MinFltRate, ("MinFltRate", "Minor page faults per second")),
+            (ConfigColumnKind::MntNs, ("MntNs", "Mount namespace")),
             (
                 ConfigColumnKind::MultiSlot,
                 ("MultiSlot", "Slot for `--insert` option"),
             ),
+            (ConfigColumnKind::NetNs, ("NetNs", "Network namespace")),
             (ConfigColumnKind::Nice, ("Nice", "Nice value")),
+            (ConfigColumnKind::NixPkg, ("NixPkg", "Nix package of the executable")),
+            (ConfigColumnKind::NumaNode, ("NumaNode", "NUMA nodes of allocated memory")),
+            (ConfigColumnKind::OomScore, ("OomScore", "OOM killer score")),
+            (ConfigColumnKind::OomScoreAdj, ("OomScoreAdj", "Adjustment of OOM killer score")),
+            (ConfigColumnKind::ParentCommand, ("ParentCommand", "Command of the parent process")),
+            (ConfigColumnKind::PendingSignals, ("PendingSignals", "Pending signals by name")),
             (ConfigColumnKind::Pgid, ("Pgid", "Process group ID")),
             (ConfigColumnKind::Pid, ("Pid", "Process ID")),
+            (ConfigColumnKind::PidNs, ("PidNs", "PID namespace")),
+            (ConfigColumnKind::Pod, ("Pod", "Kubernetes pod ( namespace/name )")),
             (ConfigColumnKind::Policy, ("Policy", "Scheduling policy")),
             (ConfigColumnKind::Ppid, ("Ppid", "Parent process ID")),
             (ConfigColumnKind::Priority, ("Priority", "Priority")),
@@ -420,18 +774,27 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::Processor,
                 ("Processor", "Currently assigned processor"),
             ),
+            (ConfigColumnKind::Pss, ("Pss", "Proportional set size ( needs --smaps )")),
             (
                 ConfigColumnKind::ReadBytes,
                 ("ReadBytes", "Read bytes from storage"),
             ),
+            (ConfigColumnKind::ReadRate, ("ReadRate", "Read bytes per second from storage")),
+            (ConfigColumnKind::Restarts, ("Restarts", "Restart count of the same identity in history")),
+            (ConfigColumnKind::Retrans, ("Retrans", "TCP retransmitted segment count")),
+            (ConfigColumnKind::RootDir, ("RootDir", "Root directory, shown if it differs from /")),
             (
                 ConfigColumnKind::RtPriority,
                 ("RtPriority", "Real-time priority"),
             ),
+            (ConfigColumnKind::Sandbox, ("Sandbox", "Flatpak or Snap application")),
+            (ConfigColumnKind::SchedPolicy, ("SchedPolicy", "Scheduling policy with real-time priority")),
             (
                 ConfigColumnKind::SecContext,
                 ("SecContext", "Security context"),
             ),
+            (ConfigColumnKind::SecLabel, ("SecLabel", "Security label of SELinux or AppArmor")),
+            (ConfigColumnKind::Seccomp, ("Seccomp", "Seccomp mode")),
             (
                 ConfigColumnKind::Separator,
                 ("Separator", "Show | for column separation"),
@@ -458,8 +821,13 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
             ),
             (ConfigColumnKind::StartTime, ("StartTime", "Starting time")),
             (ConfigColumnKind::State, ("State", "Process state")),
+            (ConfigColumnKind::TcpConn, ("TcpConn", "Established TCP connections and top remote endpoints")),
             (ConfigColumnKind::TcpPort, ("TcpPort", "Bound TCP ports")),
             (ConfigColumnKind::Threads, ("Threads", "Thread count")),
+            (ConfigColumnKind::Throttled, ("Throttled", "Throttled time ratio by cgroup v2 CPU quota")),
+            (ConfigColumnKind::TimerFd, ("TimerFd", "timerfd count")),
+            (ConfigColumnKind::TreeCpu, ("TreeCpu", "CPU utilization including descendants")),
+            (ConfigColumnKind::TreeRss, ("TreeRss", "Resident set size including descendants")),
             (
                 ConfigColumnKind::TreeSlot,
                 ("TreeSlot", "Slot for tree column"),
@@ -471,11 +839,14 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
             (ConfigColumnKind::UidLogin, ("UidLogin", "Login user ID")),
             (ConfigColumnKind::UidReal, ("UidReal", "Real user ID")),
             (ConfigColumnKind::UidSaved, ("UidSaved", "Saved user ID")),
+            (ConfigColumnKind::Unit, ("Unit", "Systemd unit")),
             (ConfigColumnKind::UsageCpu, ("UsageCpu", "CPU utilization")),
             (
                 ConfigColumnKind::UsageMem,
                 ("UsageMem", "Memory utilization"),
             ),
+            (ConfigColumnKind::UsageSys, ("UsageSys", "CPU utilization in kernel mode")),
+            (ConfigColumnKind::UsageUser, ("UsageUser", "CPU utilization in user mode")),
             (ConfigColumnKind::User, ("User", "User name")),
             (
                 ConfigColumnKind::UserFs,
@@ -485,11 +856,13 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::UserLogin,
                 ("UserLogin", "Login user name"),
             ),
+            (ConfigColumnKind::UserNs, ("UserNs", "User namespace")),
             (ConfigColumnKind::UserReal, ("UserReal", "Real user name")),
             (
                 ConfigColumnKind::UserSaved,
                 ("UserSaved", "Saved user name"),
             ),
+            (ConfigColumnKind::Uss, ("Uss", "Unique set size ( needs --smaps )")),
             (ConfigColumnKind::VmData, ("VmData", "Data size")),
             (ConfigColumnKind::VmExe, ("VmExe", "Text segments size")),
             (ConfigColumnKind::VmHwm, ("VmHwm", "Peak resident set size")),
@@ -511,10 +884,12 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::VmSwap,
                 ("VmSwap", "Swapped-out virtual memory size"),
             ),
+            (ConfigColumnKind::Wakeups, ("Wakeups", "Wakeups per second")),
             (
                 ConfigColumnKind::Wchan,
                 ("Wchan", "Process sleeping kernel function"),
             ),
+            (ConfigColumnKind::WindowTitles, ("WindowTitles", "Titles of top-level windows")),
             (
                 ConfigColumnKind::WorkDir,
                 ("WorkDir", "Current working directory"),
@@ -523,6 +898,9 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::WriteBytes,
                 ("WriteBytes", "Write bytes to storage"),
             ),
+            (ConfigColumnKind::WriteRate, ("WriteRate", "Write bytes per second to storage")),
+            (ConfigColumnKind::YaraHits, ("YaraHits", "Matched YARA rules of --yara")),
+            (ConfigColumnKind::ZombieChildren, ("ZombieChildren", "Number of zombie children")),
         ]
         .iter()
         .cloned()
@@ -731,6 +1109,18 @@ align = "Left"
 #[cfg(test)]
 pub static CONFIG_ALL: &str = r#"
 [[columns]]
+kind = "Audio"
+style = "White"
+[[columns]]
+kind = "Audit"
+style = "BrightRed"
+[[columns]]
+kind = "Baseline"
+style = "White"
+[[columns]]
+kind = "BlockedSignals"
+style = "White"
+[[columns]]
 kind = "Ccgroup"
 style = "BrightRed"
 align = "Left"
@@ -739,36 +1129,96 @@ kind = "Cgroup"
 style = "BrightRed"
 align = "Left"
 [[columns]]
+kind = "CgroupCpuMax"
+style = "ByUnit"
+[[columns]]
+kind = "CgroupMemMax"
+style = "ByUnit"
+[[columns]]
 kind = "Command"
 style = "BrightRed"
 align = "Left"
 [[columns]]
+kind = "Container"
+style = "BrightMagenta"
+[[columns]]
 kind = "ContextSw"
 style = "BrightRed"
 align = "Right"
 [[columns]]
+kind = "CoreDump"
+style = "White"
+[[columns]]
+kind = "CoreSched"
+style = "BrightWhite"
+[[columns]]
+kind = "CpuShare"
+style = "White"
+[[columns]]
 kind = "CpuTime"
 style = "BrightGreen"
 align = "Center"
 [[columns]]
+kind = "CpuTimeSys"
+style = "BrightGreen"
+[[columns]]
+kind = "CpuTimeUser"
+style = "BrightGreen"
+[[columns]]
+kind = "Cwd"
+style = "White"
+[[columns]]
+kind = "DbusNames"
+style = "White"
+[[columns]]
+kind = "DeltaCpuTime"
+style = "BrightGreen"
+[[columns]]
+kind = "DeltaRss"
+style = "White"
+[[columns]]
+kind = "Devices"
+style = "White"
+[[columns]]
 kind = "Docker"
 style = "BrightMagenta"
 [[columns]]
 kind = "Eip"
 style = "BrightYellow"
 [[columns]]
+kind = "Elapsed"
+style = "BrightYellow"
+[[columns]]
 kind = "ElapsedTime"
 style = "BrightYellow"
 [[columns]]
 kind = "Empty"
 style = "BrightYellow"
 [[columns]]
+kind = "Energy"
+style = "White"
+[[columns]]
 kind = "Env"
 style = "BrightYellow"
 [[columns]]
+kind = "Epoll"
+style = "White"
+[[columns]]
 kind = "Esp"
 style = "BrightBlue"
 [[columns]]
+kind = "EventFd"
+style = "White"
+[[columns]]
+kind = "ExeDeleted"
+style = "BrightRed"
+[[columns]]
+kind = "ExeHash"
+style = "White"
+[[columns]]
+kind = "FdCount"
+style = "BrightYellow"
+[[columns]]
 kind = "FileName"
 style = "BrightBlue"
 [[columns]]
@@ -784,6 +1234,12 @@ style = "White"
 kind = "GidSaved"
 style = "White"
 [[columns]]
+kind = "Gpu"
+style = "ByPercentage"
+[[columns]]
+kind = "GpuMem"
+style = "ByUnit"
+[[columns]]
 kind = "Group"
 style = "White"
 [[columns]]
@@ -796,24 +1252,79 @@ style = "White"
 kind = "GroupSaved"
 style = "White"
 [[columns]]
+kind = "Identity"
+style = "White"
+[[columns]]
+kind = "InotifyWatches"
+style = "White"
+[[columns]]
+kind = "IoPrio"
+style = "White"
+[[columns]]
+kind = "LaunchedBy"
+style = "White"
+[[columns]]
 kind = "MajFlt"
 style = "BrightCyan"
 [[columns]]
+kind = "MajFltRate"
+style = "White"
+[[columns]]
+kind = "Mappings"
+style = "White"
+[[columns]]
+kind = "MemShare"
+style = "White"
+[[columns]]
 kind = "MinFlt"
 style = "BrightWhite"
 [[columns]]
+kind = "MinFltRate"
+style = "White"
+[[columns]]
+kind = "MntNs"
+style = "BrightCyan"
+[[columns]]
 kind = "MultiSlot"
 style = "BrightWhite"
 [[columns]]
+kind = "NetNs"
+style = "BrightCyan"
+[[columns]]
 kind = "Nice"
 style = "Red"
 [[columns]]
+kind = "NixPkg"
+style = "White"
+[[columns]]
+kind = "NumaNode"
+style = "White"
+[[columns]]
+kind = "OomScore"
+style = "BrightYellow"
+[[columns]]
+kind = "OomScoreAdj"
+style = "BrightYellow"
+[[columns]]
+kind = "ParentCommand"
+style = "BrightRed"
+align = "Left"
+[[columns]]
+kind = "PendingSignals"
+style = "White"
+[[columns]]
 kind = "Pgid"
 style = "Yellow"
 [[columns]]
 kind = "Pid"
 style = "Green"
 [[columns]]
+kind = "PidNs"
+style = "BrightCyan"
+[[columns]]
+kind = "Pod"
+style = "BrightMagenta"
+[[columns]]
 kind = "Policy"
 style = "Green"
 [[columns]]
@@ -826,15 +1337,42 @@ style = "Blue"
 kind = "Processor"
 style = "Magenta"
 [[columns]]
+kind = "Pss"
+style = "White"
+[[columns]]
 kind = "ReadBytes"
 style = "Cyan"
 [[columns]]
+kind = "ReadRate"
+style = "White"
+[[columns]]
+kind = "Restarts"
+style = "ByUnit"
+[[columns]]
+kind = "Retrans"
+style = "White"
+[[columns]]
+kind = "RootDir"
+style = "BrightMagenta"
+[[columns]]
 kind = "RtPriority"
 style = "White"
 [[columns]]
+kind = "Sandbox"
+style = "White"
+[[columns]]
+kind = "SchedPolicy"
+style = "White"
+[[columns]]
 kind = "SecContext"
 style = "White"
 [[columns]]
+kind = "SecLabel"
+style = "White"
+[[columns]]
+kind = "Seccomp"
+style = "White"
+[[columns]]
 kind = "Separator"
 style = "White"
 [[columns]]
@@ -865,12 +1403,27 @@ style = "White"
 kind = "State"
 style = "White"
 [[columns]]
+kind = "TcpConn"
+style = "BrightCyan"
+[[columns]]
 kind = "TcpPort"
 style = "White"
 [[columns]]
 kind = "Threads"
 style = "White"
 [[columns]]
+kind = "Throttled"
+style = "ByPercentage"
+[[columns]]
+kind = "TimerFd"
+style = "White"
+[[columns]]
+kind = "TreeCpu"
+style = "ByPercentage"
+[[columns]]
+kind = "TreeRss"
+style = "ByUnit"
+[[columns]]
 kind = "TreeSlot"
 style = "BrightWhite"
 [[columns]]
@@ -895,12 +1448,21 @@ style = "White"
 kind = "UidSaved"
 style = "White"
 [[columns]]
+kind = "Unit"
+style = "BrightYellow"
+[[columns]]
 kind = "UsageCpu"
 style = "White"
 [[columns]]
 kind = "UsageMem"
 style = "White"
 [[columns]]
+kind = "UsageSys"
+style = "White"
+[[columns]]
+kind = "UsageUser"
+style = "White"
+[[columns]]
 kind = "User"
 style = "White"
 [[columns]]
@@ -910,12 +1472,18 @@ style = "White"
 kind = "UserLogin"
 style = "White"
 [[columns]]
+kind = "UserNs"
+style = "BrightCyan"
+[[columns]]
 kind = "UserReal"
 style = "White"
 [[columns]]
 kind = "UserSaved"
 style = "White"
 [[columns]]
+kind = "Uss"
+style = "White"
+[[columns]]
 kind = "VmData"
 style = "ByUnit"
 [[columns]]
@@ -952,12 +1520,27 @@ style = "ByUnit"
 kind = "VmSwap"
 style = "ByUnit"
 [[columns]]
+kind = "Wakeups"
+style = "White"
+[[columns]]
 kind = "Wchan"
 style = "White"
 [[columns]]
+kind = "WindowTitles"
+style = "White"
+[[columns]]
 kind = "WorkDir"
 style = "White"
 [[columns]]
 kind = "WriteBytes"
 style = "White"
+[[columns]]
+kind = "WriteRate"
+style = "White"
+[[columns]]
+kind = "YaraHits"
+style = "BrightRed"
+[[columns]]
+kind = "ZombieChildren"
+style = "White"
 "#;
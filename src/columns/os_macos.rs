@@ -1,10 +1,14 @@
 pub mod command;
 pub mod context_sw;
 pub mod cpu_time;
+pub mod cpu_time_sys;
+pub mod cpu_time_user;
+pub mod cwd;
 #[cfg(feature = "docker")]
 pub mod docker;
 pub mod elapsed_time;
 pub mod empty;
+pub mod energy;
 pub mod gid;
 pub mod gid_real;
 pub mod gid_saved;
@@ -15,6 +19,7 @@ pub mod maj_flt;
 pub mod min_flt;
 pub mod multi_slot;
 pub mod nice;
+pub mod parent_command;
 pub mod pgid;
 pub mod pid;
 pub mod policy;
@@ -37,20 +42,27 @@ pub mod uid_real;
 pub mod uid_saved;
 pub mod usage_cpu;
 pub mod usage_mem;
+pub mod usage_sys;
+pub mod usage_user;
 pub mod user;
 pub mod user_real;
 pub mod user_saved;
 pub mod vm_rss;
 pub mod vm_size;
+pub mod wakeups;
 pub mod write_bytes;
 
 pub use self::command::Command;
 pub use self::context_sw::ContextSw;
 pub use self::cpu_time::CpuTime;
+pub use self::cpu_time_sys::CpuTimeSys;
+pub use self::cpu_time_user::CpuTimeUser;
+pub use self::cwd::Cwd;
 #[cfg(feature = "docker")]
 pub use self::docker::Docker;
 pub use self::elapsed_time::ElapsedTime;
 pub use self::empty::Empty;
+pub use self::energy::Energy;
 pub use self::gid::Gid;
 pub use self::gid_real::GidReal;
 pub use self::gid_saved::GidSaved;
@@ -61,6 +73,7 @@ pub use self::maj_flt::MajFlt;
 pub use self::min_flt::MinFlt;
 pub use self::multi_slot::MultiSlot;
 pub use self::nice::Nice;
+pub use self::parent_command::ParentCommand;
 pub use self::pgid::Pgid;
 pub use self::pid::Pid;
 pub use self::policy::Policy;
@@ -83,14 +96,18 @@ pub use self::uid_real::UidReal;
 pub use self::uid_saved::UidSaved;
 pub use self::usage_cpu::UsageCpu;
 pub use self::usage_mem::UsageMem;
+pub use self::usage_sys::UsageSys;
+pub use self::usage_user::UsageUser;
 pub use self::user::User;
 pub use self::user_real::UserReal;
 pub use self::user_saved::UserSaved;
 pub use self::vm_rss::VmRss;
 pub use self::vm_size::VmSize;
+pub use self::wakeups::Wakeups;
 pub use self::write_bytes::WriteBytes;
 
 use crate::column::Column;
+use crate::config::ConfigHistory;
 use once_cell::sync::Lazy;
 use serde_derive::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -104,9 +121,14 @@ pub enum ConfigColumnKind {
     Command,
     ContextSw,
     CpuTime,
+    CpuTimeSys,
+    CpuTimeUser,
+    Cwd,
     Docker,
+    Elapsed,
     ElapsedTime,
     Empty,
+    Energy,
     Gid,
     GidReal,
     GidSaved,
@@ -117,6 +139,7 @@ pub enum ConfigColumnKind {
     MinFlt,
     MultiSlot,
     Nice,
+    ParentCommand,
     Pgid,
     Pid,
     Policy,
@@ -139,12 +162,15 @@ pub enum ConfigColumnKind {
     UidSaved,
     UsageCpu,
     UsageMem,
+    UsageSys,
+    UsageUser,
     User,
     UserReal,
     UserSaved,
     Username,
     VmRss,
     VmSize,
+    Wakeups,
     WriteBytes,
 }
 
@@ -152,24 +178,37 @@ pub enum ConfigColumnKind {
 // gen_column
 // ---------------------------------------------------------------------------------------------------------------------
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_column(
     kind: &ConfigColumnKind,
     header: Option<String>,
+    unit_suffix: bool,
     _docker_path: &str,
     separator: &str,
     abbr_sid: bool,
+    abbr_path: bool,
+    _abbr_cgroup: bool,
     tree_symbols: &[String; 5],
+    _map_count_warn: u64,
+    _history: &ConfigHistory,
+    _smaps: bool,
+    start_time_format: &str,
 ) -> Box<dyn Column> {
     match kind {
         ConfigColumnKind::Command => Box::new(Command::new(header)),
-        ConfigColumnKind::ContextSw => Box::new(ContextSw::new(header)),
+        ConfigColumnKind::ContextSw => Box::new(ContextSw::new(header, unit_suffix)),
         ConfigColumnKind::CpuTime => Box::new(CpuTime::new(header)),
+        ConfigColumnKind::CpuTimeSys => Box::new(CpuTimeSys::new(header)),
+        ConfigColumnKind::CpuTimeUser => Box::new(CpuTimeUser::new(header)),
+        ConfigColumnKind::Cwd => Box::new(Cwd::new(header, abbr_path)),
         #[cfg(feature = "docker")]
         ConfigColumnKind::Docker => Box::new(Docker::new(header, _docker_path)),
         #[cfg(not(feature = "docker"))]
         ConfigColumnKind::Docker => Box::new(Empty::new()),
-        ConfigColumnKind::ElapsedTime => Box::new(ElapsedTime::new(header)),
+        ConfigColumnKind::Elapsed => Box::new(ElapsedTime::new(header, true)),
+        ConfigColumnKind::ElapsedTime => Box::new(ElapsedTime::new(header, false)),
         ConfigColumnKind::Empty => Box::new(Empty::new()),
+        ConfigColumnKind::Energy => Box::new(Energy::new(header)),
         ConfigColumnKind::Gid => Box::new(Gid::new(header, abbr_sid)),
         ConfigColumnKind::GidReal => Box::new(GidReal::new(header)),
         ConfigColumnKind::GidSaved => Box::new(GidSaved::new(header)),
@@ -180,16 +219,17 @@ pub fn gen_column(
         ConfigColumnKind::MinFlt => Box::new(MinFlt::new(header)),
         ConfigColumnKind::MultiSlot => Box::new(MultiSlot::new()),
         ConfigColumnKind::Nice => Box::new(Nice::new(header)),
+        ConfigColumnKind::ParentCommand => Box::new(ParentCommand::new(header)),
         ConfigColumnKind::Pgid => Box::new(Pgid::new(header)),
         ConfigColumnKind::Pid => Box::new(Pid::new(header)),
         ConfigColumnKind::Policy => Box::new(Policy::new(header)),
         ConfigColumnKind::Ppid => Box::new(Ppid::new(header)),
         ConfigColumnKind::Priority => Box::new(Priority::new(header)),
-        ConfigColumnKind::ReadBytes => Box::new(ReadBytes::new(header)),
+        ConfigColumnKind::ReadBytes => Box::new(ReadBytes::new(header, unit_suffix)),
         ConfigColumnKind::Separator => Box::new(Separator::new(separator)),
         ConfigColumnKind::Session => Box::new(Session::new(header)),
         ConfigColumnKind::Slot => Box::new(Slot::new()),
-        ConfigColumnKind::StartTime => Box::new(StartTime::new(header)),
+        ConfigColumnKind::StartTime => Box::new(StartTime::new(header, start_time_format)),
         ConfigColumnKind::State => Box::new(State::new(header)),
         ConfigColumnKind::TcpPort => Box::new(TcpPort::new(header)),
         ConfigColumnKind::Threads => Box::new(Threads::new(header)),
@@ -202,13 +242,16 @@ pub fn gen_column(
         ConfigColumnKind::UidSaved => Box::new(UidSaved::new(header)),
         ConfigColumnKind::UsageCpu => Box::new(UsageCpu::new(header)),
         ConfigColumnKind::UsageMem => Box::new(UsageMem::new(header)),
+        ConfigColumnKind::UsageSys => Box::new(UsageSys::new(header)),
+        ConfigColumnKind::UsageUser => Box::new(UsageUser::new(header)),
         ConfigColumnKind::User => Box::new(User::new(header, abbr_sid)),
         ConfigColumnKind::UserReal => Box::new(UserReal::new(header)),
         ConfigColumnKind::UserSaved => Box::new(UserSaved::new(header)),
         ConfigColumnKind::Username => Box::new(User::new(header, abbr_sid)),
-        ConfigColumnKind::VmRss => Box::new(VmRss::new(header)),
-        ConfigColumnKind::VmSize => Box::new(VmSize::new(header)),
-        ConfigColumnKind::WriteBytes => Box::new(WriteBytes::new(header)),
+        ConfigColumnKind::VmRss => Box::new(VmRss::new(header, unit_suffix)),
+        ConfigColumnKind::VmSize => Box::new(VmSize::new(header, unit_suffix)),
+        ConfigColumnKind::Wakeups => Box::new(Wakeups::new(header)),
+        ConfigColumnKind::WriteBytes => Box::new(WriteBytes::new(header, unit_suffix)),
     }
 }
 
@@ -231,15 +274,20 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::CpuTime,
                 ("CpuTime", "Cumulative CPU time"),
             ),
+            (ConfigColumnKind::CpuTimeSys, ("CpuTimeSys", "Cumulative CPU time in kernel mode")),
+            (ConfigColumnKind::CpuTimeUser, ("CpuTimeUser", "Cumulative CPU time in user mode")),
+            (ConfigColumnKind::Cwd, ("Cwd", "Current working directory")),
             (
                 ConfigColumnKind::Docker,
                 ("Docker", "Docker container name"),
             ),
+            (ConfigColumnKind::Elapsed, ("Elapsed", "Elapsed time in the same format as CpuTime")),
             (
                 ConfigColumnKind::ElapsedTime,
                 ("ElapsedTime", "Elapsed time"),
             ),
             (ConfigColumnKind::Empty, ("Empty", "Empty")),
+            (ConfigColumnKind::Energy, ("Energy", "Estimated energy impact from CPU usage and wakeups")),
             (ConfigColumnKind::Gid, ("Gid", "Group ID")),
             (ConfigColumnKind::GidReal, ("GidReal", "Real group ID")),
             (ConfigColumnKind::GidSaved, ("GidSaved", "Saved group ID")),
@@ -265,6 +313,7 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ("MultiSlot", "Slot for `--insert` option"),
             ),
             (ConfigColumnKind::Nice, ("Nice", "Nice value")),
+            (ConfigColumnKind::ParentCommand, ("ParentCommand", "Command of the parent process")),
             (ConfigColumnKind::Pgid, ("Pgid", "Process group ID")),
             (ConfigColumnKind::Pid, ("Pid", "Process ID")),
             (ConfigColumnKind::Policy, ("Policy", "Scheduling policy")),
@@ -301,6 +350,8 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::UsageMem,
                 ("UsageMem", "Memory utilization"),
             ),
+            (ConfigColumnKind::UsageSys, ("UsageSys", "CPU utilization in kernel mode")),
+            (ConfigColumnKind::UsageUser, ("UsageUser", "CPU utilization in user mode")),
             (ConfigColumnKind::User, ("User", "User name")),
             (ConfigColumnKind::UserReal, ("UserReal", "Real user name")),
             (
@@ -309,6 +360,7 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
             ),
             (ConfigColumnKind::VmRss, ("VmRss", "Resident set size")),
             (ConfigColumnKind::VmSize, ("VmSize", "Physical page size")),
+            (ConfigColumnKind::Wakeups, ("Wakeups", "Wakeups per second")),
             (
                 ConfigColumnKind::WriteBytes,
                 ("WriteBytes", "Write bytes to storage"),
@@ -533,15 +585,30 @@ kind = "CpuTime"
 style = "BrightGreen"
 align = "Center"
 [[columns]]
+kind = "CpuTimeSys"
+style = "BrightGreen"
+[[columns]]
+kind = "CpuTimeUser"
+style = "BrightGreen"
+[[columns]]
+kind = "Cwd"
+style = "White"
+[[columns]]
 kind = "Docker"
 style = "BrightMagenta"
 [[columns]]
+kind = "Elapsed"
+style = "BrightYellow"
+[[columns]]
 kind = "ElapsedTime"
 style = "BrightYellow"
 [[columns]]
 kind = "Empty"
 style = "BrightYellow"
 [[columns]]
+kind = "Energy"
+style = "White"
+[[columns]]
 kind = "Gid"
 style = "White"
 [[columns]]
@@ -572,6 +639,10 @@ style = "BrightWhite"
 kind = "Nice"
 style = "Red"
 [[columns]]
+kind = "ParentCommand"
+style = "BrightRed"
+align = "Left"
+[[columns]]
 kind = "Pgid"
 style = "Yellow"
 [[columns]]
@@ -632,6 +703,12 @@ style = "White"
 kind = "UsageMem"
 style = "White"
 [[columns]]
+kind = "UsageSys"
+style = "White"
+[[columns]]
+kind = "UsageUser"
+style = "White"
+[[columns]]
 kind = "User"
 style = "White"
 [[columns]]
@@ -647,6 +724,9 @@ style = "ByUnit"
 kind = "VmSize"
 style = "ByUnit"
 [[columns]]
+kind = "Wakeups"
+style = "White"
+[[columns]]
 kind = "WriteBytes"
 style = "White"
 "#;
@@ -1,11 +1,14 @@
 pub mod command;
 pub mod cpu_time;
+pub mod cpu_time_sys;
+pub mod cpu_time_user;
 pub mod elapsed_time;
 pub mod empty;
 pub mod gid;
 pub mod group;
 pub mod maj_flt;
 pub mod multi_slot;
+pub mod parent_command;
 pub mod pid;
 pub mod ppid;
 pub mod priority;
@@ -20,6 +23,8 @@ pub mod tree_slot;
 pub mod uid;
 pub mod usage_cpu;
 pub mod usage_mem;
+pub mod usage_sys;
+pub mod usage_user;
 pub mod user;
 pub mod vm_hwm;
 pub mod vm_peak;
@@ -31,12 +36,15 @@ pub mod write_bytes;
 
 pub use self::command::Command;
 pub use self::cpu_time::CpuTime;
+pub use self::cpu_time_sys::CpuTimeSys;
+pub use self::cpu_time_user::CpuTimeUser;
 pub use self::elapsed_time::ElapsedTime;
 pub use self::empty::Empty;
 pub use self::gid::Gid;
 pub use self::group::Group;
 pub use self::maj_flt::MajFlt;
 pub use self::multi_slot::MultiSlot;
+pub use self::parent_command::ParentCommand;
 pub use self::pid::Pid;
 pub use self::ppid::Ppid;
 pub use self::priority::Priority;
@@ -51,6 +59,8 @@ pub use self::tree_slot::TreeSlot;
 pub use self::uid::Uid;
 pub use self::usage_cpu::UsageCpu;
 pub use self::usage_mem::UsageMem;
+pub use self::usage_sys::UsageSys;
+pub use self::usage_user::UsageUser;
 pub use self::user::User;
 pub use self::vm_hwm::VmHwm;
 pub use self::vm_peak::VmPeak;
@@ -61,6 +71,7 @@ pub use self::vm_swap::VmSwap;
 pub use self::write_bytes::WriteBytes;
 
 use crate::column::Column;
+use crate::config::ConfigHistory;
 use once_cell::sync::Lazy;
 use serde_derive::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -73,12 +84,16 @@ use std::collections::BTreeMap;
 pub enum ConfigColumnKind {
     Command,
     CpuTime,
+    CpuTimeSys,
+    CpuTimeUser,
+    Elapsed,
     ElapsedTime,
     Empty,
     Gid,
     Group,
     MajFlt,
     MultiSlot,
+    ParentCommand,
     Pid,
     Ppid,
     Priority,
@@ -93,6 +108,8 @@ pub enum ConfigColumnKind {
     Uid,
     UsageCpu,
     UsageMem,
+    UsageSys,
+    UsageUser,
     User,
     VmHwm,
     VmPeak,
@@ -107,30 +124,42 @@ pub enum ConfigColumnKind {
 // gen_column
 // ---------------------------------------------------------------------------------------------------------------------
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_column(
     kind: &ConfigColumnKind,
     header: Option<String>,
+    unit_suffix: bool,
     _docker_path: &str,
     separator: &str,
     abbr_sid: bool,
+    _abbr_path: bool,
+    _abbr_cgroup: bool,
     tree_symbols: &[String; 5],
+    _map_count_warn: u64,
+    _history: &ConfigHistory,
+    _smaps: bool,
+    start_time_format: &str,
 ) -> Box<dyn Column> {
     match kind {
         ConfigColumnKind::Command => Box::new(Command::new(header)),
         ConfigColumnKind::CpuTime => Box::new(CpuTime::new(header)),
-        ConfigColumnKind::ElapsedTime => Box::new(ElapsedTime::new(header)),
+        ConfigColumnKind::CpuTimeSys => Box::new(CpuTimeSys::new(header)),
+        ConfigColumnKind::CpuTimeUser => Box::new(CpuTimeUser::new(header)),
+        ConfigColumnKind::Elapsed => Box::new(ElapsedTime::new(header, true)),
+        ConfigColumnKind::ElapsedTime => Box::new(ElapsedTime::new(header, false)),
         ConfigColumnKind::Empty => Box::new(Empty::new()),
         ConfigColumnKind::Gid => Box::new(Gid::new(header, abbr_sid)),
         ConfigColumnKind::Group => Box::new(Group::new(header, abbr_sid)),
         ConfigColumnKind::MajFlt => Box::new(MajFlt::new(header)),
         ConfigColumnKind::MultiSlot => Box::new(MultiSlot::new()),
+        ConfigColumnKind::ParentCommand => Box::new(ParentCommand::new(header)),
         ConfigColumnKind::Pid => Box::new(Pid::new(header)),
         ConfigColumnKind::Ppid => Box::new(Ppid::new(header)),
         ConfigColumnKind::Priority => Box::new(Priority::new(header)),
-        ConfigColumnKind::ReadBytes => Box::new(ReadBytes::new(header)),
+        ConfigColumnKind::ReadBytes => Box::new(ReadBytes::new(header, unit_suffix)),
         ConfigColumnKind::Separator => Box::new(Separator::new(separator)),
         ConfigColumnKind::Slot => Box::new(Slot::new()),
-        ConfigColumnKind::StartTime => Box::new(StartTime::new(header)),
+        ConfigColumnKind::StartTime => Box::new(StartTime::new(header, start_time_format)),
         ConfigColumnKind::TcpPort => Box::new(TcpPort::new(header)),
         ConfigColumnKind::Threads => Box::new(Threads::new(header)),
         ConfigColumnKind::Tree => Box::new(Tree::new(tree_symbols)),
@@ -138,14 +167,16 @@ pub fn gen_column(
         ConfigColumnKind::Uid => Box::new(Uid::new(header, abbr_sid)),
         ConfigColumnKind::UsageCpu => Box::new(UsageCpu::new(header)),
         ConfigColumnKind::UsageMem => Box::new(UsageMem::new(header)),
+        ConfigColumnKind::UsageSys => Box::new(UsageSys::new(header)),
+        ConfigColumnKind::UsageUser => Box::new(UsageUser::new(header)),
         ConfigColumnKind::User => Box::new(User::new(header, abbr_sid)),
-        ConfigColumnKind::VmHwm => Box::new(VmHwm::new(header)),
-        ConfigColumnKind::VmPeak => Box::new(VmPeak::new(header)),
-        ConfigColumnKind::VmPin => Box::new(VmPin::new(header)),
-        ConfigColumnKind::VmRss => Box::new(VmRss::new(header)),
-        ConfigColumnKind::VmSize => Box::new(VmSize::new(header)),
-        ConfigColumnKind::VmSwap => Box::new(VmSwap::new(header)),
-        ConfigColumnKind::WriteBytes => Box::new(WriteBytes::new(header)),
+        ConfigColumnKind::VmHwm => Box::new(VmHwm::new(header, unit_suffix)),
+        ConfigColumnKind::VmPeak => Box::new(VmPeak::new(header, unit_suffix)),
+        ConfigColumnKind::VmPin => Box::new(VmPin::new(header, unit_suffix)),
+        ConfigColumnKind::VmRss => Box::new(VmRss::new(header, unit_suffix)),
+        ConfigColumnKind::VmSize => Box::new(VmSize::new(header, unit_suffix)),
+        ConfigColumnKind::VmSwap => Box::new(VmSwap::new(header, unit_suffix)),
+        ConfigColumnKind::WriteBytes => Box::new(WriteBytes::new(header, unit_suffix)),
     }
 }
 
@@ -164,6 +195,9 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::CpuTime,
                 ("CpuTime", "Cumulative CPU time"),
             ),
+            (ConfigColumnKind::CpuTimeSys, ("CpuTimeSys", "Cumulative CPU time in kernel mode")),
+            (ConfigColumnKind::CpuTimeUser, ("CpuTimeUser", "Cumulative CPU time in user mode")),
+            (ConfigColumnKind::Elapsed, ("Elapsed", "Elapsed time in the same format as CpuTime")),
             (
                 ConfigColumnKind::ElapsedTime,
                 ("ElapsedTime", "Elapsed time"),
@@ -179,6 +213,7 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::MultiSlot,
                 ("MultiSlot", "Slot for `--insert` option"),
             ),
+            (ConfigColumnKind::ParentCommand, ("ParentCommand", "Command of the parent process")),
             (ConfigColumnKind::Pid, ("Pid", "Process ID")),
             (ConfigColumnKind::Ppid, ("Ppid", "Parent process ID")),
             (ConfigColumnKind::Priority, ("Priority", "Priority")),
@@ -207,6 +242,8 @@ pub static KIND_LIST: Lazy<BTreeMap<ConfigColumnKind, (&'static str, &'static st
                 ConfigColumnKind::UsageMem,
                 ("UsageMem", "Memory utilization"),
             ),
+            (ConfigColumnKind::UsageSys, ("UsageSys", "CPU utilization in kernel mode")),
+            (ConfigColumnKind::UsageUser, ("UsageUser", "CPU utilization in user mode")),
             (ConfigColumnKind::User, ("User", "User name")),
             (ConfigColumnKind::VmHwm, ("VmHwm", "Peak resident set size")),
             (
@@ -404,6 +441,15 @@ kind = "CpuTime"
 style = "BrightGreen"
 align = "Center"
 [[columns]]
+kind = "CpuTimeSys"
+style = "BrightGreen"
+[[columns]]
+kind = "CpuTimeUser"
+style = "BrightGreen"
+[[columns]]
+kind = "Elapsed"
+style = "BrightYellow"
+[[columns]]
 kind = "ElapsedTime"
 style = "BrightYellow"
 [[columns]]
@@ -422,6 +468,10 @@ style = "BrightCyan"
 kind = "MultiSlot"
 style = "BrightWhite"
 [[columns]]
+kind = "ParentCommand"
+style = "BrightRed"
+align = "Left"
+[[columns]]
 kind = "Pid"
 style = "Green"
 [[columns]]
@@ -458,6 +508,12 @@ style = "White"
 kind = "UsageMem"
 style = "White"
 [[columns]]
+kind = "UsageSys"
+style = "White"
+[[columns]]
+kind = "UsageUser"
+style = "White"
+[[columns]]
 kind = "User"
 style = "White"
 [[columns]]
@@ -0,0 +1,55 @@
+use crate::columns::Command;
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct ParentCommand {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    commands: Command,
+    parent_pids: HashMap<i32, i32>,
+}
+
+impl ParentCommand {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Parent Command"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            commands: Command::new(None),
+            parent_pids: HashMap::new(),
+        }
+    }
+}
+
+impl Column for ParentCommand {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // Commands are built in the same way as `Command` column, and looked up by PPID after all processes are added
+        self.commands.add(proc);
+        self.parent_pids.insert(proc.pid, proc.ppid);
+    }
+
+    fn streamable(&self) -> bool {
+        false
+    }
+
+    fn finish(&mut self) {
+        for (pid, ppid) in &self.parent_pids {
+            let fmt_content = self.commands.get(*ppid).cloned().unwrap_or_default();
+            let raw_content = fmt_content.clone();
+
+            self.fmt_contents.insert(*pid, fmt_content);
+            self.raw_contents.insert(*pid, raw_content);
+        }
+    }
+
+    column_default!(String);
+}
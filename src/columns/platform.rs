@@ -0,0 +1,300 @@
+use std::fmt;
+use Platform::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Platform {
+    Linux,
+    MacOs,
+    Windows,
+    FreeBsd,
+}
+
+impl Platform {
+    pub const ALL: [Platform; 4] = [
+        Platform::Linux,
+        Platform::MacOs,
+        Platform::Windows,
+        Platform::FreeBsd,
+    ];
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub const CURRENT: Platform = Platform::Linux;
+    #[cfg(target_os = "macos")]
+    pub const CURRENT: Platform = Platform::MacOs;
+    #[cfg(target_os = "windows")]
+    pub const CURRENT: Platform = Platform::Windows;
+    #[cfg(target_os = "freebsd")]
+    pub const CURRENT: Platform = Platform::FreeBsd;
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Platform::Linux => "Linux",
+            Platform::MacOs => "macOS",
+            Platform::Windows => "Windows",
+            Platform::FreeBsd => "FreeBSD",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Platforms supporting each kind of `KIND_LIST`.
+///
+/// `ConfigColumnKind` has only the kinds of the current platform,
+/// so this table is used to tell kinds of other platforms from unknown kinds.
+pub static KIND_PLATFORMS: &[(&str, &[Platform])] = &[
+    ("Audio", &[Linux]),
+    ("Audit", &[Linux]),
+    ("Baseline", &[Linux]),
+    ("BlockedSignals", &[Linux]),
+    ("Ccgroup", &[Linux]),
+    ("Cgroup", &[Linux]),
+    ("CgroupCpuMax", &[Linux]),
+    ("CgroupMemMax", &[Linux]),
+    ("Command", &[Linux, MacOs, Windows, FreeBsd]),
+    ("Container", &[Linux]),
+    ("ContextSw", &[Linux, MacOs, FreeBsd]),
+    ("CoreDump", &[Linux]),
+    ("CoreSched", &[Linux]),
+    ("CpuShare", &[Linux]),
+    ("CpuTime", &[Linux, MacOs, Windows, FreeBsd]),
+    ("CpuTimeSys", &[Linux, MacOs, Windows, FreeBsd]),
+    ("CpuTimeUser", &[Linux, MacOs, Windows, FreeBsd]),
+    ("Cwd", &[Linux, MacOs, FreeBsd]),
+    ("DbusNames", &[Linux]),
+    ("DeltaCpuTime", &[Linux]),
+    ("DeltaRss", &[Linux]),
+    ("Devices", &[Linux]),
+    ("Docker", &[Linux, MacOs]),
+    ("Eip", &[Linux]),
+    ("Elapsed", &[Linux, MacOs, Windows, FreeBsd]),
+    ("ElapsedTime", &[Linux, MacOs, Windows, FreeBsd]),
+    ("Empty", &[Linux, MacOs, Windows, FreeBsd]),
+    ("Energy", &[Linux, MacOs]),
+    ("Env", &[Linux, FreeBsd]),
+    ("Env:<name>", &[Linux, FreeBsd]),
+    ("Epoll", &[Linux]),
+    ("Esp", &[Linux]),
+    ("EventFd", &[Linux]),
+    ("ExeDeleted", &[Linux]),
+    ("ExeHash", &[Linux]),
+    ("FdCount", &[Linux]),
+    ("FileName", &[Linux, FreeBsd]),
+    ("Gid", &[Linux, MacOs, Windows, FreeBsd]),
+    ("GidFs", &[Linux]),
+    ("GidReal", &[Linux, MacOs, FreeBsd]),
+    ("GidSaved", &[Linux, MacOs, FreeBsd]),
+    ("Gpu", &[Linux]),
+    ("GpuMem", &[Linux]),
+    ("Group", &[Linux, MacOs, Windows, FreeBsd]),
+    ("GroupFs", &[Linux]),
+    ("GroupReal", &[Linux, MacOs, FreeBsd]),
+    ("GroupSaved", &[Linux, MacOs, FreeBsd]),
+    ("Identity", &[Linux]),
+    ("InotifyWatches", &[Linux]),
+    ("IoPrio", &[Linux]),
+    ("LaunchedBy", &[Linux]),
+    ("MajFlt", &[Linux, MacOs, Windows, FreeBsd]),
+    ("MajFltRate", &[Linux]),
+    ("Mappings", &[Linux]),
+    ("MemShare", &[Linux]),
+    ("MinFlt", &[Linux, MacOs, FreeBsd]),
+    ("MinFltRate", &[Linux]),
+    ("MntNs", &[Linux]),
+    ("MultiSlot", &[Linux, MacOs, Windows, FreeBsd]),
+    ("NetNs", &[Linux]),
+    ("Nice", &[Linux, MacOs, FreeBsd]),
+    ("NixPkg", &[Linux]),
+    ("NumaNode", &[Linux]),
+    ("OomScore", &[Linux]),
+    ("OomScoreAdj", &[Linux]),
+    ("ParentCommand", &[Linux, MacOs, Windows, FreeBsd]),
+    ("PendingSignals", &[Linux]),
+    ("Pgid", &[Linux, MacOs, FreeBsd]),
+    ("Pid", &[Linux, MacOs, Windows, FreeBsd]),
+    ("PidNs", &[Linux]),
+    ("Pod", &[Linux]),
+    ("Policy", &[Linux, MacOs]),
+    ("Ppid", &[Linux, MacOs, Windows, FreeBsd]),
+    ("Priority", &[Linux, MacOs, Windows, FreeBsd]),
+    ("Processor", &[Linux, FreeBsd]),
+    ("Pss", &[Linux]),
+    ("ReadBytes", &[Linux, MacOs, Windows, FreeBsd]),
+    ("ReadRate", &[Linux]),
+    ("Restarts", &[Linux]),
+    ("Retrans", &[Linux]),
+    ("RootDir", &[Linux]),
+    ("RtPriority", &[Linux]),
+    ("Sandbox", &[Linux]),
+    ("SchedPolicy", &[Linux]),
+    ("SecContext", &[Linux]),
+    ("SecLabel", &[Linux]),
+    ("Seccomp", &[Linux]),
+    ("Separator", &[Linux, MacOs, Windows, FreeBsd]),
+    ("Session", &[Linux, MacOs, FreeBsd]),
+    ("ShdPnd", &[Linux, FreeBsd]),
+    ("SigBlk", &[Linux, FreeBsd]),
+    ("SigCgt", &[Linux, FreeBsd]),
+    ("SigIgn", &[Linux, FreeBsd]),
+    ("SigPnd", &[Linux]),
+    ("Slot", &[Linux, MacOs, Windows, FreeBsd]),
+    ("Ssb", &[Linux]),
+    ("StartTime", &[Linux, MacOs, Windows, FreeBsd]),
+    ("State", &[Linux, MacOs, FreeBsd]),
+    ("TcpConn", &[Linux]),
+    ("TcpPort", &[Linux, MacOs, Windows]),
+    ("Threads", &[Linux, MacOs, Windows, FreeBsd]),
+    ("Throttled", &[Linux]),
+    ("TimerFd", &[Linux]),
+    ("TreeCpu", &[Linux]),
+    ("TreeRss", &[Linux]),
+    ("TreeSlot", &[Linux, MacOs, Windows, FreeBsd]),
+    ("Tty", &[Linux, MacOs, FreeBsd]),
+    ("UdpPort", &[Linux, MacOs]),
+    ("Uid", &[Linux, MacOs, Windows, FreeBsd]),
+    ("UidFs", &[Linux]),
+    ("UidLogin", &[Linux]),
+    ("UidReal", &[Linux, MacOs, FreeBsd]),
+    ("UidSaved", &[Linux, MacOs, FreeBsd]),
+    ("Unit", &[Linux]),
+    ("UsageCpu", &[Linux, MacOs, Windows, FreeBsd]),
+    ("UsageMem", &[Linux, MacOs, Windows, FreeBsd]),
+    ("UsageSys", &[Linux, MacOs, Windows, FreeBsd]),
+    ("UsageUser", &[Linux, MacOs, Windows, FreeBsd]),
+    ("User", &[Linux, MacOs, Windows, FreeBsd]),
+    ("UserFs", &[Linux]),
+    ("UserLogin", &[Linux]),
+    ("UserNs", &[Linux]),
+    ("UserReal", &[Linux, MacOs, FreeBsd]),
+    ("UserSaved", &[Linux, MacOs, FreeBsd]),
+    ("Uss", &[Linux]),
+    ("VmData", &[Linux, FreeBsd]),
+    ("VmExe", &[Linux, FreeBsd]),
+    ("VmHwm", &[Linux, Windows, FreeBsd]),
+    ("VmLib", &[Linux]),
+    ("VmLock", &[Linux]),
+    ("VmPeak", &[Linux, Windows]),
+    ("VmPin", &[Linux, Windows]),
+    ("VmPte", &[Linux]),
+    ("VmRss", &[Linux, MacOs, Windows, FreeBsd]),
+    ("VmSize", &[Linux, MacOs, Windows, FreeBsd]),
+    ("VmStack", &[Linux, FreeBsd]),
+    ("VmSwap", &[Linux, Windows]),
+    ("Wakeups", &[Linux, MacOs]),
+    ("Wchan", &[Linux, FreeBsd]),
+    ("WindowTitles", &[Linux]),
+    ("WorkDir", &[Linux]),
+    ("WriteBytes", &[Linux, MacOs, Windows, FreeBsd]),
+    ("WriteRate", &[Linux]),
+    ("YaraHits", &[Linux]),
+    ("ZombieChildren", &[Linux]),
+];
+
+/// Returns platforms supporting the kind ( case-insensitive ), or `None` if the kind is unknown on all platforms.
+pub fn kind_platforms(name: &str) -> Option<&'static [Platform]> {
+    KIND_PLATFORMS
+        .iter()
+        .find(|(x, _)| x.eq_ignore_ascii_case(name))
+        .map(|(_, x)| *x)
+}
+
+/// Removes columns whose kinds are supported only on other platforms, and returns the kinds.
+///
+/// Unknown kinds are kept so that they are reported as parse errors.
+pub fn remove_unsupported_columns(config: &mut toml::Value) -> Vec<String> {
+    let mut removed = Vec::new();
+    if let Some(columns) = config.get_mut("columns").and_then(|x| x.as_array_mut()) {
+        columns.retain(|column| {
+            let kind = column
+                .get("kind")
+                .and_then(|x| x.as_str())
+                .unwrap_or_default();
+            let unsupported = kind_platforms(kind).is_some_and(|x| !x.contains(&Platform::CURRENT));
+            if unsupported {
+                removed.push(kind.to_string());
+            }
+            !unsupported
+        });
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::columns::KIND_LIST;
+
+    #[test]
+    fn test_kind_platforms() {
+        for (name, _) in KIND_LIST.values() {
+            let platforms = kind_platforms(name).expect(name);
+            assert!(platforms.contains(&Platform::CURRENT), "{name}");
+        }
+        for (name, platforms) in KIND_PLATFORMS {
+            if platforms.contains(&Platform::CURRENT) {
+                assert!(KIND_LIST.values().any(|(x, _)| x == name), "{name}");
+            }
+        }
+        assert_eq!(kind_platforms("Wakeups"), Some(&[Linux, MacOs][..]));
+        assert_eq!(kind_platforms("Unknown"), None);
+    }
+
+    /// Names of `KIND_LIST` in the source, because it isn't compiled on other platforms
+    fn kind_list_names(source: &str) -> Vec<String> {
+        let start = source.find("pub static KIND_LIST").unwrap();
+        let list: String = source[start..]
+            .split(".collect()")
+            .next()
+            .unwrap()
+            .split_whitespace()
+            .collect();
+        list.split("(\"")
+            .skip(1)
+            .filter_map(|x| x.split('"').next())
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_kind_platforms_of_all_platforms() {
+        for (platform, source) in [
+            (Linux, include_str!("os_linux.rs")),
+            (MacOs, include_str!("os_macos.rs")),
+            (Windows, include_str!("os_windows.rs")),
+            (FreeBsd, include_str!("os_freebsd.rs")),
+        ] {
+            let names = kind_list_names(source);
+            assert!(names.iter().any(|x| x == "Pid"), "{platform}");
+            for name in &names {
+                let platforms = kind_platforms(name).unwrap_or_default();
+                assert!(platforms.contains(&platform), "{name} on {platform}");
+            }
+            for (name, platforms) in KIND_PLATFORMS {
+                if platforms.contains(&platform) {
+                    assert!(names.iter().any(|x| x == name), "{name} on {platform}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_remove_unsupported_columns() {
+        let other = KIND_PLATFORMS
+            .iter()
+            .find(|(_, x)| !x.contains(&Platform::CURRENT))
+            .map(|(x, _)| *x);
+        let mut config: toml::Value = toml::from_str(&format!(
+            "[[columns]]\nkind = \"Pid\"\n[[columns]]\nkind = \"{}\"\n[[columns]]\nkind = \"Unknown\"\n",
+            other.unwrap_or("Pid")
+        ))
+        .unwrap();
+        let removed = remove_unsupported_columns(&mut config);
+        assert_eq!(
+            removed,
+            other.map(String::from).into_iter().collect::<Vec<_>>()
+        );
+        let rest = config["columns"].as_array().unwrap().len();
+        assert_eq!(rest, 3 - removed.len());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::cmp;
+use std::collections::HashMap;
+use std::fs;
+
+/// Pod UIDs in cgroup paths of systemd driver ( `kubepods-burstable-pod<uid>.slice` )
+/// and cgroupfs driver ( `/kubepods/burstable/pod<uid>/` ).
+/// `-` in UID is replaced by `_` on systemd driver.
+static PAT_POD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"kubepods[^/]*-pod([0-9a-f_]{36})\.slice|/kubepods/(?:[^/]+/)?pod([0-9a-f-]{36})/")
+        .unwrap()
+});
+
+/// Container IDs at the end of cgroup paths ( ex. `cri-containerd-<64 hex>.scope`, `crio-<64 hex>.scope` )
+static PAT_CONTAINER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"([0-9a-f]{64})(?:\.scope)?$").unwrap());
+
+/// OCI runtime specs of containerd and CRI-O, which have annotations of the pod
+const SPEC_PATHS: &[&str] = &[
+    "/run/containerd/io.containerd.runtime.v2.task/k8s.io/{}/config.json",
+    "/run/containers/storage/overlay-containers/{}/userdata/config.json",
+];
+
+pub struct Pod {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    pods: HashMap<String, Option<String>>,
+}
+
+impl Pod {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Pod"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            pods: HashMap::new(),
+        }
+    }
+}
+
+impl Column for Pod {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let path = if let Ok(cgroups) = proc.curr_proc.cgroups() {
+            cgroups
+                .iter()
+                .find(|x| x.hierarchy == 0 && x.controllers.is_empty())
+                .or_else(|| cgroups.last())
+                .map(|x| x.pathname.clone())
+        } else {
+            None
+        };
+
+        let fmt_content = match path.as_deref().and_then(pod_uid) {
+            Some(uid) => {
+                // Pod sandboxes ( pause ) and conmon don't have container IDs at the end
+                let name = PAT_CONTAINER
+                    .captures(path.as_deref().unwrap_or_default())
+                    .and_then(|x| x.get(1))
+                    .and_then(|id| {
+                        self.pods
+                            .entry(id.as_str().to_string())
+                            .or_insert_with(|| pod_name(id.as_str()))
+                            .clone()
+                    });
+                name.unwrap_or(uid)
+            }
+            None => String::new(),
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+fn pod_uid(path: &str) -> Option<String> {
+    PAT_POD
+        .captures(path)
+        .and_then(|x| x.get(1).or_else(|| x.get(2)))
+        .map(|x| x.as_str().replace('_', "-"))
+}
+
+/// Gets `<namespace>/<name>` of the pod from annotations of the container.
+fn pod_name(id: &str) -> Option<String> {
+    // The runtime state of other systems can't be read
+    if !crate::process::is_local_procfs() {
+        return None;
+    }
+    for path in SPEC_PATHS {
+        let spec = if let Ok(x) = fs::read(path.replace("{}", id)) {
+            x
+        } else {
+            continue;
+        };
+        let spec: serde_json::Value = if let Ok(x) = serde_json::from_slice(&spec) {
+            x
+        } else {
+            continue;
+        };
+        let annotations = &spec["annotations"];
+        let name = annotations["io.kubernetes.cri.sandbox-name"]
+            .as_str()
+            .or_else(|| annotations["io.kubernetes.pod.name"].as_str());
+        let namespace = annotations["io.kubernetes.cri.sandbox-namespace"]
+            .as_str()
+            .or_else(|| annotations["io.kubernetes.pod.namespace"].as_str());
+        if let (Some(name), Some(namespace)) = (name, namespace) {
+            return Some(format!("{namespace}/{name}"));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pod_uid;
+
+    #[test]
+    fn test_pod_uid() {
+        let uid = "0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0";
+        assert_eq!(
+            pod_uid("/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod0f1e2d3c_4b5a_6978_8796_a5b4c3d2e1f0.slice/cri-containerd-0123.scope"),
+            Some(uid.to_string())
+        );
+        assert_eq!(
+            pod_uid(&format!("/kubepods/besteffort/pod{uid}/0123")),
+            Some(uid.to_string())
+        );
+        assert_eq!(
+            pod_uid(&format!("/kubepods/pod{uid}/0123")),
+            Some(uid.to_string())
+        );
+        assert_eq!(pod_uid("/system.slice/kubelet.service"), None);
+    }
+}
@@ -1,27 +1,29 @@
 use crate::process::ProcessInfo;
 use crate::util::bytify;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents, ValueFormat};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct ReadBytes {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, u64>,
     width: usize,
+    unit_suffix: bool,
 }
 
 impl ReadBytes {
-    pub fn new(header: Option<String>) -> Self {
+    pub fn new(header: Option<String>, unit_suffix: bool) -> Self {
         let header = header.unwrap_or_else(|| String::from("Read"));
         let unit = String::from("[B/s]");
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
             unit,
+            unit_suffix,
         }
     }
 }
@@ -29,13 +31,12 @@ impl ReadBytes {
 #[cfg(any(target_os = "linux", target_os = "android"))]
 impl Column for ReadBytes {
     fn add(&mut self, proc: &ProcessInfo) {
-        let (fmt_content, raw_content) = if proc.curr_io.is_some() && proc.prev_io.is_some() {
+        let (fmt_content, raw_content) = if let (Some(curr_io), Some(prev_io)) =
+            (proc.curr_io.as_ref(), proc.prev_io.as_ref())
+        {
             let interval_ms = proc.interval.as_secs() + u64::from(proc.interval.subsec_millis());
-            let io = (proc.curr_io.as_ref().unwrap().read_bytes
-                - proc.prev_io.as_ref().unwrap().read_bytes)
-                * 1000
-                / interval_ms;
-            (bytify(io), io)
+            let io = (curr_io.read_bytes - prev_io.read_bytes) * 1000 / interval_ms;
+            (bytify(io, self.unit_suffix), io)
         } else {
             (String::new(), 0)
         };
@@ -44,6 +45,10 @@ impl Column for ReadBytes {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
 
@@ -56,7 +61,7 @@ impl Column for ReadBytes {
                 - proc.prev_res.as_ref().unwrap().ri_diskio_bytesread)
                 * 1000
                 / interval_ms;
-            (bytify(io), io)
+            (bytify(io, self.unit_suffix), io)
         } else {
             (String::from(""), 0)
         };
@@ -65,6 +70,10 @@ impl Column for ReadBytes {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
 
@@ -75,12 +84,16 @@ impl Column for ReadBytes {
         let io = (proc.disk_info.curr_read - proc.disk_info.prev_read) * 1000 / interval_ms;
 
         let raw_content = io;
-        let fmt_content = bytify(raw_content);
+        let fmt_content = bytify(raw_content, self.unit_suffix);
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
 
@@ -97,11 +110,15 @@ impl Column for ReadBytes {
             / interval_ms;
 
         let raw_content = io;
-        let fmt_content = bytify(raw_content);
+        let fmt_content = bytify(raw_content, self.unit_suffix);
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
@@ -0,0 +1,66 @@
+use crate::process::ProcessInfo;
+use crate::util::{bytify, CounterRate};
+use crate::{column_default, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct ReadRate {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    unit_suffix: bool,
+    rate: CounterRate,
+}
+
+impl ReadRate {
+    pub fn new(header: Option<String>, unit_suffix: bool) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Read Rate"));
+        let unit = String::from("[B/s]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            unit_suffix,
+            rate: CounterRate::default(),
+        }
+    }
+}
+
+impl Column for ReadRate {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let raw_content = proc.curr_io.as_ref().and_then(|curr_io| {
+            self.rate.rate(
+                proc.pid,
+                proc.curr_proc.stat().starttime,
+                curr_io.read_bytes,
+                proc.prev_io.as_ref().map(|x| x.read_bytes),
+                proc.interval,
+            )
+        });
+        let fmt_content = raw_content
+            .map(|x| bytify(x, self.unit_suffix))
+            .unwrap_or_default();
+        let raw_content = raw_content.unwrap_or_default();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn finish(&mut self) {
+        self.rate.retain(&self.raw_contents);
+    }
+
+    fn persistent(&self) -> bool {
+        true
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
+    column_default!(u64);
+}
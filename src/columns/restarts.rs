@@ -0,0 +1,75 @@
+use crate::config::ConfigHistory;
+use crate::history::History;
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use chrono::{DateTime, Local};
+use once_cell::sync::Lazy;
+use std::cmp;
+use std::collections::HashMap;
+use std::path::Path;
+
+static TICKS_PER_SECOND: Lazy<u64> = Lazy::new(procfs::ticks_per_second);
+
+pub struct Restarts {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    history: Option<History>,
+    boot_time: Option<DateTime<Local>>,
+}
+
+impl Restarts {
+    pub fn new(header: Option<String>, config: &ConfigHistory) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Restarts"));
+        let unit = String::new();
+        let history = config
+            .path
+            .as_ref()
+            .map(|x| History::load(Path::new(x), config.retention));
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            history,
+            boot_time: crate::process::boot_time().ok(),
+        }
+    }
+}
+
+impl Column for Restarts {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let raw_content = if let (Some(history), Some(boot_time)) =
+            (self.history.as_mut(), self.boot_time)
+        {
+            let identity = crate::columns::identity::identity(proc);
+            let start = boot_time.timestamp() as u64
+                + proc.curr_proc.stat().starttime / *TICKS_PER_SECOND;
+            history.record(&identity, proc.pid, start);
+            history.restarts(&identity)
+        } else {
+            0
+        };
+        let fmt_content = format!("{raw_content}");
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn available(&self) -> bool {
+        self.history.is_some()
+    }
+
+    column_default!(u64);
+}
+
+impl Drop for Restarts {
+    fn drop(&mut self) {
+        if let Some(history) = &self.history {
+            let _ = history.save();
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::net::TcpInfo;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use procfs::process::FDTarget;
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Retrans {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    tcp_info: HashMap<u64, TcpInfo>,
+}
+
+impl Retrans {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Retrans"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            // sock_diag can see sockets of the network namespace of self only
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            tcp_info: if crate::process::is_local_procfs() {
+                crate::net::collect_tcp_info()
+            } else {
+                HashMap::new()
+            },
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for Retrans {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let (fmt_content, raw_content) = if let Ok(fds) = proc.curr_proc.fd() {
+            let mut retrans = None;
+            for fd in fds {
+                if let FDTarget::Socket(x) = fd.target {
+                    if let Some(info) = self.tcp_info.get(&x) {
+                        retrans = Some(retrans.unwrap_or(0) + u64::from(info.total_retrans));
+                    }
+                }
+            }
+            if let Some(x) = retrans {
+                (format!("{x}"), x)
+            } else {
+                (String::new(), 0)
+            }
+        } else {
+            (String::new(), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u64);
+}
@@ -0,0 +1,59 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+
+pub struct RootDir {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    host_root: Option<(u64, u64)>,
+}
+
+impl RootDir {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Root"));
+        let unit = String::new();
+        // The root of init is the root of the host even if procs is in a container with --host-proc
+        let host_root = fs::metadata(crate::process::procfs_root().join("1/root"))
+            .or_else(|_| fs::metadata("/"))
+            .ok()
+            .map(|x| (x.dev(), x.ino()));
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            host_root,
+        }
+    }
+}
+
+impl Column for RootDir {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // root of other users' processes can't be read without privilege
+        let root = proc
+            .curr_proc
+            .root_file()
+            .and_then(|x| Ok(x.metadata()?))
+            .map(|x| (x.dev(), x.ino()));
+        let fmt_content = match (root, proc.curr_proc.root()) {
+            (Ok(id), _) if Some(id) == self.host_root => String::new(),
+            // The path of the link is relative to the mount namespace of the process
+            (Ok(_), Ok(path)) if path.as_os_str() == "/" => String::from("/ (other mount ns)"),
+            (Ok(_), Ok(path)) => path.to_string_lossy().into_owned(),
+            _ => String::new(),
+        };
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
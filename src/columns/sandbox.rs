@@ -0,0 +1,133 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Sandbox {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+}
+
+impl Sandbox {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Sandbox"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+impl Column for Sandbox {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = sandbox_app(proc).unwrap_or_default();
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+/// Gets the application ID of Flatpak or Snap as `flatpak:<app ID>` or `snap:<snap name>`.
+///
+/// The scope created for the application is used at first, and `/.flatpak-info` in the root directory
+/// is used for Flatpak without systemd ( it can be read for processes of the same user only ).
+fn sandbox_app(proc: &ProcessInfo) -> Option<String> {
+    let from_cgroup = proc.curr_proc.cgroups().ok().and_then(|cgroups| {
+        cgroups
+            .iter()
+            .find(|x| x.hierarchy == 0 && x.controllers.is_empty())
+            .or_else(|| {
+                cgroups
+                    .iter()
+                    .find(|x| x.controllers.iter().any(|c| c == "name=systemd"))
+            })
+            .and_then(|x| sandbox_of(&x.pathname))
+    });
+    from_cgroup.or_else(|| {
+        let info = proc.curr_proc.flatpak_info()?;
+        flatpak_app(&info).map(|x| format!("flatpak:{x}"))
+    })
+}
+
+/// Gets the application from scopes or services in the cgroup path
+/// ( ex. `app-flatpak-org.mozilla.firefox-1234.scope`, `snap.firefox.firefox-<UUID>.scope`, `snap.lxd.daemon.service` ).
+fn sandbox_of(path: &str) -> Option<String> {
+    path.split('/').find_map(|x| {
+        if let Some(x) = x.strip_prefix("app-flatpak-") {
+            // The suffix is PID of the launcher
+            let (app, pid) = x.strip_suffix(".scope")?.rsplit_once('-')?;
+            pid.parse::<u32>().ok()?;
+            Some(format!("flatpak:{app}"))
+        } else if let Some(x) = x.strip_prefix("snap.") {
+            if !(x.ends_with(".scope") || x.ends_with(".service")) {
+                return None;
+            }
+            let name = x.split('.').next().filter(|x| !x.is_empty())?;
+            Some(format!("snap:{name}"))
+        } else {
+            None
+        }
+    })
+}
+
+/// Gets `name` of `[Application]` group from `.flatpak-info`.
+fn flatpak_app(info: &str) -> Option<&str> {
+    let mut in_application = false;
+    for line in info.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_application = line == "[Application]";
+        } else if in_application {
+            if let Some(x) = line.strip_prefix("name=") {
+                return Some(x);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sandbox_of() {
+        let cases = [
+            (
+                "/user.slice/user-1000.slice/user@1000.service/app.slice/app-flatpak-org.mozilla.firefox-4321.scope",
+                Some("flatpak:org.mozilla.firefox"),
+            ),
+            (
+                "/user.slice/user-1000.slice/user@1000.service/app.slice/snap.firefox.firefox-2c6b5c8e-4f1a-4d0e-9b8f-0a1b2c3d4e5f.scope",
+                Some("snap:firefox"),
+            ),
+            ("/system.slice/snap.lxd.daemon.service", Some("snap:lxd")),
+            ("/system.slice/snapd.service", None),
+            ("/system.slice/snap-core22-1122.mount", None),
+            (
+                "/user.slice/user-1000.slice/user@1000.service/app.slice/app-gnome-org.gnome.Terminal-1234.scope",
+                None,
+            ),
+            ("/", None),
+        ];
+        for (path, app) in cases {
+            assert_eq!(sandbox_of(path).as_deref(), app, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_flatpak_app() {
+        let info = "[Application]\nname=org.gnome.Calculator\nruntime=runtime/org.gnome.Platform/x86_64/46\n\n[Instance]\ninstance-id=1234\n";
+        assert_eq!(flatpak_app(info), Some("org.gnome.Calculator"));
+        assert_eq!(flatpak_app("[Runtime]\nname=org.gnome.Platform\n"), None);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::process::ProcessInfo;
+use crate::{column_default_text, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct SchedPolicy {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u32>,
+    width: usize,
+}
+
+impl SchedPolicy {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Sched"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+impl Column for SchedPolicy {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // stat has the same values as sched_getscheduler/sched_getparam, and can be read from other procfs
+        let stat = proc.curr_proc.stat();
+        let rt_priority = stat.rt_priority.unwrap_or_default();
+
+        // Real-time processes are sorted first in descending order, and by their priority
+        let (fmt_content, raw_content) = match stat.policy.map(|x| x as i32) {
+            Some(libc::SCHED_DEADLINE) => (String::from("DEADLINE"), 200),
+            Some(libc::SCHED_FIFO) => (format!("FIFO/{rt_priority}"), 100 + rt_priority),
+            Some(libc::SCHED_RR) => (format!("RR/{rt_priority}"), 100 + rt_priority),
+            Some(libc::SCHED_OTHER) => (String::from("OTHER"), 2),
+            Some(libc::SCHED_BATCH) => (String::from("BATCH"), 1),
+            Some(libc::SCHED_IDLE) => (String::from("IDLE"), 0),
+            _ => (String::new(), 0),
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default_text!(u32);
+}
@@ -1,5 +1,5 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
 #[cfg(any(target_os = "linux", target_os = "android"))]
@@ -8,7 +8,7 @@ use std::io::Read;
 pub struct SecContext {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
 }
@@ -18,7 +18,7 @@ impl SecContext {
         let header = header.unwrap_or_else(|| String::from("Context"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -30,7 +30,7 @@ impl SecContext {
 #[cfg(any(target_os = "linux", target_os = "android"))]
 impl Column for SecContext {
     fn add(&mut self, proc: &ProcessInfo) {
-        let fmt_content = if let Ok(proc) = procfs::process::Process::new(proc.pid) {
+        let fmt_content = if let Ok(proc) = crate::process::open_process(proc.pid) {
             if let Ok(mut file) = proc.open_relative("attr/current") {
                 let mut ret = String::new();
                 let _ = file.read_to_string(&mut ret);
@@ -0,0 +1,121 @@
+use crate::process::ProcessInfo;
+use crate::{Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+use std::io::Read;
+
+pub struct SecLabel {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+}
+
+impl SecLabel {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Label"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+impl Column for SecLabel {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // The label of LSM ( SELinux, AppArmor or Smack ) which is active on the system
+        let raw_content = crate::process::open_process(proc.pid)
+            .ok()
+            .and_then(|x| x.open_relative("attr/current").ok())
+            .map(|mut file| {
+                let mut ret = String::new();
+                let _ = file.read_to_string(&mut ret);
+                ret.trim_end_matches(['\0', '\n']).to_string()
+            })
+            .unwrap_or_default();
+        let fmt_content = abbreviate(&raw_content);
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    // Abbreviated labels are shown, but full labels are searched
+    fn find_partial(&self, pid: i32, keyword: &str, content_to_lowercase: bool) -> bool {
+        if let Some(content) = self.raw_contents.get(&pid) {
+            if content_to_lowercase {
+                content.to_ascii_lowercase().contains(keyword)
+            } else {
+                content.contains(keyword)
+            }
+        } else {
+            false
+        }
+    }
+
+    fn find_exact(&self, pid: i32, keyword: &str, content_to_lowercase: bool) -> bool {
+        if let Some(content) = self.raw_contents.get(&pid) {
+            if content_to_lowercase {
+                content.to_ascii_lowercase() == keyword
+            } else {
+                content == keyword
+            }
+        } else {
+            false
+        }
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_sorted_pid!(String);
+    crate::column_default_apply_visible!();
+    crate::column_default_clear!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
+
+/// Abbreviates SELinux contexts ( `user:role:type:level` ) to the type, and categories if exist.
+///
+/// ex. `system_u:system_r:container_t:s0:c12,c34` -> `container_t:c12,c34`
+///
+/// Labels of other LSMs ( ex. `/usr/sbin/cupsd (enforce)` of AppArmor ) are short enough.
+fn abbreviate(label: &str) -> String {
+    let fields: Vec<_> = label.splitn(4, ':').collect();
+    if fields.len() < 3 || label.contains(' ') {
+        return label.to_string();
+    }
+    let ty = fields[2];
+    match fields.get(3).and_then(|x| x.split_once(':')) {
+        Some((_, categories)) => format!("{ty}:{categories}"),
+        None => ty.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::abbreviate;
+
+    #[test]
+    fn test_abbreviate() {
+        assert_eq!(
+            abbreviate("system_u:system_r:httpd_t:s0"),
+            "httpd_t"
+        );
+        assert_eq!(
+            abbreviate("unconfined_u:unconfined_r:unconfined_t:s0-s0:c0.c1023"),
+            "unconfined_t:c0.c1023"
+        );
+        assert_eq!(
+            abbreviate("system_u:system_r:container_t:s0:c12,c34"),
+            "container_t:c12,c34"
+        );
+        assert_eq!(abbreviate("/usr/sbin/cupsd (enforce)"), "/usr/sbin/cupsd (enforce)");
+        assert_eq!(abbreviate("unconfined"), "unconfined");
+    }
+}
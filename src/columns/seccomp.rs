@@ -0,0 +1,46 @@
+use crate::process::ProcessInfo;
+use crate::{column_default_text, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Seccomp {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u32>,
+    width: usize,
+}
+
+impl Seccomp {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Seccomp"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+impl Column for Seccomp {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // The field is missing if the kernel is built without CONFIG_SECCOMP
+        let raw_content = proc.curr_status.as_ref().and_then(|x| x.seccomp);
+        let fmt_content = match raw_content {
+            Some(0) => String::from("disabled"),
+            Some(1) => String::from("strict"),
+            Some(2) => String::from("filter"),
+            Some(x) => format!("{x}"),
+            None => String::new(),
+        };
+        let raw_content = raw_content.unwrap_or_default();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default_text!(u32);
+}
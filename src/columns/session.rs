@@ -1,5 +1,5 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents};
 #[cfg(target_os = "macos")]
 use nix::unistd::{self, Pid};
 use std::cmp;
@@ -8,7 +8,7 @@ use std::collections::HashMap;
 pub struct Session {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, i32>,
     width: usize,
 }
@@ -18,7 +18,7 @@ impl Session {
         let header = header.unwrap_or_else(|| String::from("Session"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -1,12 +1,12 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default_text, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct SigCgt {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, u64>,
     width: usize,
 }
@@ -16,7 +16,7 @@ impl SigCgt {
         let header = header.unwrap_or_else(|| String::from("SigCgt"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -39,7 +39,7 @@ impl Column for SigCgt {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(u64);
+    column_default_text!(u64);
 }
 
 #[cfg(target_os = "freebsd")]
@@ -52,5 +52,5 @@ impl Column for SigCgt {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(u64);
+    column_default_text!(u64);
 }
@@ -1,5 +1,5 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents};
 #[cfg(not(target_os = "windows"))]
 use chrono::offset::TimeZone;
 #[cfg(any(target_os = "linux", target_os = "android"))]
@@ -16,29 +16,61 @@ static TICKS_PER_SECOND: Lazy<u64> = Lazy::new(procfs::ticks_per_second);
 pub struct StartTime {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, DateTime<Local>>,
     width: usize,
+    format: String,
     #[cfg(any(target_os = "linux", target_os = "android"))]
     boot_time: DateTime<Local>,
 }
 
 impl StartTime {
-    pub fn new(header: Option<String>) -> Self {
+    /// If `format` is empty, the format is chosen from the locale by `LC_ALL`, `LC_TIME` and `LANG`.
+    pub fn new(header: Option<String>, format: &str) -> Self {
         let header = header.unwrap_or_else(|| String::from("Start"));
         let unit = String::new();
+        let format = if format.is_empty() {
+            String::from(locale_format(&locale()))
+        } else {
+            String::from(format)
+        };
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
             unit,
+            format,
             #[cfg(any(target_os = "linux", target_os = "android"))]
-            boot_time: procfs::boot_time().unwrap_or_else(|_| Local.timestamp_opt(0, 0).unwrap()),
+            boot_time: crate::process::boot_time().unwrap_or_else(|_| Local.timestamp_opt(0, 0).unwrap()),
         }
     }
 }
 
+fn locale() -> String {
+    ["LC_ALL", "LC_TIME", "LANG"]
+        .iter()
+        .filter_map(|x| std::env::var(x).ok())
+        .find(|x| !x.is_empty())
+        .unwrap_or_default()
+}
+
+/// Order of date fields by locale, time is always 24-hour because AM/PM names can't be localized
+fn locale_format(locale: &str) -> &'static str {
+    let lang = locale.split(['.', '@']).next().unwrap_or_default();
+    let (language, territory) = lang.split_once('_').unwrap_or((lang, ""));
+    match (language, territory) {
+        ("en", "US" | "PH") => "%m/%d/%Y %H:%M",
+        ("en", "" | "CA") => "%Y/%m/%d %H:%M",
+        ("en" | "fr" | "es" | "it" | "pt" | "el" | "nl" | "id" | "vi", _) => "%d/%m/%Y %H:%M",
+        ("de" | "ru" | "uk" | "pl" | "cs" | "sk" | "fi" | "nb" | "da" | "tr" | "ro", _) => {
+            "%d.%m.%Y %H:%M"
+        }
+        ("sv" | "lt", _) => "%Y-%m-%d %H:%M",
+        _ => "%Y/%m/%d %H:%M",
+    }
+}
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 impl Column for StartTime {
     fn add(&mut self, proc: &ProcessInfo) {
@@ -46,7 +78,7 @@ impl Column for StartTime {
         let seconds_since_boot = starttime as f32 / *TICKS_PER_SECOND as f32;
         let raw_content = self.boot_time
             + Duration::try_milliseconds((seconds_since_boot * 1000.0) as i64).unwrap_or_default();
-        let fmt_content = format!("{}", raw_content.format("%Y/%m/%d %H:%M"));
+        let fmt_content = format!("{}", raw_content.format(&self.format));
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
@@ -62,7 +94,7 @@ impl Column for StartTime {
             .timestamp_opt(proc.curr_task.pbsd.pbi_start_tvsec as i64, 0)
             .unwrap();
         let raw_content = start_time;
-        let fmt_content = format!("{}", start_time.format("%Y/%m/%d %H:%M"));
+        let fmt_content = format!("{}", start_time.format(&self.format));
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
@@ -75,7 +107,7 @@ impl Column for StartTime {
 impl Column for StartTime {
     fn add(&mut self, proc: &ProcessInfo) {
         let raw_content = proc.start_time;
-        let fmt_content = format!("{}", proc.start_time.format("%Y/%m/%d %H:%M"));
+        let fmt_content = format!("{}", proc.start_time.format(&self.format));
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
@@ -91,7 +123,7 @@ impl Column for StartTime {
             .timestamp_opt(proc.curr_proc.info.start.sec as i64, 0)
             .unwrap();
         let raw_content = start_time;
-        let fmt_content = format!("{}", start_time.format("%Y/%m/%d %H:%M"));
+        let fmt_content = format!("{}", start_time.format(&self.format));
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
@@ -99,3 +131,19 @@ impl Column for StartTime {
 
     column_default!(DateTime<Local>);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_format() {
+        assert_eq!(locale_format(""), "%Y/%m/%d %H:%M");
+        assert_eq!(locale_format("C.UTF-8"), "%Y/%m/%d %H:%M");
+        assert_eq!(locale_format("ja_JP.UTF-8"), "%Y/%m/%d %H:%M");
+        assert_eq!(locale_format("en_US.UTF-8"), "%m/%d/%Y %H:%M");
+        assert_eq!(locale_format("en_GB.UTF-8"), "%d/%m/%Y %H:%M");
+        assert_eq!(locale_format("de_DE.UTF-8@euro"), "%d.%m.%Y %H:%M");
+        assert_eq!(locale_format("sv_SE"), "%Y-%m-%d %H:%M");
+    }
+}
@@ -0,0 +1,187 @@
+use crate::process::ProcessInfo;
+use crate::{Column, Contents};
+use procfs::net::TcpState;
+use procfs::process::FDTarget;
+use std::cmp;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+
+/// Number of remote endpoints shown before the rest is abbreviated
+const TOP_REMOTES: usize = 2;
+
+pub struct TcpConn {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    /// Remote endpoints of established sockets by inode
+    remotes: HashMap<u64, SocketAddr>,
+    /// All remote endpoints of each process for search
+    search_contents: HashMap<i32, String>,
+}
+
+impl TcpConn {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Connections"));
+        let unit = String::new();
+        let mut remotes = HashMap::new();
+        for file in ["net/tcp", "net/tcp6"] {
+            if let Ok(x) = crate::process::read_procfs_si::<procfs::net::TcpNetEntries>(file) {
+                for entry in x.0 {
+                    if entry.state == TcpState::Established {
+                        remotes.insert(entry.inode, entry.remote_address);
+                    }
+                }
+            }
+        }
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            remotes,
+            search_contents: HashMap::new(),
+        }
+    }
+}
+
+impl Column for TcpConn {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let mut remotes = Vec::new();
+        // fd of other users' processes can't be read without privilege
+        if let Ok(fds) = proc.curr_proc.fd() {
+            for fd in fds {
+                if let FDTarget::Socket(x) = fd.target {
+                    if let Some(remote) = self.remotes.get(&x) {
+                        remotes.push(unmap(*remote));
+                    }
+                }
+            }
+        }
+        let fmt_content = summarize(&remotes);
+        let raw_content = remotes.len() as u64;
+        let search_content = remotes
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+        self.search_contents.insert(proc.pid, search_content);
+    }
+
+    // Top remotes are shown, but all remotes are searched
+    fn find_partial(&self, pid: i32, keyword: &str, content_to_lowercase: bool) -> bool {
+        if let Some(content) = self.search_contents.get(&pid) {
+            if content_to_lowercase {
+                content.to_ascii_lowercase().contains(keyword)
+            } else {
+                content.contains(keyword)
+            }
+        } else {
+            false
+        }
+    }
+
+    fn find_exact(&self, pid: i32, keyword: &str, content_to_lowercase: bool) -> bool {
+        if let Some(content) = self.search_contents.get(&pid) {
+            content.split(' ').any(|x| {
+                if content_to_lowercase {
+                    x.to_ascii_lowercase() == keyword
+                } else {
+                    x == keyword
+                }
+            })
+        } else {
+            false
+        }
+    }
+
+    fn clear(&mut self) {
+        self.fmt_contents.clear();
+        self.raw_contents.clear();
+        self.search_contents.clear();
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_sorted_pid!(u64);
+    crate::column_default_apply_visible!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
+
+/// Sockets of dual-stack listeners have IPv4-mapped IPv6 addresses
+fn unmap(addr: SocketAddr) -> SocketAddr {
+    match addr.ip() {
+        IpAddr::V6(x) => match x.to_ipv4_mapped() {
+            Some(v4) => SocketAddr::new(IpAddr::V4(v4), addr.port()),
+            None => addr,
+        },
+        IpAddr::V4(_) => addr,
+    }
+}
+
+/// Summarizes connections as the count and the most connected remotes.
+///
+/// ex. `5: 10.0.0.5:5432 (3), 192.0.2.1:443, +1`
+fn summarize(remotes: &[SocketAddr]) -> String {
+    if remotes.is_empty() {
+        return String::new();
+    }
+
+    let mut counts: HashMap<&SocketAddr, usize> = HashMap::new();
+    for x in remotes {
+        *counts.entry(x).or_default() += 1;
+    }
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|(a1, n1), (a2, n2)| n2.cmp(n1).then(a1.cmp(a2)));
+
+    let mut top: Vec<_> = counts
+        .iter()
+        .take(TOP_REMOTES)
+        .map(|(addr, n)| {
+            if *n > 1 {
+                format!("{addr} ({n})")
+            } else {
+                addr.to_string()
+            }
+        })
+        .collect();
+    if counts.len() > TOP_REMOTES {
+        top.push(format!("+{}", counts.len() - TOP_REMOTES));
+    }
+    format!("{}: {}", remotes.len(), top.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize() {
+        let addr = |x: &str| x.parse::<SocketAddr>().unwrap();
+        assert_eq!(summarize(&[]), "");
+        assert_eq!(summarize(&[addr("192.0.2.1:443")]), "1: 192.0.2.1:443");
+        let remotes = [
+            addr("192.0.2.1:443"),
+            addr("10.0.0.5:5432"),
+            addr("10.0.0.5:5432"),
+            addr("10.0.0.5:5432"),
+            addr("[2001:db8::1]:80"),
+        ];
+        assert_eq!(
+            summarize(&remotes),
+            "5: 10.0.0.5:5432 (3), 192.0.2.1:443, +1"
+        );
+        assert_eq!(
+            unmap(addr("[::ffff:192.0.2.1]:443")),
+            addr("192.0.2.1:443")
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::process::ProcessInfo;
-use crate::Column;
+use crate::{Column, Contents};
 #[cfg(target_os = "macos")]
 use libproc::libproc::net_info::TcpSIState;
 #[cfg(any(target_os = "linux", target_os = "android"))]
@@ -20,7 +20,7 @@ use windows_sys::Win32::Networking::WinSock::{ntohl, ntohs};
 pub struct TcpPort {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
     #[cfg(any(target_os = "linux", target_os = "android", target_os = "windows"))]
@@ -34,15 +34,19 @@ impl TcpPort {
         let header = header.unwrap_or_else(|| String::from("TCP"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
             unit,
             #[cfg(any(target_os = "linux", target_os = "android"))]
-            tcp_entry: procfs::net::tcp().unwrap_or_default(),
+            tcp_entry: crate::process::read_procfs_si::<procfs::net::TcpNetEntries>("net/tcp")
+                .map(|x| x.0)
+                .unwrap_or_default(),
             #[cfg(any(target_os = "linux", target_os = "android"))]
-            tcp6_entry: procfs::net::tcp6().unwrap_or_default(),
+            tcp6_entry: crate::process::read_procfs_si::<procfs::net::TcpNetEntries>("net/tcp6")
+                .map(|x| x.0)
+                .unwrap_or_default(),
             #[cfg(target_os = "windows")]
             tcp_entry: get_tcp_entry_list().unwrap_or_default(),
             #[cfg(target_os = "windows")]
@@ -106,6 +110,7 @@ impl Column for TcpPort {
     crate::column_default_find_partial!();
     crate::column_default_sorted_pid!(String);
     crate::column_default_apply_visible!();
+    crate::column_default_clear!();
     crate::column_default_reset_width!();
     crate::column_default_update_width!();
     crate::column_default_get_width!();
@@ -152,6 +157,7 @@ impl Column for TcpPort {
     crate::column_default_find_partial!();
     crate::column_default_sorted_pid!(String);
     crate::column_default_apply_visible!();
+    crate::column_default_clear!();
     crate::column_default_reset_width!();
     crate::column_default_update_width!();
     crate::column_default_get_width!();
@@ -196,6 +202,7 @@ impl Column for TcpPort {
     crate::column_default_find_partial!();
     crate::column_default_sorted_pid!(String);
     crate::column_default_apply_visible!();
+    crate::column_default_clear!();
     crate::column_default_reset_width!();
     crate::column_default_update_width!();
     crate::column_default_get_width!();
@@ -0,0 +1,99 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+pub struct Throttled {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    prev_stats: HashMap<PathBuf, u64>,
+    prev_time: Instant,
+    cache: HashMap<PathBuf, Option<u64>>,
+}
+
+impl Throttled {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Throttled"));
+        let unit = String::from("[%]");
+
+        // The column is created before collecting processes,
+        // so the throttled time of all cgroups is sampled here as the previous value.
+        let mut prev_stats = HashMap::new();
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if let Some(mount) = crate::process::cgroup2_mount() {
+            collect_throttled_usec(mount, &mut prev_stats);
+        }
+
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            prev_stats,
+            prev_time: Instant::now(),
+            cache: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for Throttled {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let ratio = if let Some(dir) = proc.curr_proc.cgroup2_dir() {
+            let prev_stats = &self.prev_stats;
+            let interval = self.prev_time.elapsed().as_micros() as u64;
+            *self.cache.entry(dir).or_insert_with_key(|dir| {
+                let prev = prev_stats.get(dir)?;
+                let curr = get_throttled_usec(dir)?;
+                // per-mille of wall time
+                (interval != 0).then(|| curr.saturating_sub(*prev) * 1000 / interval)
+            })
+        } else {
+            None
+        };
+
+        let (fmt_content, raw_content) = if let Some(ratio) = ratio {
+            (format!("{:.1}", ratio as f64 / 10.0), ratio)
+        } else {
+            (String::new(), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u64, 10.0);
+}
+
+fn get_throttled_usec(dir: &Path) -> Option<u64> {
+    let stat = fs::read_to_string(dir.join("cpu.stat")).ok()?;
+    stat.lines().find_map(|x| {
+        x.strip_prefix("throttled_usec ")
+            .and_then(|x| x.trim().parse().ok())
+    })
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn collect_throttled_usec(dir: &Path, map: &mut HashMap<PathBuf, u64>) {
+    if let Some(x) = get_throttled_usec(dir) {
+        map.insert(dir.to_path_buf(), x);
+    }
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            if entry.file_type().map(|x| x.is_dir()).unwrap_or(false) {
+                collect_throttled_usec(&entry.path(), map);
+            }
+        }
+    }
+}
@@ -126,7 +126,11 @@ impl Column for Tree {
         false
     }
 
-    fn sorted_pid(&self, _order: &crate::config::ConfigSortOrder) -> Vec<i32> {
+    fn sorted_pid(
+        &self,
+        _order: &crate::config::ConfigSortOrder,
+        _tie_break: Option<&HashMap<i32, usize>>,
+    ) -> Vec<i32> {
         let mut root_pids = Vec::new();
         for p in self.rev_tree.values() {
             if !self.rev_tree.contains_key(p) {
@@ -230,6 +234,7 @@ mod tests {
             stat: Process::myself().unwrap().stat().unwrap(),
             proc: Process::myself().unwrap(),
             owner: Process::myself().unwrap().uid().unwrap(),
+            cache: Default::default(),
         };
         let prev_stat = Process::myself().unwrap().stat().unwrap();
 
@@ -248,6 +253,7 @@ mod tests {
             stat: Process::myself().unwrap().stat().unwrap(),
             proc: Process::myself().unwrap(),
             owner: Process::myself().unwrap().uid().unwrap(),
+            cache: Default::default(),
         };
         let prev_stat = Process::myself().unwrap().stat().unwrap();
 
@@ -266,6 +272,7 @@ mod tests {
             stat: Process::myself().unwrap().stat().unwrap(),
             proc: Process::myself().unwrap(),
             owner: Process::myself().unwrap().uid().unwrap(),
+            cache: Default::default(),
         };
         let prev_stat = Process::myself().unwrap().stat().unwrap();
 
@@ -0,0 +1,70 @@
+use crate::process::{ProcessInfo, ProcessTask};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::util::roll_up;
+use crate::{column_default, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct TreeCpu {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u32>,
+    width: usize,
+    usages: HashMap<i32, f64>,
+    parent_pids: HashMap<i32, i32>,
+}
+
+impl TreeCpu {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Tree CPU"));
+        let unit = String::from("[%]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            usages: HashMap::new(),
+            parent_pids: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for TreeCpu {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let curr_stat = proc.curr_proc.stat();
+        let prev_stat = &proc.prev_stat;
+
+        let curr_time = curr_stat.utime + curr_stat.stime;
+        let prev_time = prev_stat.utime + prev_stat.stime;
+        let usage_ms = (curr_time - prev_time) * 1000 / procfs::ticks_per_second();
+        let interval_ms = proc.interval.as_secs() * 1000 + u64::from(proc.interval.subsec_millis());
+        let usage = usage_ms as f64 * 100.0 / interval_ms as f64;
+
+        self.usages.insert(proc.pid, usage);
+        // Threads are included in the usage of the process, so they are not rolled up
+        if let ProcessTask::Process { .. } = proc.curr_proc {
+            self.parent_pids.insert(proc.pid, proc.ppid);
+        }
+    }
+
+    fn streamable(&self) -> bool {
+        false
+    }
+
+    fn finish(&mut self) {
+        let totals = roll_up(&self.usages, &self.parent_pids);
+        for (pid, total) in totals {
+            self.fmt_contents.insert(pid, format!("{total:.1}"));
+            self.raw_contents.insert(pid, (total * 1000.0) as u32);
+        }
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
+}
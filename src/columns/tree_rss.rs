@@ -0,0 +1,73 @@
+use crate::process::{ProcessInfo, ProcessTask};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::util::{bytify, roll_up};
+use crate::{column_default, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct TreeRss {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    sizes: HashMap<i32, u64>,
+    parent_pids: HashMap<i32, i32>,
+    unit_suffix: bool,
+    smaps: bool,
+}
+
+impl TreeRss {
+    pub fn new(header: Option<String>, unit_suffix: bool, smaps: bool) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Tree RSS"));
+        let unit = String::from("[bytes]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            unit_suffix,
+            smaps,
+            sizes: HashMap::new(),
+            parent_pids: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for TreeRss {
+    fn add(&mut self, proc: &ProcessInfo) {
+        use procfs::WithCurrentSystemInfo;
+
+        // Threads share the memory of the process, so they are neither counted nor rolled up
+        if let ProcessTask::Process { .. } = proc.curr_proc {
+            // PSS divides shared pages by the number of sharing processes, so the sum isn't double-counted.
+            // It's read from smaps_rollup only if smaps is enabled because it's costly.
+            let pss = if self.smaps { proc.curr_proc.pss() } else { None };
+            let size = pss.unwrap_or_else(|| proc.curr_proc.stat().rss_bytes().get());
+            self.sizes.insert(proc.pid, size);
+            self.parent_pids.insert(proc.pid, proc.ppid);
+        } else {
+            self.sizes.insert(proc.pid, 0);
+        }
+    }
+
+    fn streamable(&self) -> bool {
+        false
+    }
+
+    fn finish(&mut self) {
+        let totals = roll_up(&self.sizes, &self.parent_pids);
+        for (pid, total) in totals {
+            self.fmt_contents.insert(pid, bytify(total, self.unit_suffix));
+            self.raw_contents.insert(pid, total);
+        }
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
+    column_default!(u64);
+}
@@ -1,5 +1,5 @@
 use crate::process::ProcessInfo;
-use crate::Column;
+use crate::{Column, Contents};
 #[cfg(any(target_os = "linux", target_os = "android"))]
 use procfs::net::UdpNetEntry;
 #[cfg(any(target_os = "linux", target_os = "android"))]
@@ -10,7 +10,7 @@ use std::collections::HashMap;
 pub struct UdpPort {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
     #[cfg(any(target_os = "linux", target_os = "android"))]
@@ -24,15 +24,19 @@ impl UdpPort {
         let header = header.unwrap_or_else(|| String::from("UDP"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
             unit,
             #[cfg(any(target_os = "linux", target_os = "android"))]
-            udp_entry: procfs::net::udp().unwrap_or_default(),
+            udp_entry: crate::process::read_procfs_si::<procfs::net::UdpNetEntries>("net/udp")
+                .map(|x| x.0)
+                .unwrap_or_default(),
             #[cfg(any(target_os = "linux", target_os = "android"))]
-            udp6_entry: procfs::net::udp6().unwrap_or_default(),
+            udp6_entry: crate::process::read_procfs_si::<procfs::net::UdpNetEntries>("net/udp6")
+                .map(|x| x.0)
+                .unwrap_or_default(),
         }
     }
 }
@@ -90,6 +94,7 @@ impl Column for UdpPort {
     crate::column_default_find_partial!();
     crate::column_default_sorted_pid!(String);
     crate::column_default_apply_visible!();
+    crate::column_default_clear!();
     crate::column_default_reset_width!();
     crate::column_default_update_width!();
     crate::column_default_get_width!();
@@ -136,6 +141,7 @@ impl Column for UdpPort {
     crate::column_default_find_partial!();
     crate::column_default_sorted_pid!(String);
     crate::column_default_apply_visible!();
+    crate::column_default_clear!();
     crate::column_default_reset_width!();
     crate::column_default_update_width!();
     crate::column_default_get_width!();
@@ -0,0 +1,115 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+/// Suffixes of unit types which can have processes
+const UNIT_SUFFIXES: &[&str] = &[".service", ".scope", ".socket", ".mount", ".swap"];
+
+pub struct Unit {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+}
+
+impl Unit {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Unit"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+impl Column for Unit {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = systemd_unit(proc).unwrap_or_default();
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(String);
+}
+
+/// Gets the systemd unit which the process belongs to.
+pub fn systemd_unit(proc: &ProcessInfo) -> Option<String> {
+    // The hierarchy of systemd is the unified one on cgroup v2, and "name=systemd" on cgroup v1
+    proc.curr_proc.cgroups().ok().and_then(|cgroups| {
+        cgroups
+            .iter()
+            .find(|x| x.hierarchy == 0 && x.controllers.is_empty())
+            .or_else(|| {
+                cgroups
+                    .iter()
+                    .find(|x| x.controllers.iter().any(|c| c == "name=systemd"))
+            })
+            .and_then(|x| unit_of(&x.pathname))
+    })
+}
+
+/// Gets the unit from the cgroup path in the same way as `sd_pid_get_unit` and `sd_pid_get_user_unit`.
+///
+/// Slices are skipped and the first unit is used, because sub-cgroups of the unit
+/// ( ex. `container` of Podman ) are not units.
+/// Processes of a user manager are shown by the user unit ( ex. `app.service` under `user@1000.service` ).
+fn unit_of(path: &str) -> Option<String> {
+    let mut ret = None;
+    for x in path.split('/').filter(|x| !x.is_empty()) {
+        if x.ends_with(".slice") {
+            continue;
+        }
+        if !UNIT_SUFFIXES.iter().any(|s| x.ends_with(s)) {
+            break;
+        }
+        // init.scope of a user manager is the manager itself
+        if ret.is_some() && x == "init.scope" {
+            break;
+        }
+        ret = Some(x.to_string());
+        if !(x.starts_with("user@") && x.ends_with(".service")) {
+            break;
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unit_of;
+
+    #[test]
+    fn test_unit_of() {
+        let cases = [
+            ("/system.slice/nginx.service", Some("nginx.service")),
+            ("/init.scope", Some("init.scope")),
+            ("/user.slice/user-1000.slice/session-2.scope", Some("session-2.scope")),
+            (
+                "/user.slice/user-1000.slice/user@1000.service/app.slice/foo.service",
+                Some("foo.service"),
+            ),
+            (
+                "/user.slice/user-1000.slice/user@1000.service/init.scope",
+                Some("user@1000.service"),
+            ),
+            (
+                "/user.slice/user-1000.slice/user@1000.service/user.slice/libpod-0123.scope/container",
+                Some("libpod-0123.scope"),
+            ),
+            ("/system.slice/docker-0123.scope/nested", Some("docker-0123.scope")),
+            ("/", None),
+            ("/kubepods/burstable/pod0123/0456", None),
+        ];
+        for (path, unit) in cases {
+            assert_eq!(unit_of(path).as_deref(), unit);
+        }
+    }
+}
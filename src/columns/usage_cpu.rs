@@ -1,12 +1,12 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents, ValueFormat};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct UsageCpu {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, u32>,
     width: usize,
 }
@@ -16,7 +16,7 @@ impl UsageCpu {
         let header = header.unwrap_or_else(|| String::from("CPU"));
         let unit = String::from("[%]");
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -44,7 +44,11 @@ impl Column for UsageCpu {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(u32);
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
 }
 
 #[cfg(target_os = "macos")]
@@ -65,7 +69,11 @@ impl Column for UsageCpu {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(u32);
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
 }
 
 #[cfg(target_os = "windows")]
@@ -85,7 +93,11 @@ impl Column for UsageCpu {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(u32);
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
 }
 
 #[cfg(target_os = "freebsd")]
@@ -106,5 +118,34 @@ impl Column for UsageCpu {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(u32);
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
+}
+
+#[cfg(all(test, any(target_os = "linux", target_os = "android")))]
+mod tests {
+    use super::*;
+    use crate::config::ConfigColumnAlign;
+    use crate::process::{collect_proc_from, fixture_procfs};
+
+    #[test]
+    fn test_usage_cpu() {
+        let mut column = UsageCpu::new(None);
+        for proc in collect_proc_from(&fixture_procfs(), true, true) {
+            column.add(&proc);
+            column.update_width(proc.pid, None);
+        }
+        let content = |pid| {
+            column
+                .display_content(pid, &ConfigColumnAlign::Left)
+                .map(|x| x.trim().to_string())
+        };
+        assert_eq!(content(1).as_deref(), Some("50.0"));
+        assert_eq!(content(100).as_deref(), Some("0.0"));
+        assert_eq!(content(200).as_deref(), Some("150.0"));
+        assert_eq!(content(201).as_deref(), Some("100.0"));
+    }
 }
@@ -1,7 +1,7 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents, ValueFormat};
 #[cfg(any(target_os = "linux", target_os = "android"))]
-use procfs::{Current, Meminfo, WithCurrentSystemInfo};
+use procfs::{Meminfo, WithCurrentSystemInfo};
 use std::cmp;
 use std::collections::HashMap;
 #[cfg(target_os = "windows")]
@@ -12,7 +12,7 @@ use windows_sys::Win32::System::ProcessStatus::{GetPerformanceInfo, PERFORMANCE_
 pub struct UsageMem {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, u32>,
     width: usize,
     mem_total: u64,
@@ -24,7 +24,7 @@ impl UsageMem {
         let unit = String::from("[%]");
 
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -36,7 +36,7 @@ impl UsageMem {
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
 fn get_mem_total() -> u64 {
-    let meminfo = Meminfo::current();
+    let meminfo: Result<Meminfo, _> = crate::process::read_procfs("meminfo");
     if let Ok(meminfo) = meminfo {
         meminfo.mem_total
     } else {
@@ -105,7 +105,11 @@ impl Column for UsageMem {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(u32);
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
 }
 
 #[cfg(target_os = "macos")]
@@ -119,7 +123,11 @@ impl Column for UsageMem {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(u32);
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
 }
 
 #[cfg(target_os = "windows")]
@@ -133,7 +141,11 @@ impl Column for UsageMem {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(u32);
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
 }
 
 #[cfg(target_os = "freebsd")]
@@ -147,5 +159,9 @@ impl Column for UsageMem {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
-    column_default!(u32);
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
 }
@@ -0,0 +1,123 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct UsageUser {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u32>,
+    width: usize,
+}
+
+impl UsageUser {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("User CPU"));
+        let unit = String::from("[%]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for UsageUser {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let curr_stat = proc.curr_proc.stat();
+        let prev_stat = &proc.prev_stat;
+
+        let curr_time = curr_stat.utime;
+        let prev_time = prev_stat.utime;
+        let usage_ms = (curr_time - prev_time) * 1000 / procfs::ticks_per_second();
+        let interval_ms = proc.interval.as_secs() * 1000 + u64::from(proc.interval.subsec_millis());
+        let usage = usage_ms as f64 * 100.0 / interval_ms as f64;
+
+        let fmt_content = format!("{usage:.1}");
+        let raw_content = (usage * 1000.0) as u32;
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
+}
+
+#[cfg(target_os = "macos")]
+impl Column for UsageUser {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let curr_time = proc.curr_task.ptinfo.pti_total_user;
+        let prev_time = proc.prev_task.ptinfo.pti_total_user;
+        let usage_ms = (curr_time - prev_time) / 1000000u64;
+        let interval_ms = proc.interval.as_secs() * 1000 + u64::from(proc.interval.subsec_millis());
+        let usage = usage_ms as f64 * 100.0 / interval_ms as f64;
+
+        let fmt_content = format!("{:.1}", usage);
+        let raw_content = (usage * 1000.0) as u32;
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
+}
+
+#[cfg(target_os = "windows")]
+impl Column for UsageUser {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let curr_time = proc.cpu_info.curr_user;
+        let prev_time = proc.cpu_info.prev_user;
+
+        let usage_ms = (curr_time - prev_time) / 10000u64;
+        let interval_ms = proc.interval.as_secs() * 1000 + u64::from(proc.interval.subsec_millis());
+        let usage = usage_ms as f64 * 100.0 / interval_ms as f64;
+
+        let fmt_content = format!("{:.1}", usage);
+        let raw_content = (usage * 1000.0) as u32;
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
+}
+
+#[cfg(target_os = "freebsd")]
+impl Column for UsageUser {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let curr_time = proc.curr_proc.info.rusage.utime.to_us() as u64;
+        let prev_time = proc.prev_proc.info.rusage.utime.to_us() as u64;
+        let usage_ms = (curr_time - prev_time) / 1_000u64;
+        let interval_ms = proc.interval.as_secs() * 1000 + u64::from(proc.interval.subsec_millis());
+        let usage = usage_ms as f64 * 100.0 / interval_ms as f64;
+
+        let fmt_content = format!("{:.1}", usage);
+        let raw_content = (usage * 1000.0) as u32;
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Number(1)
+    }
+
+    column_default!(u32, 1000.0);
+}
+
@@ -2,17 +2,15 @@ use crate::process::ProcessInfo;
 #[cfg(target_os = "windows")]
 use crate::util::format_sid;
 #[cfg(not(target_os = "windows"))]
-use crate::util::USERS_CACHE;
-use crate::{column_default, Column};
+use crate::util::get_user_by_uid;
+use crate::{column_default, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
-#[cfg(not(target_os = "windows"))]
-use uzers::Users;
 
 pub struct User {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
     #[allow(dead_code)]
@@ -24,7 +22,7 @@ impl User {
         let header = header.unwrap_or_else(|| String::from("User"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -37,7 +35,7 @@ impl User {
 #[cfg(any(target_os = "linux", target_os = "android"))]
 impl Column for User {
     fn add(&mut self, proc: &ProcessInfo) {
-        let user = USERS_CACHE.with(|x| x.borrow_mut().get_user_by_uid(proc.curr_proc.owner()));
+        let user = get_user_by_uid(proc.curr_proc.owner());
         let fmt_content = if let Some(user) = user {
             format!("{}", user.name().to_string_lossy())
         } else {
@@ -57,7 +55,7 @@ impl Column for User {
     fn add(&mut self, proc: &ProcessInfo) {
         let uid = proc.curr_task.pbsd.pbi_uid;
         let fmt_content =
-            if let Some(user) = USERS_CACHE.with(|x| x.borrow_mut().get_user_by_uid(uid)) {
+            if let Some(user) = get_user_by_uid(uid) {
                 format!("{}", user.name().to_string_lossy())
             } else {
                 format!("{}", uid)
@@ -93,7 +91,7 @@ impl Column for User {
     fn add(&mut self, proc: &ProcessInfo) {
         let uid = proc.curr_proc.info.uid;
         let fmt_content =
-            if let Some(user) = USERS_CACHE.with(|x| x.borrow_mut().get_user_by_uid(uid)) {
+            if let Some(user) = get_user_by_uid(uid) {
                 format!("{}", user.name().to_string_lossy())
             } else {
                 format!("{}", uid)
@@ -1,14 +1,13 @@
 use crate::process::ProcessInfo;
-use crate::util::USERS_CACHE;
-use crate::{column_default, Column};
+use crate::util::get_user_by_uid;
+use crate::{column_default, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
-use uzers::Users;
 
 pub struct UserFs {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
 }
@@ -18,7 +17,7 @@ impl UserFs {
         let header = header.unwrap_or_else(|| String::from("File System User"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -31,7 +30,7 @@ impl Column for UserFs {
     fn add(&mut self, proc: &ProcessInfo) {
         let fmt_content = if let Some(ref status) = proc.curr_status {
             let uid = status.fuid;
-            if let Some(user) = USERS_CACHE.with(|x| x.borrow_mut().get_user_by_uid(uid)) {
+            if let Some(user) = get_user_by_uid(uid) {
                 format!("{}", user.name().to_string_lossy())
             } else {
                 format!("{uid}")
@@ -1,16 +1,15 @@
 use crate::process::ProcessInfo;
-use crate::util::USERS_CACHE;
-use crate::{column_default, Column};
+use crate::util::get_user_by_uid;
+use crate::{column_default, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
-use uzers::Users;
 
 const UID_NOT_SET: u32 = 0xffffffff;
 
 pub struct UserLogin {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
 }
@@ -20,7 +19,7 @@ impl UserLogin {
         let header = header.unwrap_or_else(|| String::from("Login User"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -32,7 +31,7 @@ impl UserLogin {
 impl Column for UserLogin {
     fn add(&mut self, proc: &ProcessInfo) {
         let fmt_content = if let Ok(uid) = proc.curr_proc.loginuid() {
-            if let Some(user) = USERS_CACHE.with(|x| x.borrow_mut().get_user_by_uid(uid)) {
+            if let Some(user) = get_user_by_uid(uid) {
                 format!("{}", user.name().to_string_lossy())
             } else if uid == UID_NOT_SET {
                 String::new()
@@ -0,0 +1,75 @@
+use crate::process::ProcessInfo;
+use crate::util::bytify;
+use crate::column::Samples;
+use crate::{column_default, Column, Contents, ValueFormat};
+use std::cmp;
+use std::collections::HashMap;
+use std::time::Instant;
+
+pub struct Uss {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    unit_suffix: bool,
+    enabled: bool,
+    samples: Samples<u64>,
+}
+
+impl Uss {
+    pub fn new(header: Option<String>, unit_suffix: bool, enabled: bool) -> Self {
+        let header = header.unwrap_or_else(|| String::from("USS"));
+        let unit = String::from("[bytes]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            unit_suffix,
+            enabled,
+            samples: Samples::new(),
+        }
+    }
+}
+
+impl Column for Uss {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // smaps_rollup of other users' processes can't be read without privilege,
+        // and the last sample is shown if the read fails by other reasons in watch mode
+        let start = proc.curr_proc.stat().starttime;
+        let raw_content = self
+            .samples
+            .update(proc.pid, start, proc.curr_proc.uss());
+        let fmt_content = raw_content
+            .map(|x| bytify(x, self.unit_suffix))
+            .unwrap_or_default();
+        let raw_content = raw_content.unwrap_or_default();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn finish(&mut self) {
+        self.samples.finish();
+    }
+
+    fn persistent(&self) -> bool {
+        true
+    }
+
+    fn stale_since(&self, pid: i32) -> Option<Instant> {
+        self.samples.stale_since(pid)
+    }
+
+    fn available(&self) -> bool {
+        self.enabled
+    }
+
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
+    column_default!(u64);
+}
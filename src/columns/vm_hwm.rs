@@ -1,27 +1,29 @@
 use crate::process::ProcessInfo;
 use crate::util::bytify;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents, ValueFormat};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct VmHwm {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, u64>,
     width: usize,
+    unit_suffix: bool,
 }
 
 impl VmHwm {
-    pub fn new(header: Option<String>) -> Self {
+    pub fn new(header: Option<String>, unit_suffix: bool) -> Self {
         let header = header.unwrap_or_else(|| String::from("VmHwm"));
         let unit = String::from("[bytes]");
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
             unit,
+            unit_suffix,
         }
     }
 }
@@ -32,7 +34,7 @@ impl Column for VmHwm {
         let (raw_content, fmt_content) = if let Some(ref curr_status) = proc.curr_status {
             if let Some(val) = curr_status.vmhwm {
                 let val = val.saturating_mul(1024);
-                (val, bytify(val))
+                (val, bytify(val, self.unit_suffix))
             } else {
                 (0, String::new())
             }
@@ -44,6 +46,10 @@ impl Column for VmHwm {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
 
@@ -51,12 +57,16 @@ impl Column for VmHwm {
 impl Column for VmHwm {
     fn add(&mut self, proc: &ProcessInfo) {
         let raw_content = proc.memory_info.peak_working_set_size;
-        let fmt_content = bytify(raw_content);
+        let fmt_content = bytify(raw_content, self.unit_suffix);
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
 
@@ -64,11 +74,15 @@ impl Column for VmHwm {
 impl Column for VmHwm {
     fn add(&mut self, proc: &ProcessInfo) {
         let raw_content = (proc.curr_proc.info.rusage.maxrss as u64).saturating_mul(4096);
-        let fmt_content = bytify(raw_content);
+        let fmt_content = bytify(raw_content, self.unit_suffix);
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
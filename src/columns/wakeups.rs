@@ -0,0 +1,75 @@
+use crate::process::ProcessInfo;
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::process::WakeupSample;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use std::sync::Arc;
+
+pub struct Wakeups {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    prev_wakeups: Arc<WakeupSample>,
+}
+
+impl Wakeups {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Wakeups"));
+        let unit = String::from("[/s]");
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            prev_wakeups: crate::process::wakeup_sample(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for Wakeups {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let wakeups = self.prev_wakeups.rate(proc);
+
+        let (fmt_content, raw_content) = if let Some(wakeups) = wakeups {
+            (format!("{wakeups:.0}"), (wakeups * 1000.0) as u64)
+        } else {
+            (String::new(), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u64, 1000.0);
+}
+
+#[cfg(target_os = "macos")]
+impl Column for Wakeups {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let (fmt_content, raw_content) = if let (Some(curr), Some(prev)) =
+            (&proc.curr_res, &proc.prev_res)
+        {
+            let interval_ms =
+                proc.interval.as_secs() * 1000 + u64::from(proc.interval.subsec_millis());
+            let curr = curr.ri_pkg_idle_wkups + curr.ri_interrupt_wkups;
+            let prev = prev.ri_pkg_idle_wkups + prev.ri_interrupt_wkups;
+            let wakeups = curr.saturating_sub(prev) as f64 * 1000.0 / interval_ms as f64;
+            (format!("{:.0}", wakeups), (wakeups * 1000.0) as u64)
+        } else {
+            (String::new(), 0)
+        };
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    column_default!(u64, 1000.0);
+}
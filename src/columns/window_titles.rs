@@ -0,0 +1,349 @@
+use crate::process::{ProcessInfo, ProcessTask};
+use crate::{Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+use std::env;
+use std::io::{self, Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::time::Duration;
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{AtomEnum, ConnectionExt, Window};
+
+pub struct WindowTitles {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, String>,
+    width: usize,
+    /// Titles by PID through X11
+    titles: HashMap<i32, Vec<String>>,
+    /// Titles by app ID through Wayland
+    app_titles: HashMap<String, Vec<String>>,
+    /// Processes matched with app IDs, and their parent PIDs
+    app_pids: HashMap<i32, (i32, String)>,
+}
+
+impl WindowTitles {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Windows"));
+        let unit = String::new();
+
+        // _NET_WM_PID is PID of the display host, so it can't be matched with other procfs.
+        let local = crate::process::is_local_procfs();
+        let titles = if local {
+            get_window_titles().unwrap_or_default()
+        } else {
+            HashMap::new()
+        };
+        let app_titles = if local {
+            get_wayland_titles().unwrap_or_default()
+        } else {
+            HashMap::new()
+        };
+
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            titles,
+            app_titles,
+            app_pids: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for WindowTitles {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let fmt_content = if let Some(titles) = self.titles.get(&proc.pid) {
+            titles.join(",")
+        } else {
+            String::new()
+        };
+        if fmt_content.is_empty() {
+            if let ProcessTask::Process { stat, .. } = &proc.curr_proc {
+                let comm = stat.comm.to_lowercase();
+                if let Some(app_id) = self.app_titles.keys().find(|x| app_id_matches(x, &comm)) {
+                    self.app_pids.insert(proc.pid, (proc.ppid, app_id.clone()));
+                }
+            }
+        }
+        let raw_content = fmt_content.clone();
+
+        self.fmt_contents.insert(proc.pid, fmt_content);
+        self.raw_contents.insert(proc.pid, raw_content);
+    }
+
+    fn finish(&mut self) {
+        // Children of an application ( ex. renderers of browsers ) have the same name,
+        // so titles are shown at the topmost process only
+        for (pid, (ppid, app_id)) in &self.app_pids {
+            if self.app_pids.get(ppid).is_some_and(|x| x.1 == *app_id) {
+                continue;
+            }
+            let content = self.app_titles[app_id].join(",");
+            self.fmt_contents.insert(*pid, content.clone());
+            self.raw_contents.insert(*pid, content);
+        }
+    }
+
+    fn streamable(&self) -> bool {
+        self.app_titles.is_empty()
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_find_exact!();
+    crate::column_default_sorted_pid!(String);
+    crate::column_default_apply_visible!();
+    crate::column_default_clear!();
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+}
+
+/// App IDs are often reverse domain names ( ex. `org.gnome.Nautilus` ), so the last part is matched as well.
+fn app_id_matches(app_id: &str, comm: &str) -> bool {
+    app_id == comm || app_id.rsplit('.').next() == Some(comm)
+}
+
+/// Gets titles of top-level windows from the window manager through X11 ( including XWayland ).
+fn get_window_titles() -> Option<HashMap<i32, Vec<String>>> {
+    let (conn, screen_num) = x11rb::connect(None).ok()?;
+    let root = conn.setup().roots.get(screen_num)?.root;
+
+    let intern = |name: &[u8]| -> Option<u32> {
+        Some(conn.intern_atom(false, name).ok()?.reply().ok()?.atom)
+    };
+    let client_list = intern(b"_NET_CLIENT_LIST")?;
+    let wm_pid = intern(b"_NET_WM_PID")?;
+    let wm_name = intern(b"_NET_WM_NAME")?;
+    let utf8_string = intern(b"UTF8_STRING")?;
+
+    let windows: Vec<Window> = conn
+        .get_property(false, root, client_list, AtomEnum::WINDOW, 0, u32::MAX)
+        .ok()?
+        .reply()
+        .ok()?
+        .value32()?
+        .collect();
+
+    let mut ret: HashMap<i32, Vec<String>> = HashMap::new();
+    for window in windows {
+        let pid = conn
+            .get_property(false, window, wm_pid, AtomEnum::CARDINAL, 0, 1)
+            .ok()
+            .and_then(|x| x.reply().ok())
+            .and_then(|x| x.value32().and_then(|mut x| x.next()));
+        let pid = if let Some(x) = pid {
+            x as i32
+        } else {
+            continue;
+        };
+
+        let get_title = |property: u32, type_: u32| -> Option<String> {
+            let reply = conn
+                .get_property(false, window, property, type_, 0, u32::MAX)
+                .ok()?
+                .reply()
+                .ok()?;
+            (!reply.value.is_empty()).then(|| String::from_utf8_lossy(&reply.value).into_owned())
+        };
+        let title = get_title(wm_name, utf8_string)
+            .or_else(|| get_title(AtomEnum::WM_NAME.into(), AtomEnum::STRING.into()))
+            .unwrap_or_default();
+
+        ret.entry(pid).or_default().push(title);
+    }
+    Some(ret)
+}
+
+const WL_DISPLAY: u32 = 1;
+const WL_REGISTRY: u32 = 2;
+const WL_CALLBACK_GLOBALS: u32 = 3;
+const TOPLEVEL_MANAGER: u32 = 4;
+const WL_CALLBACK_TOPLEVELS: u32 = 5;
+const TOPLEVEL_MANAGER_INTERFACE: &str = "zwlr_foreign_toplevel_manager_v1";
+
+/// Gets titles of top-level windows by app ID through the foreign-toplevel protocol of Wayland.
+///
+/// Wayland doesn't expose PIDs of clients, so titles are matched with processes by app ID.
+/// Compositors which don't support the protocol ( ex. GNOME ) give nothing.
+fn get_wayland_titles() -> Option<HashMap<String, Vec<String>>> {
+    let display = env::var_os("WAYLAND_DISPLAY")?;
+    let path = PathBuf::from(&display);
+    let path = if path.is_absolute() {
+        path
+    } else {
+        PathBuf::from(env::var_os("XDG_RUNTIME_DIR")?).join(display)
+    };
+    let stream = UnixStream::connect(path).ok()?;
+    stream.set_read_timeout(Some(Duration::from_secs(1))).ok()?;
+    wayland_toplevels(stream).ok()
+}
+
+fn wayland_toplevels(mut stream: UnixStream) -> io::Result<HashMap<String, Vec<String>>> {
+    let mut ret: HashMap<String, Vec<String>> = HashMap::new();
+
+    // wl_display.get_registry and wl_display.sync to receive all globals
+    stream.write_all(&wl_message(WL_DISPLAY, 1, &wl_uint(WL_REGISTRY)))?;
+    stream.write_all(&wl_message(WL_DISPLAY, 0, &wl_uint(WL_CALLBACK_GLOBALS)))?;
+    let mut manager = None;
+    loop {
+        let (object, opcode, mut body) = wl_read(&mut stream)?;
+        match (object, opcode) {
+            (WL_REGISTRY, 0) => {
+                let name = body.uint()?;
+                let interface = body.string()?;
+                let version = body.uint()?;
+                if interface == TOPLEVEL_MANAGER_INTERFACE {
+                    manager = Some((name, version));
+                }
+            }
+            (WL_CALLBACK_GLOBALS, 0) => break,
+            (WL_DISPLAY, 0) => return Err(io::ErrorKind::InvalidData.into()),
+            _ => (),
+        }
+    }
+    let (name, version) = if let Some(x) = manager {
+        x
+    } else {
+        return Ok(ret);
+    };
+
+    // wl_registry.bind, and the compositor sends the current toplevels with their titles and app IDs
+    let mut args = wl_uint(name);
+    args.extend(wl_string(TOPLEVEL_MANAGER_INTERFACE));
+    args.extend(wl_uint(cmp::min(version, 3)));
+    args.extend(wl_uint(TOPLEVEL_MANAGER));
+    stream.write_all(&wl_message(WL_REGISTRY, 0, &args))?;
+    stream.write_all(&wl_message(WL_DISPLAY, 0, &wl_uint(WL_CALLBACK_TOPLEVELS)))?;
+
+    let mut toplevels: HashMap<u32, (String, String)> = HashMap::new();
+    loop {
+        let (object, opcode, mut body) = wl_read(&mut stream)?;
+        match (object, opcode) {
+            (TOPLEVEL_MANAGER, 0) => {
+                toplevels.insert(body.uint()?, Default::default());
+            }
+            (WL_CALLBACK_TOPLEVELS, 0) => break,
+            (WL_DISPLAY, 0) => return Err(io::ErrorKind::InvalidData.into()),
+            (x, 0) if toplevels.contains_key(&x) => {
+                toplevels.get_mut(&x).unwrap().0 = body.string()?;
+            }
+            (x, 1) if toplevels.contains_key(&x) => {
+                toplevels.get_mut(&x).unwrap().1 = body.string()?.to_lowercase();
+            }
+            _ => (),
+        }
+    }
+
+    let mut toplevels: Vec<_> = toplevels.into_iter().collect();
+    toplevels.sort();
+    for (_, (title, app_id)) in toplevels {
+        if !app_id.is_empty() {
+            ret.entry(app_id).or_default().push(title);
+        }
+    }
+    Ok(ret)
+}
+
+fn wl_message(object: u32, opcode: u16, args: &[u8]) -> Vec<u8> {
+    let size = (8 + args.len()) as u32;
+    let mut ret = wl_uint(object);
+    ret.extend(wl_uint((size << 16) | u32::from(opcode)));
+    ret.extend(args);
+    ret
+}
+
+fn wl_uint(x: u32) -> Vec<u8> {
+    x.to_ne_bytes().to_vec()
+}
+
+/// A string is the length including NUL, and the bytes padded to 32-bit
+fn wl_string(x: &str) -> Vec<u8> {
+    let mut ret = wl_uint(x.len() as u32 + 1);
+    ret.extend(x.as_bytes());
+    ret.push(0);
+    ret.resize(ret.len().next_multiple_of(4), 0);
+    ret
+}
+
+fn wl_read(stream: &mut UnixStream) -> io::Result<(u32, u16, WlBody)> {
+    let mut header = [0; 8];
+    stream.read_exact(&mut header)?;
+    let object = u32::from_ne_bytes(header[0..4].try_into().unwrap());
+    let word = u32::from_ne_bytes(header[4..8].try_into().unwrap());
+    let size = (word >> 16) as usize;
+    if size < 8 {
+        return Err(io::ErrorKind::InvalidData.into());
+    }
+    let mut body = vec![0; size - 8];
+    stream.read_exact(&mut body)?;
+    Ok((object, word as u16, WlBody(body, 0)))
+}
+
+/// Arguments of an event, and the read position
+struct WlBody(Vec<u8>, usize);
+
+impl WlBody {
+    fn uint(&mut self) -> io::Result<u32> {
+        let x = self
+            .0
+            .get(self.1..self.1 + 4)
+            .ok_or(io::ErrorKind::InvalidData)?;
+        self.1 += 4;
+        Ok(u32::from_ne_bytes(x.try_into().unwrap()))
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        let len = self.uint()? as usize;
+        let x = self
+            .0
+            .get(self.1..self.1 + len)
+            .ok_or(io::ErrorKind::InvalidData)?;
+        self.1 += len.next_multiple_of(4);
+        Ok(String::from_utf8_lossy(x.strip_suffix(&[0]).unwrap_or(x)).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wayland_toplevels() {
+        let (client, mut server) = UnixStream::pair().unwrap();
+        let toplevel = |id: u32, title: &str, app_id: &str| {
+            let mut ret = wl_message(TOPLEVEL_MANAGER, 0, &wl_uint(id));
+            ret.extend(wl_message(id, 0, &wl_string(title)));
+            ret.extend(wl_message(id, 1, &wl_string(app_id)));
+            // done
+            ret.extend(wl_message(id, 5, &[]));
+            ret
+        };
+        let mut global = wl_uint(7);
+        global.extend(wl_string(TOPLEVEL_MANAGER_INTERFACE));
+        global.extend(wl_uint(3));
+
+        // The compositor responds in order, so all events can be sent in advance
+        let mut events = wl_message(WL_REGISTRY, 0, &global);
+        events.extend(wl_message(WL_CALLBACK_GLOBALS, 0, &wl_uint(0)));
+        events.extend(toplevel(0xff000000, "Inbox - Mozilla Firefox", "firefox"));
+        events.extend(toplevel(0xff000001, "~/src", "org.gnome.Nautilus"));
+        events.extend(toplevel(0xff000002, "Downloads", "org.gnome.Nautilus"));
+        events.extend(wl_message(WL_CALLBACK_TOPLEVELS, 0, &wl_uint(0)));
+        server.write_all(&events).unwrap();
+
+        let titles = wayland_toplevels(client).unwrap();
+        assert_eq!(titles["firefox"], ["Inbox - Mozilla Firefox"]);
+        assert_eq!(titles["org.gnome.nautilus"], ["~/src", "Downloads"]);
+        assert!(app_id_matches("org.gnome.nautilus", "nautilus"));
+        assert!(!app_id_matches("org.gnome.nautilus", "gnome"));
+    }
+}
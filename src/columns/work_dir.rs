@@ -1,12 +1,12 @@
 use crate::process::ProcessInfo;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct WorkDir {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, String>,
     width: usize,
 }
@@ -16,7 +16,7 @@ impl WorkDir {
         let header = header.unwrap_or_else(|| String::from("WorkDir"));
         let unit = String::new();
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
@@ -28,7 +28,7 @@ impl WorkDir {
 #[cfg(any(target_os = "linux", target_os = "android"))]
 impl Column for WorkDir {
     fn add(&mut self, proc: &ProcessInfo) {
-        let fmt_content = if let Ok(proc) = procfs::process::Process::new(proc.pid) {
+        let fmt_content = if let Ok(proc) = crate::process::open_process(proc.pid) {
             if let Ok(path) = proc.cwd() {
                 path.to_string_lossy().to_string()
             } else {
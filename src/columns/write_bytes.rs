@@ -1,27 +1,29 @@
 use crate::process::ProcessInfo;
 use crate::util::bytify;
-use crate::{column_default, Column};
+use crate::{column_default, Column, Contents, ValueFormat};
 use std::cmp;
 use std::collections::HashMap;
 
 pub struct WriteBytes {
     header: String,
     unit: String,
-    fmt_contents: HashMap<i32, String>,
+    fmt_contents: Contents,
     raw_contents: HashMap<i32, u64>,
     width: usize,
+    unit_suffix: bool,
 }
 
 impl WriteBytes {
-    pub fn new(header: Option<String>) -> Self {
+    pub fn new(header: Option<String>, unit_suffix: bool) -> Self {
         let header = header.unwrap_or_else(|| String::from("Write"));
         let unit = String::from("[B/s]");
         Self {
-            fmt_contents: HashMap::new(),
+            fmt_contents: Contents::new(),
             raw_contents: HashMap::new(),
             width: 0,
             header,
             unit,
+            unit_suffix,
         }
     }
 }
@@ -29,13 +31,12 @@ impl WriteBytes {
 #[cfg(any(target_os = "linux", target_os = "android"))]
 impl Column for WriteBytes {
     fn add(&mut self, proc: &ProcessInfo) {
-        let (fmt_content, raw_content) = if proc.curr_io.is_some() && proc.prev_io.is_some() {
+        let (fmt_content, raw_content) = if let (Some(curr_io), Some(prev_io)) =
+            (proc.curr_io.as_ref(), proc.prev_io.as_ref())
+        {
             let interval_ms = proc.interval.as_secs() + u64::from(proc.interval.subsec_millis());
-            let io = (proc.curr_io.as_ref().unwrap().write_bytes
-                - proc.prev_io.as_ref().unwrap().write_bytes)
-                * 1000
-                / interval_ms;
-            (bytify(io), io)
+            let io = (curr_io.write_bytes - prev_io.write_bytes) * 1000 / interval_ms;
+            (bytify(io, self.unit_suffix), io)
         } else {
             (String::new(), 0)
         };
@@ -44,6 +45,10 @@ impl Column for WriteBytes {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
 
@@ -56,7 +61,7 @@ impl Column for WriteBytes {
                 - proc.prev_res.as_ref().unwrap().ri_diskio_byteswritten)
                 * 1000
                 / interval_ms;
-            (bytify(io), io)
+            (bytify(io, self.unit_suffix), io)
         } else {
             (String::from(""), 0)
         };
@@ -65,6 +70,10 @@ impl Column for WriteBytes {
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
 
@@ -75,12 +84,16 @@ impl Column for WriteBytes {
         let io = (proc.disk_info.curr_write - proc.disk_info.prev_write) * 1000 / interval_ms;
 
         let raw_content = io;
-        let fmt_content = bytify(raw_content);
+        let fmt_content = bytify(raw_content, self.unit_suffix);
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
 
@@ -97,11 +110,15 @@ impl Column for WriteBytes {
             / interval_ms;
 
         let raw_content = io;
-        let fmt_content = bytify(raw_content);
+        let fmt_content = bytify(raw_content, self.unit_suffix);
 
         self.fmt_contents.insert(proc.pid, fmt_content);
         self.raw_contents.insert(proc.pid, raw_content);
     }
 
+    fn value_format(&self) -> ValueFormat {
+        ValueFormat::Bytes(self.unit_suffix)
+    }
+
     column_default!(u64);
 }
@@ -0,0 +1,186 @@
+use crate::column::Samples;
+use crate::process::ProcessInfo;
+use crate::{Column, Contents};
+use anyhow::{anyhow, Error};
+use once_cell::sync::OnceCell;
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+use std::time::Instant;
+
+/// Timeout of each scan in seconds
+const TIMEOUT: i32 = 10;
+
+/// Compiled rules, and whether memory of processes is scanned
+static RULES: OnceCell<(yara::Rules, bool)> = OnceCell::new();
+
+/// Compiles the rules file of `--yara`, which is done once even if views are renewed in watch mode.
+pub fn load_rules(path: &Path, memory: bool) -> Result<(), Error> {
+    RULES.get_or_try_init(|| {
+        let rules = yara::Compiler::new()
+            .map_err(yara::Error::from)
+            .and_then(|x| x.add_rules_file(path))
+            .map_err(|e| anyhow!("failed to load YARA rules {}: {e}", path.display()))?
+            .compile_rules()
+            .map_err(|e| anyhow!("failed to compile YARA rules {}: {e}", path.display()))?;
+        Ok::<_, Error>((rules, memory))
+    })?;
+    Ok(())
+}
+
+/// Executables are identified by device and inode, and modification time and size
+type FileStamp = (u64, u64, i64, u64);
+
+pub struct YaraHits {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, usize>,
+    width: usize,
+    memory: bool,
+    /// Hits of executables by PID, which memory hits of visible processes are added to
+    hits: HashMap<i32, Vec<String>>,
+    /// PIDs whose memory is scanned already in this view
+    scanned: HashSet<i32>,
+    /// Start time of each process to keep samples of the same process
+    starts: HashMap<i32, u64>,
+    cache: HashMap<FileStamp, Vec<String>>,
+    seen: HashSet<FileStamp>,
+    /// Last hits which are shown while the executable or memory can't be scanned
+    exe_samples: Samples<Vec<String>>,
+    memory_samples: Samples<Vec<String>>,
+}
+
+impl YaraHits {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("YARA"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            // Memory of processes can be scanned only through PIDs of self
+            memory: RULES.get().is_some_and(|x| x.1) && crate::process::is_local_procfs(),
+            hits: HashMap::new(),
+            scanned: HashSet::new(),
+            starts: HashMap::new(),
+            cache: HashMap::new(),
+            seen: HashSet::new(),
+            exe_samples: Samples::new(),
+            memory_samples: Samples::new(),
+        }
+    }
+
+    fn insert(&mut self, pid: i32, mut hits: Vec<String>) {
+        hits.sort();
+        hits.dedup();
+        self.fmt_contents.insert(pid, hits.join(","));
+        self.raw_contents.insert(pid, hits.len());
+        self.hits.insert(pid, hits);
+    }
+
+    fn scan_exe(&mut self, rules: &yara::Rules, pid: i32) -> Option<Vec<String>> {
+        // exe of other users' processes can't be read without privilege
+        let exe = crate::process::procfs_root()
+            .join(pid.to_string())
+            .join("exe");
+        let meta = fs::metadata(&exe).ok()?;
+        let stamp = (meta.dev(), meta.ino(), meta.mtime(), meta.size());
+        self.seen.insert(stamp);
+
+        // Many processes share the same executable, so each file is scanned once while it isn't modified
+        if let Some(x) = self.cache.get(&stamp) {
+            return Some(x.clone());
+        }
+        let hits: Vec<String> = rules
+            .scan_file(&exe, TIMEOUT)
+            .ok()?
+            .iter()
+            .map(|x| x.identifier.to_string())
+            .collect();
+        self.cache.insert(stamp, hits.clone());
+        Some(hits)
+    }
+}
+
+impl Column for YaraHits {
+    fn add(&mut self, proc: &ProcessInfo) {
+        let start = proc.curr_proc.stat().starttime;
+        let hits = RULES
+            .get()
+            .and_then(|(rules, _)| self.scan_exe(rules, proc.pid));
+        let hits = self.exe_samples.update(proc.pid, start, hits);
+        self.starts.insert(proc.pid, start);
+        self.insert(proc.pid, hits.unwrap_or_default());
+    }
+
+    fn finish(&mut self) {
+        let seen = std::mem::take(&mut self.seen);
+        self.cache.retain(|x, _| seen.contains(x));
+        self.exe_samples.finish();
+        // Memory samples are updated by `apply_visible` after this, so samples of processes
+        // which were visible at the previous refresh are kept
+        self.memory_samples.finish();
+    }
+
+    fn apply_visible(&mut self, visible_pids: &[i32]) {
+        // Scanning memory is costly ( up to `TIMEOUT` for each process ), so only visible processes are scanned
+        let rules = match RULES.get() {
+            Some((rules, _)) if self.memory => rules,
+            _ => return,
+        };
+        for pid in visible_pids {
+            if !self.scanned.insert(*pid) {
+                continue;
+            }
+            let (Some(mut hits), Some(start)) = (self.hits.remove(pid), self.starts.get(pid))
+            else {
+                continue;
+            };
+            let memory = rules
+                .scan_process(*pid as u32, TIMEOUT)
+                .ok()
+                .map(|x| x.iter().map(|x| x.identifier.to_string()).collect());
+            if let Some(x) = self.memory_samples.update(*pid, *start, memory) {
+                hits.extend(x);
+            }
+            self.insert(*pid, hits);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.fmt_contents.clear();
+        self.raw_contents.clear();
+        self.hits.clear();
+        self.scanned.clear();
+        self.starts.clear();
+    }
+
+    fn persistent(&self) -> bool {
+        true
+    }
+
+    fn stale_since(&self, pid: i32) -> Option<Instant> {
+        let exe = self.exe_samples.stale_since(pid);
+        let memory = self.memory_samples.stale_since(pid);
+        match (exe, memory) {
+            (Some(x), Some(y)) => Some(x.min(y)),
+            (x, y) => x.or(y),
+        }
+    }
+
+    crate::column_default_display_header!();
+    crate::column_default_display_unit!();
+    crate::column_default_display_content!();
+    crate::column_default_find_partial!();
+    crate::column_default_find_exact!();
+    crate::column_default_sorted_pid!(usize);
+    crate::column_default_reset_width!();
+    crate::column_default_update_width!();
+    crate::column_default_get_width!();
+    crate::column_default_raw_value!();
+}
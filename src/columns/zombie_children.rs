@@ -0,0 +1,88 @@
+use crate::process::{ProcessInfo, ProcessTask};
+use crate::{column_default, Column, Contents};
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct ZombieChildren {
+    header: String,
+    unit: String,
+    fmt_contents: Contents,
+    raw_contents: HashMap<i32, u64>,
+    width: usize,
+    parent_pids: HashMap<i32, i32>,
+    zombie_pids: Vec<i32>,
+}
+
+impl ZombieChildren {
+    pub fn new(header: Option<String>) -> Self {
+        let header = header.unwrap_or_else(|| String::from("Zombies"));
+        let unit = String::new();
+        Self {
+            fmt_contents: Contents::new(),
+            raw_contents: HashMap::new(),
+            width: 0,
+            header,
+            unit,
+            parent_pids: HashMap::new(),
+            zombie_pids: Vec::new(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Column for ZombieChildren {
+    fn add(&mut self, proc: &ProcessInfo) {
+        // Threads can't be reaped separately, so they are not counted
+        if let ProcessTask::Process { .. } = proc.curr_proc {
+            self.parent_pids.insert(proc.pid, proc.ppid);
+            if proc.curr_proc.stat().state == 'Z' {
+                self.zombie_pids.push(proc.pid);
+            }
+        }
+    }
+
+    fn streamable(&self) -> bool {
+        false
+    }
+
+    fn finish(&mut self) {
+        let mut counts: HashMap<i32, u64> = self.parent_pids.keys().map(|x| (*x, 0)).collect();
+        for pid in &self.zombie_pids {
+            let ppid = self.parent_pids.get(pid);
+            if let Some(count) = ppid.and_then(|x| counts.get_mut(x)) {
+                *count += 1;
+            }
+        }
+        for (pid, count) in counts {
+            self.fmt_contents.insert(pid, count.to_string());
+            self.raw_contents.insert(pid, count);
+        }
+    }
+
+    column_default!(u64);
+}
+
+#[cfg(all(test, any(target_os = "linux", target_os = "android")))]
+mod tests {
+    use super::*;
+    use crate::config::ConfigColumnAlign;
+
+    #[test]
+    fn test_zombie_children() {
+        let mut column = ZombieChildren::new(None);
+        column.parent_pids = HashMap::from([(1, 0), (100, 1), (101, 100), (102, 100), (200, 1)]);
+        column.zombie_pids = vec![101, 102, 200];
+        column.finish();
+        for pid in [1, 100, 101] {
+            column.update_width(pid, None);
+        }
+        let content = |pid| {
+            column
+                .display_content(pid, &ConfigColumnAlign::Left)
+                .map(|x| x.trim().to_string())
+        };
+        assert_eq!(content(1).as_deref(), Some("1"));
+        assert_eq!(content(100).as_deref(), Some("2"));
+        assert_eq!(content(101).as_deref(), Some("0"));
+    }
+}
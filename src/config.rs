@@ -1,7 +1,9 @@
 use crate::column::Column;
 use crate::columns::ConfigColumnKind;
 use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 // ---------------------------------------------------------------------------------------------------------------------
 // Functions for serde default
@@ -23,6 +25,14 @@ fn default_column_align_left() -> ConfigColumnAlign {
     ConfigColumnAlign::Left
 }
 
+fn default_sort_empty_value() -> ConfigSortEmpty {
+    ConfigSortEmpty::Value
+}
+
+fn default_aggregate_auto() -> ConfigAggregate {
+    ConfigAggregate::Auto
+}
+
 fn default_color_mode_auto() -> ConfigColorMode {
     ConfigColorMode::Auto
 }
@@ -80,10 +90,69 @@ fn default_color_by_theme() -> ConfigColorByTheme {
     }
 }
 
+fn default_color_deleted_exe() -> ConfigColorByTheme {
+    ConfigColorByTheme {
+        dark: ConfigColor::BrightRed,
+        light: ConfigColor::Red,
+    }
+}
+
+fn default_group_headers() -> Vec<ConfigColorByTheme> {
+    vec![
+        ConfigColorByTheme {
+            dark: ConfigColor::BrightCyan,
+            light: ConfigColor::Cyan,
+        },
+        ConfigColorByTheme {
+            dark: ConfigColor::BrightMagenta,
+            light: ConfigColor::Magenta,
+        },
+        ConfigColorByTheme {
+            dark: ConfigColor::BrightYellow,
+            light: ConfigColor::Yellow,
+        },
+        ConfigColorByTheme {
+            dark: ConfigColor::BrightGreen,
+            light: ConfigColor::Green,
+        },
+    ]
+}
+
 fn default_theme_auto() -> ConfigTheme {
     ConfigTheme::Auto
 }
 
+fn default_lookup_timeout() -> u64 {
+    1000
+}
+
+fn default_resolve_timeout() -> u64 {
+    2000
+}
+
+fn default_history_retention() -> u64 {
+    86400
+}
+
+fn default_statsd_template() -> String {
+    String::from("procs.{command}.{pid}.{kind}")
+}
+
+fn default_metrics() -> Vec<String> {
+    ["UsageCpu", "UsageMem", "VmRss", "ReadBytes", "WriteBytes"]
+        .iter()
+        .map(|x| String::from(*x))
+        .collect()
+}
+
+fn default_map_count_warn() -> u64 {
+    90
+}
+
+fn default_tie_break() -> String {
+    String::from("Pid")
+}
+
 // ---------------------------------------------------------------------------------------------------------------------
 // ColumnInfo
 // ---------------------------------------------------------------------------------------------------------------------
@@ -98,6 +167,12 @@ pub struct ColumnInfo {
     pub max_width: Option<usize>,
     pub min_width: Option<usize>,
     pub visible: bool,
+    pub refresh: Option<Duration>,
+    pub refreshed: Instant,
+    pub sort_empty: ConfigSortEmpty,
+    pub aggregate: ConfigAggregate,
+    /// Index of the group in order of appearance
+    pub group: Option<usize>,
 }
 
 // ---------------------------------------------------------------------------------------------------------------------
@@ -118,7 +193,23 @@ pub struct Config {
     #[serde(default)]
     pub docker: ConfigDocker,
     #[serde(default)]
+    pub net: ConfigNet,
+    #[serde(default)]
+    pub history: ConfigHistory,
+    #[serde(default)]
+    pub otel: ConfigOtel,
+    #[serde(default)]
+    pub statsd: ConfigStatsd,
+    #[serde(default)]
     pub pager: ConfigPager,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alerts: Vec<ConfigAlert>,
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "deserialize_aliases"
+    )]
+    pub aliases: BTreeMap<String, ConfigAlias>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -311,6 +402,14 @@ pub struct ConfigColumn {
     pub max_width: Option<usize>,
     pub min_width: Option<usize>,
     pub header: Option<String>,
+    #[serde(default = "default_true")]
+    pub unit_suffix: bool,
+    pub refresh: Option<u64>,
+    #[serde(default = "default_sort_empty_value")]
+    pub sort_empty: ConfigSortEmpty,
+    #[serde(default = "default_aggregate_auto")]
+    pub aggregate: ConfigAggregate,
+    pub group: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -321,6 +420,12 @@ pub struct ConfigStyle {
     pub unit: ConfigColorByTheme,
     #[serde(default = "default_color_by_theme")]
     pub tree: ConfigColorByTheme,
+    /// Color of `Command` column of processes running a deleted executable, which requires `ExeDeleted` column
+    #[serde(default = "default_color_deleted_exe")]
+    pub deleted_exe: ConfigColorByTheme,
+    /// Header colors of column groups, which are used in turn
+    #[serde(default = "default_group_headers")]
+    pub group_headers: Vec<ConfigColorByTheme>,
     #[serde(default)]
     pub by_percentage: ConfigStyleByPercentage,
     #[serde(default)]
@@ -335,6 +440,8 @@ impl Default for ConfigStyle {
             header: default_color_by_theme(),
             unit: default_color_by_theme(),
             tree: default_color_by_theme(),
+            deleted_exe: default_color_deleted_exe(),
+            group_headers: default_group_headers(),
             by_percentage: Default::default(),
             by_state: Default::default(),
             by_unit: Default::default(),
@@ -555,10 +662,30 @@ pub struct ConfigDisplay {
     pub tree_symbols: [String; 5],
     #[serde(default = "default_true")]
     pub abbr_sid: bool,
+    #[serde(default = "default_true")]
+    pub abbr_path: bool,
+    #[serde(default = "default_true")]
+    pub abbr_cgroup: bool,
     #[serde(default = "default_theme_auto")]
     pub theme: ConfigTheme,
     #[serde(default = "default_true")]
+    pub detect_theme: bool,
+    #[serde(default = "default_true")]
     pub show_kthreads: bool,
+    #[serde(default = "default_false")]
+    pub numeric_id: bool,
+    #[serde(default = "default_lookup_timeout")]
+    pub lookup_timeout: u64,
+    #[serde(default = "default_map_count_warn")]
+    pub map_count_warn: u64,
+    #[serde(default = "default_false")]
+    pub smaps: bool,
+    #[serde(default)]
+    pub start_time_format: String,
+    #[serde(default)]
+    pub max_rows: usize,
+    #[serde(default = "default_false")]
+    pub show_aggregate: bool,
 }
 
 impl Default for ConfigDisplay {
@@ -587,8 +714,18 @@ impl Default for ConfigDisplay {
                 String::from("└"),
             ],
             abbr_sid: true,
+            abbr_path: true,
+            abbr_cgroup: true,
             theme: ConfigTheme::Auto,
+            detect_theme: true,
             show_kthreads: true,
+            numeric_id: false,
+            lookup_timeout: 1000,
+            map_count_warn: 90,
+            smaps: false,
+            start_time_format: String::new(),
+            max_rows: 0,
+            show_aggregate: false,
         }
     }
 }
@@ -602,17 +739,21 @@ pub enum ConfigColorMode {
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ConfigSort {
-    #[serde(default)]
-    pub column: usize,
+    /// None means the left column, and lets modes choose their own default ( ex. `--oneline` )
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
     #[serde(default = "default_sort_order_ascending")]
     pub order: ConfigSortOrder,
+    #[serde(default = "default_tie_break")]
+    pub tie_break: String,
 }
 
 impl Default for ConfigSort {
     fn default() -> Self {
         ConfigSort {
-            column: 0,
+            column: None,
             order: ConfigSortOrder::Ascending,
+            tie_break: default_tie_break(),
         }
     }
 }
@@ -623,6 +764,92 @@ pub enum ConfigSortOrder {
     Descending,
 }
 
+/// Position of empty cells ( ex. processes without TTY ) when the column is sorted
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ConfigSortEmpty {
+    /// Before all values regardless of the order
+    First,
+    /// After all values regardless of the order
+    Last,
+    /// By the raw value, which is usually zero or an empty string
+    Value,
+}
+
+/// Aggregation of the column in the footer row shown by `show_aggregate`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ConfigAggregate {
+    /// Sum if the column has a unit ( ex. `[%]`, `[bytes]` ), otherwise nothing
+    Auto,
+    Sum,
+    Average,
+    Disable,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConfigNet {
+    pub country_db: Option<String>,
+    pub asn_db: Option<String>,
+    #[serde(default = "default_resolve_timeout")]
+    pub resolve_timeout: u64,
+}
+
+impl Default for ConfigNet {
+    fn default() -> Self {
+        ConfigNet {
+            country_db: None,
+            asn_db: None,
+            resolve_timeout: default_resolve_timeout(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConfigHistory {
+    pub path: Option<String>,
+    #[serde(default = "default_history_retention")]
+    pub retention: u64,
+}
+
+impl Default for ConfigHistory {
+    fn default() -> Self {
+        ConfigHistory {
+            path: None,
+            retention: default_history_retention(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConfigOtel {
+    #[serde(default = "default_metrics")]
+    pub metrics: Vec<String>,
+}
+
+impl Default for ConfigOtel {
+    fn default() -> Self {
+        ConfigOtel {
+            metrics: default_metrics(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConfigStatsd {
+    #[serde(default = "default_metrics")]
+    pub metrics: Vec<String>,
+    #[serde(default = "default_statsd_template")]
+    pub template: String,
+}
+
+impl Default for ConfigStatsd {
+    fn default() -> Self {
+        ConfigStatsd {
+            metrics: default_metrics(),
+            template: default_statsd_template(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ConfigDocker {
     pub path: String,
@@ -637,6 +864,73 @@ impl Default for ConfigDocker {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConfigAlert {
+    pub condition: String,
+    #[serde(default, deserialize_with = "deserialize_notify")]
+    pub notify: ConfigNotify,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ConfigNotify {
+    pub webhook: Option<String>,
+    pub exec: Option<String>,
+    #[serde(default = "default_false")]
+    pub desktop: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConfigAlias {
+    pub kind: String,
+    pub header: Option<String>,
+}
+
+/// An alias accepts the kind name ( ex. `mem = "VmRss"` ) as well as a table with the header.
+fn deserialize_aliases<'de, D>(deserializer: D) -> Result<BTreeMap<String, ConfigAlias>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Alias {
+        Kind(String),
+        Table(ConfigAlias),
+    }
+
+    let aliases = <BTreeMap<String, Alias> as serde::Deserialize>::deserialize(deserializer)?;
+    Ok(aliases
+        .into_iter()
+        .map(|(name, x)| match x {
+            Alias::Kind(kind) => (name, ConfigAlias { kind, header: None }),
+            Alias::Table(x) => (name, x),
+        })
+        .collect())
+}
+
+/// `notify` accepts the name of a target ( ex. `notify = "desktop"` ) as well as a table.
+fn deserialize_notify<'de, D>(deserializer: D) -> Result<ConfigNotify, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Notify {
+        Name(String),
+        Table(ConfigNotify),
+    }
+
+    match <Notify as serde::Deserialize>::deserialize(deserializer)? {
+        Notify::Name(x) if x == "desktop" => Ok(ConfigNotify {
+            desktop: true,
+            ..Default::default()
+        }),
+        Notify::Name(x) => Err(serde::de::Error::custom(format!(
+            "unknown notify target: {x}"
+        ))),
+        Notify::Table(x) => Ok(x),
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ConfigPager {
     #[serde(default = "default_pager_mode_auto")]
@@ -0,0 +1,227 @@
+use crate::config::*;
+use crate::process::{self, boot_ticks, is_local_procfs, PidFd};
+use crate::util::insert_metric_columns;
+use crate::view::View;
+use crate::Opt;
+use anyhow::{bail, Error};
+use chrono::Local;
+use std::collections::HashMap;
+use std::io;
+use std::os::fd::AsRawFd;
+use std::process::Command;
+use std::thread;
+
+/// Interval to rescan processes for new matches in milliseconds
+const RESCAN_INTERVAL: i32 = 1000;
+
+struct Watched {
+    pidfd: PidFd,
+    command: String,
+    unit: String,
+}
+
+/// Watches processes matched by the keyword, and reports the exit status of them.
+///
+/// Exits are noticed by pidfd. The exit status can't be got by `waitid` because procs is not the
+/// parent of watched processes, so it is looked up from coredumpctl and systemd at best effort.
+pub fn run_watch_exits(opt: &mut Opt, config: &Config, keyword: &str) -> Result<(), Error> {
+    let names = [String::from("Command"), String::from("Unit")];
+    insert_metric_columns(opt, config, &names)?;
+    opt.keyword = keyword.split_whitespace().map(String::from).collect();
+
+    let mut watched: HashMap<i32, Watched> = HashMap::new();
+    let mut first = true;
+    loop {
+        let scanned = boot_ticks();
+        let mut view = View::new(opt, config, false)?;
+        // PIDs of other procfs are meaningless for pidfd, and procfs root is set by View
+        if !is_local_procfs() {
+            bail!("--watch-exits can't be used with --procfs, --host-proc or --from-dir");
+        }
+        view.filter(opt, config);
+        view.fit_width(config);
+
+        for pid in &view.visible_pids {
+            if watched.contains_key(pid) {
+                continue;
+            }
+            let pidfd = match PidFd::open_started_by(*pid, scanned) {
+                Ok(Some(x)) => x,
+                Err(e) if e.raw_os_error() == Some(libc::ENOSYS) => {
+                    bail!("--watch-exits requires pidfd_open ( Linux 5.3 or later )")
+                }
+                // The process exited already, or the PID is reused by another process
+                _ => continue,
+            };
+            let content = |name| {
+                view.find_column(config, name)
+                    .and_then(|c| c.column.display_content(*pid, &ConfigColumnAlign::Left))
+                    .map(|x| x.trim().to_string())
+                    .unwrap_or_default()
+            };
+            let w = Watched {
+                pidfd,
+                command: content("Command"),
+                unit: content("Unit"),
+            };
+            if !first {
+                println!("{} {}: matched", timestamp(), describe(*pid, &w));
+            }
+            watched.insert(*pid, w);
+        }
+        if first {
+            eprintln!(
+                "watching {} processes matched by {keyword:?}, press Ctrl-C to stop",
+                watched.len()
+            );
+            first = false;
+        }
+
+        for pid in poll_exits(&watched)? {
+            let w = watched.remove(&pid).unwrap();
+            // Lookups may take a while until systemd or systemd-coredump notices the exit
+            let line = format!("{} {}", timestamp(), describe(pid, &w));
+            thread::spawn(move || {
+                println!("{line}: {}", exit_reason(pid, &w.unit));
+            });
+        }
+    }
+}
+
+/// Waits for exits of the watched processes until the rescan interval elapses.
+fn poll_exits(watched: &HashMap<i32, Watched>) -> Result<Vec<i32>, Error> {
+    let pids: Vec<_> = watched.keys().copied().collect();
+    let mut fds: Vec<_> = pids
+        .iter()
+        .map(|pid| libc::pollfd {
+            fd: watched[pid].pidfd.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        })
+        .collect();
+
+    let ret = unsafe { libc::poll(fds.as_mut_ptr(), fds.len() as libc::nfds_t, RESCAN_INTERVAL) };
+    if ret < 0 {
+        let e = io::Error::last_os_error();
+        if e.kind() == io::ErrorKind::Interrupted {
+            return Ok(Vec::new());
+        }
+        return Err(e.into());
+    }
+
+    Ok(pids
+        .into_iter()
+        .zip(fds)
+        .filter(|(_, fd)| fd.revents != 0)
+        .map(|(pid, _)| pid)
+        .collect())
+}
+
+fn timestamp() -> String {
+    Local::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+fn describe(pid: i32, w: &Watched) -> String {
+    if w.unit.is_empty() {
+        format!("PID {pid} {}", w.command)
+    } else {
+        format!("PID {pid} {} [{}]", w.command, w.unit)
+    }
+}
+
+fn exit_reason(pid: i32, unit: &str) -> String {
+    // systemd-coredump takes a moment to record the crash
+    thread::sleep(std::time::Duration::from_secs(1));
+    if let Some(x) = coredump_signal(pid) {
+        return format!("killed by {} (core dumped)", signal_name(x));
+    }
+    if unit.ends_with(".service") {
+        if let Some(x) = systemd_status(pid, unit) {
+            return x;
+        }
+    }
+    String::from("exited ( reason unavailable )")
+}
+
+fn coredump_signal(pid: i32) -> Option<i32> {
+    let output = Command::new("coredumpctl")
+        .args(["--no-pager", "--json=short", "list"])
+        .arg(pid.to_string())
+        .output()
+        .ok()?;
+    let list: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+    list.as_array()?
+        .last()?
+        .get("sig")?
+        .as_i64()
+        .map(|x| x as i32)
+}
+
+fn systemd_status(pid: i32, unit: &str) -> Option<String> {
+    let output = Command::new("systemctl")
+        .args(["show", unit])
+        .args([
+            "-p",
+            "ExecMainPID",
+            "-p",
+            "ExecMainCode",
+            "-p",
+            "ExecMainStatus",
+        ])
+        .output()
+        .ok()?;
+    parse_systemd_status(pid, &String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parses the output of `systemctl show`, which is valid only if the process is the main process of the unit.
+fn parse_systemd_status(pid: i32, output: &str) -> Option<String> {
+    let mut main_pid = None;
+    let mut code = None;
+    let mut status = None;
+    for line in output.lines() {
+        match line.split_once('=') {
+            Some(("ExecMainPID", x)) => main_pid = x.parse::<i32>().ok(),
+            Some(("ExecMainCode", x)) => code = x.parse::<i32>().ok(),
+            Some(("ExecMainStatus", x)) => status = x.parse::<i32>().ok(),
+            _ => (),
+        }
+    }
+    if main_pid != Some(pid) {
+        return None;
+    }
+    // Codes of siginfo_t
+    match (code?, status?) {
+        (libc::CLD_EXITED, x) => Some(format!("exited with status {x}")),
+        (libc::CLD_KILLED, x) => Some(format!("killed by {}", signal_name(x))),
+        (libc::CLD_DUMPED, x) => Some(format!("killed by {} (core dumped)", signal_name(x))),
+        _ => None,
+    }
+}
+
+fn signal_name(sig: i32) -> String {
+    process::signal_name(sig)
+        .map(String::from)
+        .unwrap_or_else(|| format!("signal {sig}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_systemd_status() {
+        let output = "ExecMainPID=1234\nExecMainCode=2\nExecMainStatus=9\n";
+        assert_eq!(
+            parse_systemd_status(1234, output),
+            Some(String::from("killed by SIGKILL"))
+        );
+        let output = "ExecMainPID=1234\nExecMainCode=1\nExecMainStatus=3\n";
+        assert_eq!(
+            parse_systemd_status(1234, output),
+            Some(String::from("exited with status 3"))
+        );
+        assert_eq!(parse_systemd_status(999, output), None);
+        assert_eq!(signal_name(11), "SIGSEGV");
+        assert_eq!(signal_name(40), "signal 40");
+    }
+}
@@ -0,0 +1,111 @@
+use crate::columns::identity::identity;
+use crate::process::{ProcessInfo, ProcessTask};
+use chrono::Local;
+use std::collections::{HashMap, VecDeque};
+
+/// Number of recent PID changes shown below processes
+const HISTORY: usize = 5;
+
+/// Processes followed by `--follow` in watch mode.
+///
+/// Processes are resolved by the keyword until any process is matched, and then they are tracked by identities
+/// ( see `Identity` column ) instead of PIDs, so restarted instances are shown in place of the previous ones.
+#[derive(Default)]
+pub struct Follow {
+    /// Identities of followed processes and PIDs of them at the last update
+    followed: Vec<(String, Vec<i32>)>,
+    /// Identities by PID and start time, so that each process is inspected only once
+    cache: HashMap<(i32, u64), String>,
+    changes: VecDeque<String>,
+}
+
+impl Follow {
+    pub fn resolved(&self) -> bool {
+        !self.followed.is_empty()
+    }
+
+    /// Starts following identities of the processes matched by the keyword.
+    pub fn resolve(&mut self, proc: &[ProcessInfo], matched_pids: &[i32]) {
+        for p in proc.iter().filter(|x| matched_pids.contains(&x.pid)) {
+            // Threads share the identity of the process
+            if let ProcessTask::Process { .. } = p.curr_proc {
+                let identity = identity(p);
+                let key = (p.pid, p.curr_proc.stat().starttime);
+                self.cache.insert(key, identity.clone());
+                if self.followed.iter().all(|(x, _)| *x != identity) {
+                    self.followed.push((identity, Vec::new()));
+                }
+            }
+        }
+        self.track(proc, false);
+    }
+
+    /// Re-resolves PIDs of followed identities, and records PID changes since the last update.
+    pub fn update(&mut self, proc: &[ProcessInfo]) {
+        self.track(proc, true);
+    }
+
+    fn track(&mut self, proc: &[ProcessInfo], record: bool) {
+        let mut cache = HashMap::new();
+        let mut curr_pids: HashMap<String, Vec<i32>> = HashMap::new();
+        for p in proc {
+            if let ProcessTask::Process { .. } = p.curr_proc {
+                let key = (p.pid, p.curr_proc.stat().starttime);
+                let identity = self.cache.remove(&key).unwrap_or_else(|| identity(p));
+                if self.followed.iter().any(|(x, _)| *x == identity) {
+                    curr_pids.entry(identity.clone()).or_default().push(p.pid);
+                }
+                cache.insert(key, identity);
+            }
+        }
+        self.cache = cache;
+
+        let time = Local::now().format("%H:%M:%S");
+        for (identity, pids) in self.followed.iter_mut() {
+            let curr = curr_pids.remove(identity.as_str()).unwrap_or_default();
+            if record {
+                let exited: Vec<_> = pids.iter().filter(|x| !curr.contains(x)).collect();
+                let started: Vec<_> = curr.iter().filter(|x| !pids.contains(x)).collect();
+                let change = match (exited.is_empty(), started.is_empty()) {
+                    (false, false) => Some(format!("PID {} -> {}", join(&exited), join(&started))),
+                    (false, true) => Some(format!("PID {} exited", join(&exited))),
+                    (true, false) => Some(format!("PID {} started", join(&started))),
+                    (true, true) => None,
+                };
+                if let Some(change) = change {
+                    if self.changes.len() == HISTORY {
+                        self.changes.pop_front();
+                    }
+                    self.changes
+                        .push_back(format!("{time} {identity}: {change}"));
+                }
+            }
+            *pids = curr;
+        }
+    }
+
+    pub fn contains(&self, pid: i32) -> bool {
+        self.followed.iter().any(|(_, x)| x.contains(&pid))
+    }
+
+    /// Rows shown below processes, so that the view doesn't go blank silently while followed processes are down.
+    pub fn notes(&self, keyword: &str) -> Vec<String> {
+        if !self.resolved() {
+            return vec![format!("following {keyword:?}: no process is matched yet")];
+        }
+        let mut ret: Vec<_> = self.changes.iter().cloned().collect();
+        for (identity, pids) in &self.followed {
+            if pids.is_empty() {
+                ret.push(format!("{identity}: not running, waiting for restart"));
+            }
+        }
+        ret
+    }
+}
+
+fn join(pids: &[&i32]) -> String {
+    pids.iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
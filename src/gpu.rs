@@ -0,0 +1,73 @@
+use nvml_wrapper::enums::device::UsedGpuMemory;
+use nvml_wrapper::Nvml;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// NVML is loaded from the driver at runtime, so it is `None` if no NVIDIA driver is present
+static NVML: Lazy<Option<Nvml>> = Lazy::new(|| Nvml::init().ok());
+
+/// Period of utilization samples which are taken into account
+const SAMPLE_PERIOD: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GpuUsage {
+    /// SM ( 3D / compute ) utilization in percent summed over GPUs
+    pub util: Option<u32>,
+    /// Used GPU memory in bytes summed over GPUs
+    pub memory: Option<u64>,
+}
+
+/// Collects GPU usage of each process by NVML per-process accounting.
+///
+/// PIDs are of the host, so processes in containers are matched only if procs runs on the host.
+pub fn gpu_usage() -> HashMap<i32, GpuUsage> {
+    let mut ret: HashMap<i32, GpuUsage> = HashMap::new();
+    let nvml = if let Some(x) = NVML.as_ref() {
+        x
+    } else {
+        return ret;
+    };
+
+    // Timestamps of samples are in microseconds of the wall clock
+    let since = SystemTime::now()
+        .checked_sub(SAMPLE_PERIOD)
+        .and_then(|x| x.duration_since(UNIX_EPOCH).ok())
+        .map(|x| x.as_micros() as u64);
+
+    for index in 0..nvml.device_count().unwrap_or(0) {
+        let device = if let Ok(x) = nvml.device_by_index(index) {
+            x
+        } else {
+            continue;
+        };
+
+        let compute = device.running_compute_processes().unwrap_or_default();
+        let graphics = device.running_graphics_processes().unwrap_or_default();
+        let mut memory = HashMap::new();
+        // A process using both of compute and graphics is listed twice with the same memory
+        for p in compute.into_iter().chain(graphics) {
+            if let UsedGpuMemory::Used(x) = p.used_gpu_memory {
+                memory.insert(p.pid as i32, x);
+            }
+        }
+        for (pid, x) in memory {
+            let usage = ret.entry(pid).or_default();
+            usage.memory = Some(usage.memory.unwrap_or(0) + x);
+        }
+
+        // Utilization is not supported by old GPUs, and only the latest sample of each process is used
+        let mut latest: HashMap<i32, (u64, u32)> = HashMap::new();
+        for s in device.process_utilization_stats(since).unwrap_or_default() {
+            let entry = latest.entry(s.pid as i32).or_default();
+            if s.timestamp >= entry.0 {
+                *entry = (s.timestamp, s.sm_util);
+            }
+        }
+        for (pid, (_, x)) in latest {
+            let usage = ret.entry(pid).or_default();
+            usage.util = Some(usage.util.unwrap_or(0) + x);
+        }
+    }
+    ret
+}
@@ -0,0 +1,143 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Sightings of processes which are recorded across runs of procs.
+///
+/// Each line of the history file is `<last seen>\t<pid>\t<start time>\t<identity>`,
+/// and an instance of a process is distinguished by PID and start time.
+pub struct History {
+    path: PathBuf,
+    now: u64,
+    /// Last seen times by PID and start time of each identity
+    entries: HashMap<String, HashMap<(i32, u64), u64>>,
+}
+
+impl History {
+    /// Loads the history file, and drops entries which are not seen within `retention` seconds.
+    pub fn load(path: &Path, retention: u64) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|x| x.as_secs())
+            .unwrap_or(0);
+
+        let mut history = Self {
+            path: path.to_path_buf(),
+            now,
+            entries: HashMap::new(),
+        };
+
+        let content = fs::read_to_string(path).unwrap_or_default();
+        for line in content.lines() {
+            let mut fields = line.splitn(4, '\t');
+            let last_seen = fields.next().and_then(|x| x.parse::<u64>().ok());
+            let pid = fields.next().and_then(|x| x.parse::<i32>().ok());
+            let start = fields.next().and_then(|x| x.parse::<u64>().ok());
+            let identity = fields.next();
+            if let (Some(last_seen), Some(pid), Some(start), Some(identity)) =
+                (last_seen, pid, start, identity)
+            {
+                if last_seen.saturating_add(retention) >= now {
+                    history.insert(identity, pid, start, last_seen);
+                }
+            }
+        }
+        history
+    }
+
+    pub fn record(&mut self, identity: &str, pid: i32, start: u64) {
+        self.insert(identity, pid, start, self.now);
+    }
+
+    /// Returns how many times the process identity has restarted within the retention window.
+    ///
+    /// An instance is counted as a restart only if it started after all earlier instances were last seen,
+    /// so concurrent instances like workers of a pool are not counted.
+    pub fn restarts(&self, identity: &str) -> u64 {
+        let Some(instances) = self.entries.get(identity) else {
+            return 0;
+        };
+        let mut lifetimes: Vec<_> = instances
+            .iter()
+            .map(|((_, start), last_seen)| (*start, *last_seen))
+            .collect();
+        lifetimes.sort_unstable();
+
+        let mut restarts = 0;
+        let mut end = None;
+        for (start, last_seen) in lifetimes {
+            match end {
+                Some(x) if start > x => {
+                    restarts += 1;
+                    end = Some(last_seen);
+                }
+                Some(x) => end = Some(cmp::max(x, last_seen)),
+                None => end = Some(last_seen),
+            }
+        }
+        restarts
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // The file is replaced by rename to keep it consistent against concurrent procs
+        let tmp = self
+            .path
+            .with_extension(format!("tmp.{}", std::process::id()));
+        let mut file = io::BufWriter::new(fs::File::create(&tmp)?);
+        for (identity, instances) in &self.entries {
+            for ((pid, start), last_seen) in instances {
+                writeln!(file, "{last_seen}\t{pid}\t{start}\t{identity}")?;
+            }
+        }
+        file.flush()?;
+        drop(file);
+        fs::rename(&tmp, &self.path)
+    }
+
+    fn insert(&mut self, identity: &str, pid: i32, start: u64, last_seen: u64) {
+        let x = self
+            .entries
+            .entry(identity.to_string())
+            .or_default()
+            .entry((pid, start))
+            .or_default();
+        *x = (*x).max(last_seen);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> History {
+        History {
+            path: PathBuf::new(),
+            now: 1000,
+            entries: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_restarts() {
+        let mut history = history();
+        // Two workers running concurrently since 100 and 200
+        history.insert("worker", 10, 100, 500);
+        history.insert("worker", 11, 200, 500);
+        assert_eq!(history.restarts("worker"), 0);
+
+        // Both are replaced after they are last seen
+        history.insert("worker", 12, 600, 900);
+        history.insert("worker", 13, 700, 900);
+        assert_eq!(history.restarts("worker"), 1);
+
+        history.record("worker", 14, 950);
+        assert_eq!(history.restarts("worker"), 2);
+        assert_eq!(history.restarts("nginx"), 0);
+    }
+}
@@ -1,17 +1,47 @@
+mod alert;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod baseline;
+mod check;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod checkpoint;
 mod column;
 mod columns;
 mod config;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod exits;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod follow;
+#[cfg(all(feature = "gpu", any(target_os = "linux", target_os = "android")))]
+mod gpu;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod history;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod net;
+mod oneline;
+#[cfg(feature = "otel")]
+mod otel;
+mod power;
 mod process;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod server;
+mod statsd;
 mod style;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod syscall;
 mod term_info;
+mod thermal;
 mod util;
 mod view;
 mod watcher;
 
-use crate::column::Column;
+use crate::column::{Column, Contents, ValueFormat};
 use crate::columns::*;
 use crate::config::*;
-use crate::util::{adjust, get_theme, lap, ArgColorMode, ArgPagerMode, ArgThemeMode};
+use crate::process::in_container;
+use crate::util::{
+    adjust, apply_aliases, get_theme, lap, parse_pids, ArgColorMode, ArgPagerMode, ArgThemeMode,
+    Profile,
+};
 use crate::view::View;
 use crate::watcher::Watcher;
 use anyhow::{anyhow, Context, Error};
@@ -24,6 +54,7 @@ use std::collections::HashMap;
 use std::fs;
 use std::io::{stdout, Read};
 use std::path::PathBuf;
+use std::thread;
 use std::time::Instant;
 use unicode_width::UnicodeWidthStr;
 
@@ -37,7 +68,7 @@ pub enum BuiltinConfig {
     Large,
 }
 
-#[derive(Debug, Parser)]
+#[derive(Clone, Debug, Parser)]
 #[clap(long_version(option_env!("LONG_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"))))]
 #[clap(
     styles(Styles::styled()
@@ -87,10 +118,21 @@ pub struct Opt {
     )]
     pub nor: bool,
 
+    /// Show processes of the PIDs separated by commas, or read from stdin one per line if it's "-"
+    #[clap(long = "pids", value_name = "pids")]
+    pub pids: Option<String>,
+
+    #[clap(skip)]
+    pub pid_list: Option<Vec<i32>>,
+
     /// Show list of kind
     #[clap(short = 'l', long = "list")]
     pub list: bool,
 
+    /// Show platforms supporting each kind
+    #[clap(long = "list-columns")]
+    pub list_columns: bool,
+
     /// Show thread
     #[clap(long = "thread")]
     pub thread: bool,
@@ -107,9 +149,30 @@ pub struct Opt {
     #[clap(short = 'W', long = "watch-interval", value_name = "second")]
     pub watch_interval: Option<f64>,
 
+    /// Watch mode which lengthens the interval while collecting processes takes long, and shortens it back
+    #[clap(long = "watch-adaptive")]
+    pub watch_adaptive: bool,
+
     #[clap(skip)]
     pub watch_mode: bool,
 
+    /// Follow processes matched by the keyword across restarts in watch mode, and show changes of PIDs ( Linux only )
+    #[clap(long = "follow", value_name = "keyword", conflicts_with("KEYWORD"))]
+    pub follow: Option<String>,
+
+    /// Push metrics of processes to the OTLP/HTTP endpoint on each update of watch mode ( ex. http://localhost:4318 )
+    #[cfg(feature = "otel")]
+    #[clap(long = "otel-endpoint", value_name = "url")]
+    pub otel_endpoint: Option<String>,
+
+    /// Send metrics of processes to the StatsD server on each update of watch mode ( ex. localhost:8125 )
+    #[clap(long = "statsd", value_name = "host:port")]
+    pub statsd: Option<String>,
+
+    /// Command executed at exit of watch mode
+    #[clap(long = "on-exit", value_name = "cmd")]
+    pub on_exit: Option<String>,
+
     /// Insert column to slot
     #[clap(value_name = "kind", short = 'i', long = "insert", number_of_values(1))]
     pub insert: Vec<String>,
@@ -174,9 +237,148 @@ pub struct Opt {
     #[clap(long = "no-header")]
     pub no_header: bool,
 
+    /// Show a footer row with the sum or average of numeric columns over the shown processes
+    #[clap(long = "aggregate")]
+    pub aggregate: bool,
+
+    /// Path to procfs of host bind-mounted into a container ( Linux only )
+    #[clap(long = "host-proc", value_name = "path")]
+    pub host_proc: Option<PathBuf>,
+
+    /// Path to procfs ( Linux only )
+    #[clap(long = "procfs", value_name = "path", env = "PROCS_PROCFS")]
+    pub procfs: Option<PathBuf>,
+
+    /// Analyze a captured copy of /proc ( ex. sosreport ) instead of live system ( Linux only )
+    #[clap(
+        long = "from-dir",
+        value_name = "path",
+        conflicts_with_all(&["watch", "watch_interval", "watch_adaptive", "host_proc"])
+    )]
+    pub from_dir: Option<PathBuf>,
+
+    /// Compare processes with a baseline recorded by --save-baseline, and show differences ( Linux only )
+    #[clap(long = "baseline", value_name = "path")]
+    pub baseline: Option<PathBuf>,
+
+    /// Record the current processes as a baseline for --baseline ( Linux only )
+    #[clap(
+        long = "save-baseline",
+        value_name = "path",
+        conflicts_with_all(&["watch", "watch_interval", "watch_adaptive", "baseline"])
+    )]
+    pub save_baseline: Option<PathBuf>,
+
+    /// Checkpoint the process tree of the PID by CRIU ( Linux only )
+    #[clap(
+        long = "checkpoint",
+        value_name = "pid",
+        requires = "images_dir",
+        conflicts_with_all(&["watch", "watch_interval", "watch_adaptive", "from_dir"])
+    )]
+    pub checkpoint: Option<i32>,
+
+    /// Directory to store CRIU images for --checkpoint
+    #[clap(long = "images-dir", value_name = "path", requires = "checkpoint")]
+    pub images_dir: Option<PathBuf>,
+
+    /// List sockets of the matched processes ( Linux only )
+    #[clap(
+        long = "net",
+        conflicts_with_all(&["watch", "watch_interval", "watch_adaptive", "tree"])
+    )]
+    pub net: bool,
+
+    /// Resolve host and service names of remote addresses in --net
+    #[clap(long = "resolve", requires = "net")]
+    pub resolve: bool,
+
+    /// Show the syscall which each thread of the PID is blocked in ( Linux only )
+    #[clap(
+        long = "syscall",
+        value_name = "pid",
+        conflicts_with_all(&["watch", "watch_interval", "watch_adaptive", "checkpoint"])
+    )]
+    pub syscall: Option<i32>,
+
+    /// Serve JSON-RPC on the UNIX domain socket ( Linux only )
+    #[clap(
+        long = "serve",
+        value_name = "socket",
+        conflicts_with_all(&["watch", "watch_interval", "watch_adaptive", "net", "syscall", "checkpoint"])
+    )]
+    pub serve: Option<PathBuf>,
+
+    /// Print a summary line by the template for status bars ( ex. "{count} procs, top: {top1.command}" )
+    #[clap(
+        long = "oneline",
+        value_name = "template",
+        conflicts_with_all(&["watch", "watch_interval", "watch_adaptive", "tree", "net", "serve"])
+    )]
+    pub oneline: Option<String>,
+
+    /// Healthcheck like Nagios plugins, CRITICAL if the condition fails ( ex. "exists:nginx", "cpu(nginx) < 80" )
+    #[clap(
+        value_name = "condition",
+        long = "check",
+        number_of_values(1),
+        conflicts_with_all(&["watch", "watch_interval", "watch_adaptive", "tree", "net", "serve", "oneline"])
+    )]
+    pub check: Vec<String>,
+
+    /// Healthcheck like Nagios plugins, WARNING if the condition fails
+    #[clap(
+        value_name = "condition",
+        long = "check-warn",
+        number_of_values(1),
+        conflicts_with_all(&["watch", "watch_interval", "watch_adaptive", "tree", "net", "serve", "oneline"])
+    )]
+    pub check_warn: Vec<String>,
+
+    /// Report exits of processes matched by the keyword with the exit code or signal ( Linux only )
+    #[clap(
+        long = "watch-exits",
+        value_name = "keyword",
+        conflicts_with_all(&["watch", "watch_interval", "watch_adaptive", "tree", "net", "serve", "oneline", "check"])
+    )]
+    pub watch_exits: Option<String>,
+
+    /// Read smaps_rollup for Pss and Uss columns, which is slower ( Linux only )
+    #[clap(long = "smaps")]
+    pub smaps: bool,
+
+    /// Power preset: sort by estimated energy impact for battery triage
+    #[clap(long = "power")]
+    pub power: bool,
+
+    /// Audit preset: show processes with suspicious executables or listening sockets ( Linux only )
+    #[clap(long = "audit")]
+    pub audit: bool,
+
+    /// Scan executables of processes by the YARA rules file, and insert YaraHits column ( Linux only )
+    #[cfg(feature = "yara")]
+    #[clap(long = "yara", value_name = "rules")]
+    pub yara: Option<PathBuf>,
+
+    /// Scan memory of processes by --yara rules as well, which needs ptrace privilege
+    #[cfg(feature = "yara")]
+    #[clap(long = "yara-memory", requires = "yara")]
+    pub yara_memory: bool,
+
+    /// Deterministic output for tests and scripts
+    #[clap(long = "deterministic")]
+    pub deterministic: bool,
+
     /// Show debug message
     #[clap(long = "debug", hide = true)]
     pub debug: bool,
+
+    /// Show durations of startup phases to stderr
+    #[clap(long = "profile")]
+    pub profile: bool,
+
+    #[clap(skip)]
+    pub timings: Profile,
 }
 
 // ---------------------------------------------------------------------------------------------------------------------
@@ -213,7 +415,7 @@ fn get_config(opt: &Opt) -> Result<Config, Error> {
         let mut s = String::new();
         f.read_to_string(&mut s)
             .context(format!("failed to read file ({path:?})"))?;
-        let c = toml::from_str(&s);
+        let c = parse_config(&s);
         check_old_config(&s, c).context(format!("failed to parse toml ({path:?})"))?
     } else {
         toml::from_str(CONFIG_DEFAULT).unwrap()
@@ -226,6 +428,24 @@ fn get_config(opt: &Opt) -> Result<Config, Error> {
     }
 }
 
+/// Retries parsing without columns of other platforms, so that a configuration can be shared between platforms.
+fn parse_config(s: &str) -> Result<Config, toml::de::Error> {
+    toml::from_str(s).or_else(|err| {
+        let mut value: toml::Value = toml::from_str(s).map_err(|_| err.clone())?;
+        let removed = remove_unsupported_columns(&mut value);
+        if removed.is_empty() {
+            return Err(err);
+        }
+        for kind in removed {
+            eprintln!(
+                "Warning: column kind \"{kind}\" is not supported on {}, so it is ignored ( see --list-columns )",
+                Platform::CURRENT
+            );
+        }
+        value.try_into()
+    })
+}
+
 fn check_old_config(s: &str, config: Result<Config, toml::de::Error>) -> Result<Config, Error> {
     match config {
         Ok(x) => Ok(x),
@@ -266,13 +486,37 @@ fn main() {
 
 fn run() -> Result<(), Error> {
     let mut opt: Opt = Parser::parse();
-    opt.watch_mode = opt.watch || opt.watch_interval.is_some();
+    opt.timings.start();
+    opt.watch_mode = opt.watch || opt.watch_interval.is_some() || opt.watch_adaptive;
+
+    #[cfg(feature = "otel")]
+    if opt.otel_endpoint.is_some() && !opt.watch_mode {
+        return Err(anyhow!(
+            "--otel-endpoint is used with --watch or --watch-interval"
+        ));
+    }
+
+    if opt.statsd.is_some() && !opt.watch_mode {
+        return Err(anyhow!("--statsd is used with --watch or --watch-interval"));
+    }
+
+    if opt.follow.is_some() && !opt.watch_mode {
+        return Err(anyhow!("--follow is used with --watch or --watch-interval"));
+    }
+
+    // Key inputs of watch mode are read from stdin
+    if opt.pids.as_deref() == Some("-") && opt.watch_mode {
+        return Err(anyhow!("--pids - can't be used with watch mode"));
+    }
 
     if opt.gen_config {
         run_gen_config()
     } else if opt.list {
         run_list();
         Ok(())
+    } else if opt.list_columns {
+        run_list_columns();
+        Ok(())
     } else if let Some(shell) = opt.gen_completion {
         //Opt::clap().gen_completions("procs", shell, "./");
         clap_complete::generate_to(shell, &mut Opt::command(), "procs", "./")?;
@@ -291,8 +535,42 @@ fn run() -> Result<(), Error> {
         clap_complete::generate(shell, &mut Opt::command(), "procs", &mut stdout());
         Ok(())
     } else {
-        let config = get_config(&opt)?;
-        if opt.watch_mode {
+        let mut time = Instant::now();
+
+        // The config is loaded while PIDs are read from stdin, which may wait for the previous command of the pipe
+        let (config, pid_list) = thread::scope(|s| {
+            let config = s.spawn(|| get_config(&opt));
+            let pid_list = opt.pids.as_deref().map(parse_pids).transpose();
+            let config = config
+                .join()
+                .unwrap_or_else(|x| std::panic::resume_unwind(x));
+            (config, pid_list)
+        });
+        let config = config?;
+        opt.pid_list = pid_list?;
+        apply_aliases(&mut opt, &config);
+        opt.timings.lap("load config");
+
+        if opt.debug {
+            lap(&mut time, "Info: get_config");
+        }
+
+        if let Some(pid) = opt.syscall {
+            run_syscall(pid)
+        } else if opt.net {
+            run_net(&mut opt, &config)
+        } else if let Some(path) = opt.serve.clone() {
+            run_serve(&opt, &config, &path)
+        } else if let Some(template) = opt.oneline.clone() {
+            oneline::run_oneline(&mut opt, &config, &template)
+        } else if !opt.check.is_empty() || !opt.check_warn.is_empty() {
+            let (critical, warning) = (opt.check.clone(), opt.check_warn.clone());
+            check::run_check(&mut opt, &config, &critical, &warning)
+        } else if let Some(keyword) = opt.watch_exits.clone() {
+            run_watch_exits(&mut opt, &config, &keyword)
+        } else if let Some(pid) = opt.checkpoint {
+            run_checkpoint(&mut opt, &config, pid)
+        } else if opt.watch_mode {
             let interval = match opt.watch_interval {
                 Some(n) => (n * 1000.0).round() as u64,
                 None => 1000,
@@ -331,6 +609,93 @@ fn run_list() {
     }
 }
 
+fn run_list_columns() {
+    let width = KIND_PLATFORMS
+        .iter()
+        .map(|(x, _)| UnicodeWidthStr::width(*x))
+        .max()
+        .unwrap_or(0);
+    let headers: Vec<_> = Platform::ALL
+        .iter()
+        .map(|x| {
+            if *x == Platform::CURRENT {
+                format!("{x}*")
+            } else {
+                x.to_string()
+            }
+        })
+        .collect();
+
+    println!("Column kind availability ( * is the current platform ):");
+    let mut line = format!("  {}", adjust("Kind", width, &ConfigColumnAlign::Left));
+    for h in &headers {
+        line.push_str(&format!(" {h}"));
+    }
+    println!("{line}");
+    for (kind, platforms) in KIND_PLATFORMS {
+        let mut line = format!("  {}", adjust(kind, width, &ConfigColumnAlign::Left));
+        for (p, h) in Platform::ALL.iter().zip(&headers) {
+            let mark = if platforms.contains(p) { "o" } else { "" };
+            line.push_str(&format!(
+                " {}",
+                adjust(mark, h.len(), &ConfigColumnAlign::Center)
+            ));
+        }
+        println!("{}", line.trim_end());
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn run_checkpoint(opt: &mut Opt, config: &Config, pid: i32) -> Result<(), Error> {
+    let images_dir = opt.images_dir.clone().unwrap_or_default();
+    checkpoint::run_checkpoint(opt, config, pid, &images_dir)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn run_checkpoint(_opt: &mut Opt, _config: &Config, _pid: i32) -> Result<(), Error> {
+    Err(anyhow!("--checkpoint is supported on Linux only"))
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn run_net(opt: &mut Opt, config: &Config) -> Result<(), Error> {
+    net::run_net(opt, config)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn run_net(_opt: &mut Opt, _config: &Config) -> Result<(), Error> {
+    Err(anyhow!("--net is supported on Linux only"))
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn run_serve(opt: &Opt, config: &Config, path: &std::path::Path) -> Result<(), Error> {
+    server::run_serve(opt, config, path)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn run_serve(_opt: &Opt, _config: &Config, _path: &std::path::Path) -> Result<(), Error> {
+    Err(anyhow!("--serve is supported on Linux only"))
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn run_watch_exits(opt: &mut Opt, config: &Config, keyword: &str) -> Result<(), Error> {
+    exits::run_watch_exits(opt, config, keyword)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn run_watch_exits(_opt: &mut Opt, _config: &Config, _keyword: &str) -> Result<(), Error> {
+    Err(anyhow!("--watch-exits is supported on Linux only"))
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn run_syscall(pid: i32) -> Result<(), Error> {
+    syscall::run_syscall(pid)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn run_syscall(_pid: i32) -> Result<(), Error> {
+    Err(anyhow!("--syscall is supported on Linux only"))
+}
+
 fn run_watch(opt: &mut Opt, config: &Config, interval: u64) -> Result<(), Error> {
     Watcher::start(opt, config, interval)
 }
@@ -338,33 +703,93 @@ fn run_watch(opt: &mut Opt, config: &Config, interval: u64) -> Result<(), Error>
 fn run_default(opt: &mut Opt, config: &Config) -> Result<(), Error> {
     let mut time = Instant::now();
 
-    let theme = get_theme(opt, config);
+    // Theme detection may wait for the terminal response,
+    // so it runs concurrently with process collection.
+    let theme_opt = opt.clone();
+
+    let ret = thread::scope(|s| {
+        let theme = s.spawn(|| {
+            let mut time = Instant::now();
+            let theme = get_theme(&theme_opt, config);
+            let period = time.elapsed();
+            if theme_opt.debug {
+                lap(&mut time, "Info: get_theme");
+            }
+            (theme, period)
+        });
+        let join_theme = |opt: &mut Opt, theme: thread::ScopedJoinHandle<(ConfigTheme, _)>| {
+            let theme = theme.join().map_or(ConfigTheme::Dark, |(theme, period)| {
+                opt.timings.concurrent("detect theme", period);
+                theme
+            });
+            opt.timings.lap("wait theme");
+            theme
+        };
 
-    let mut view = View::new(opt, config, false)?;
+        if opt.debug && opt.host_proc.is_none() && in_container() {
+            eprintln!("Info: running inside a container, --host-proc can show processes of host");
+        }
 
-    if opt.debug {
-        lap(&mut time, "Info: View::new");
-    }
+        let (mut view, proc) = View::prepare(opt, config, false)?;
+        opt.timings.lap("collect");
 
-    view.filter(opt, config);
+        if view.streamable(opt, config) {
+            if opt.debug {
+                lap(&mut time, "Info: View::prepare");
+            }
 
-    if opt.debug {
-        lap(&mut time, "Info: view.filter");
-    }
+            let theme = join_theme(opt, theme);
+            view.display_stream(opt, config, &theme, proc)?;
+            opt.timings.lap("display");
 
-    view.adjust(config, &HashMap::new());
+            if opt.debug {
+                lap(&mut time, "Info: view.display_stream");
+            }
+            return Ok(());
+        }
 
-    if opt.debug {
-        lap(&mut time, "Info: view.adjust");
-    }
+        view.add(&proc);
+        opt.timings.lap("columns");
+
+        if opt.debug {
+            lap(&mut time, "Info: View::new");
+        }
 
-    view.display(opt, config, &theme)?;
+        view.filter(opt, config);
+        view.limit_rows(opt, config);
+        opt.timings.lap("filter");
 
-    if opt.debug {
-        lap(&mut time, "Info: view.display");
-    }
+        if opt.debug {
+            lap(&mut time, "Info: view.filter");
+        }
 
-    Ok(())
+        view.adjust(config, &HashMap::new());
+        opt.timings.lap("adjust");
+
+        if opt.debug {
+            lap(&mut time, "Info: view.adjust");
+        }
+
+        let theme = join_theme(opt, theme);
+
+        if opt.debug {
+            lap(&mut time, "Info: wait get_theme");
+        }
+
+        view.display(opt, config, &theme)?;
+        opt.timings.lap("display");
+
+        if opt.debug {
+            lap(&mut time, "Info: view.display");
+        }
+
+        Ok(())
+    });
+
+    if opt.profile {
+        opt.timings.report();
+    }
+    ret
 }
 
 #[cfg(test)]
@@ -377,7 +802,7 @@ mod tests {
         config.pager.mode = ConfigPagerMode::Disable;
         config.display.theme = ConfigTheme::Dark;
 
-        let args = vec!["procs"];
+        let args = ["procs"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
@@ -389,44 +814,136 @@ mod tests {
         config.pager.mode = ConfigPagerMode::Disable;
         config.display.theme = ConfigTheme::Dark;
 
-        let args = vec!["procs", "root"];
+        let args = ["procs", "root"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
 
-        let args = vec!["procs", "1"];
+        let args = ["procs", "1"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
 
-        let args = vec!["procs", "--or", "root", "1"];
+        let args = ["procs", "--or", "root", "1"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
 
-        let args = vec!["procs", "--and", "root", "1"];
+        let args = ["procs", "--and", "root", "1"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
 
-        let args = vec!["procs", "--nor", "root", "1"];
+        let args = ["procs", "--nor", "root", "1"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
 
-        let args = vec!["procs", "--nand", "root", "1"];
+        let args = ["procs", "--nand", "root", "1"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
 
         config.search.nonnumeric_search = ConfigSearchKind::Exact;
         config.search.numeric_search = ConfigSearchKind::Partial;
-        let args = vec!["procs", "root", "1"];
+        let args = ["procs", "root", "1"];
+        let mut opt = Opt::parse_from(args.iter());
+        let ret = run_default(&mut opt, &config);
+        assert!(ret.is_ok());
+    }
+
+    #[test]
+    fn test_run_deterministic() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+
+        let args = ["procs", "--deterministic", "--sortd", "cpu"];
         let mut opt = Opt::parse_from(args.iter());
+        assert!(matches!(get_theme(&opt, &config), ConfigTheme::Dark));
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
     }
 
+    #[test]
+    fn test_run_without_detect_theme() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.detect_theme = false;
+
+        let args = ["procs"];
+        let mut opt = Opt::parse_from(args.iter());
+        assert!(matches!(get_theme(&opt, &config), ConfigTheme::Dark));
+        let ret = run_default(&mut opt, &config);
+        assert!(ret.is_ok());
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn test_run_from_dir() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.theme = ConfigTheme::Dark;
+
+        let args = ["procs", "--from-dir", "/proc"];
+        let mut opt = Opt::parse_from(args.iter());
+        let ret = run_default(&mut opt, &config);
+        assert!(ret.is_ok());
+    }
+
+    #[test]
+    fn test_run_with_aliases() {
+        let aliases = "[aliases]\nmem = \"VmRss\"\nrss = { kind = \"VmSize\", header = \"VSZ\" }\n";
+        let mut config: Config = toml::from_str(&format!("{CONFIG_DEFAULT}\n{aliases}")).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.theme = ConfigTheme::Dark;
+
+        let args = [
+            "procs", "--insert", "MEM", "--insert", "rss", "--sortd", "mem",
+        ];
+        let mut opt = Opt::parse_from(args.iter());
+        apply_aliases(&mut opt, &config);
+        assert_eq!(opt.insert, ["VmRss", "VmSize"]);
+        assert_eq!(opt.sortd.as_deref(), Some("VmRss"));
+
+        let mut view = View::new(&mut opt, &config, false).unwrap();
+        view.fit_width(&config);
+        assert!(view.find_column(&config, "mem").is_some());
+        let header = view
+            .find_column(&config, "rss")
+            .unwrap()
+            .column
+            .display_header(&ConfigColumnAlign::Left, None, &config);
+        assert_eq!(header.trim(), "VSZ");
+
+        let ret = run_default(&mut opt, &config);
+        assert!(ret.is_ok());
+    }
+
+    #[test]
+    fn test_run_start_time_format() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.theme = ConfigTheme::Dark;
+        config.display.start_time_format = String::from("%Y-%m-%dT%H:%M:%S");
+
+        let args = ["procs", "--insert", "StartTime"];
+        let mut opt = Opt::parse_from(args.iter());
+        let mut view = View::new(&mut opt, &config, false).unwrap();
+        view.filter(&opt, &config);
+        view.fit_width(&config);
+        let pid = std::process::id() as i32;
+        let content = view
+            .find_column(&config, "StartTime")
+            .unwrap()
+            .column
+            .display_content(pid, &ConfigColumnAlign::Left)
+            .unwrap();
+        assert!(chrono::NaiveDateTime::parse_from_str(content.trim(), "%Y-%m-%dT%H:%M:%S").is_ok());
+
+        config.display.start_time_format = String::from("%Y/%Q");
+        assert!(View::new(&mut opt, &config, false).is_err());
+    }
+
     #[test]
     fn test_run_gen_config() {
         let ret = run_gen_config();
@@ -439,7 +956,7 @@ mod tests {
         config.display.cut_to_terminal = false;
         config.display.theme = ConfigTheme::Dark;
 
-        let args = vec!["procs"];
+        let args = ["procs"];
         let mut opt = Opt::parse_from(args.iter());
         config.pager.mode = ConfigPagerMode::Disable;
         let ret = run_default(&mut opt, &config);
@@ -452,10 +969,252 @@ mod tests {
         config.pager.mode = ConfigPagerMode::Disable;
         config.display.theme = ConfigTheme::Dark;
 
-        let args = vec!["procs", "--insert", "ppid"];
+        let args = ["procs", "--insert", "ppid"];
+        let mut opt = Opt::parse_from(args.iter());
+        let ret = run_default(&mut opt, &config);
+        assert!(ret.is_ok());
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+    #[test]
+    fn test_run_power() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.theme = ConfigTheme::Dark;
+
+        let args = ["procs", "--power"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
+        assert_eq!(opt.sortd.as_deref(), Some("Energy"));
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn test_run_audit() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.theme = ConfigTheme::Dark;
+
+        let args = ["procs", "--audit"];
+        let mut opt = Opt::parse_from(args.iter());
+        let view = View::new(&mut opt, &config, false).unwrap();
+        assert_eq!(opt.sortd.as_deref(), Some("Audit"));
+        let audit = view
+            .columns
+            .iter()
+            .find(|c| c.kind == ConfigColumnKind::Audit)
+            .unwrap();
+        for pid in &view.visible_pids {
+            assert!(!audit.column.find_exact(*pid, "", false));
+        }
+    }
+
+    #[cfg(all(feature = "yara", any(target_os = "linux", target_os = "android")))]
+    #[test]
+    fn test_run_yara() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.theme = ConfigTheme::Dark;
+
+        let rules = std::env::temp_dir().join(format!("procs_test_{}.yar", std::process::id()));
+        std::fs::write(&rules, "rule elf { condition: uint32(0) == 0x464c457f }").unwrap();
+
+        let args = ["procs", "--yara", rules.to_str().unwrap()];
+        let mut opt = Opt::parse_from(args.iter());
+        let view = View::new(&mut opt, &config, false).unwrap();
+        let _ = std::fs::remove_file(&rules);
+        let yara = view
+            .columns
+            .iter()
+            .find(|c| c.kind == ConfigColumnKind::YaraHits)
+            .unwrap();
+        // The test process is hidden from visible_pids, but its executable is scanned
+        let pid = std::process::id() as i32;
+        assert!(yara.column.find_exact(pid, "elf", false));
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+    #[test]
+    fn test_run_env_var() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.theme = ConfigTheme::Dark;
+
+        let args = ["procs", "--insert", "Env:PATH", "--sortd", "Env:PATH"];
+        let mut opt = Opt::parse_from(args.iter());
+        let ret = run_default(&mut opt, &config);
+        assert!(ret.is_ok());
+
+        let config: Config = toml::from_str(
+            r#"
+            [[columns]]
+            kind = { EnvVar = "PATH" }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.columns[0].kind,
+            ConfigColumnKind::EnvVar(String::from("PATH"))
+        );
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn test_run_oom_score() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.theme = ConfigTheme::Dark;
+
+        let args = [
+            "procs",
+            "--insert",
+            "OomScoreAdj",
+            "--insert",
+            "OomScore",
+            "--sortd",
+            "oomscore",
+        ];
+        let mut opt = Opt::parse_from(args.iter());
+        let view = View::new(&mut opt, &config, false).unwrap();
+        let kind = &view.columns[view.sort_info.idx].kind;
+        assert_eq!(*kind, ConfigColumnKind::OomScore);
+    }
+
+    #[test]
+    fn test_run_oneline() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.theme = ConfigTheme::Dark;
+
+        let mut opt = Opt::parse_from(["procs"]);
+        let line = oneline::summary(&mut opt, &config, "{count} procs, top: {top1.pid}").unwrap();
+        assert!(line.ends_with(|c: char| c.is_ascii_digit()));
+        assert!(!line.contains('{'));
+
+        for template in ["{unknown}", "{top0.pid}", "{count"] {
+            let mut opt = Opt::parse_from(["procs"]);
+            assert!(oneline::summary(&mut opt, &config, template).is_err());
+        }
+        assert_eq!(opt.sortd.as_deref(), Some("UsageCpu"));
+
+        // The sort column of the configuration is kept
+        config.sort.column = Some(0);
+        let mut opt = Opt::parse_from(["procs"]);
+        oneline::summary(&mut opt, &config, "{top1.pid}").unwrap();
+        assert!(opt.sortd.is_none());
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn test_renew_rate() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.theme = ConfigTheme::Dark;
+
+        let args = [
+            "procs",
+            "--insert",
+            "ReadRate",
+            "--insert",
+            "WriteRate",
+            "--insert",
+            "MajFltRate",
+            "--insert",
+            "MinFltRate",
+        ];
+        let mut opt = Opt::parse_from(args.iter());
+        let view = View::renew(&mut opt, &config, None).unwrap();
+        let view = View::renew(&mut opt, &config, Some(view)).unwrap();
+        let pid = std::process::id() as i32;
+        for c in &view.columns {
+            if matches!(
+                c.kind,
+                ConfigColumnKind::ReadRate | ConfigColumnKind::WriteRate
+            ) {
+                assert!(c.column.persistent());
+                assert!(c
+                    .column
+                    .display_content(pid, &ConfigColumnAlign::Left)
+                    .is_some());
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_alert() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        // procs itself is not shown, so a child process is used
+        let mut child = std::process::Command::new("sleep")
+            .arg("10")
+            .spawn()
+            .unwrap();
+        let pid = child.id() as i32;
+        let out = std::env::temp_dir().join(format!("procs_test_alert_{pid}"));
+        let _ = std::fs::remove_file(&out);
+        config.alerts.push(ConfigAlert {
+            condition: format!("exists:{pid}"),
+            notify: ConfigNotify {
+                webhook: None,
+                exec: Some(format!("cat >> {}", out.display())),
+                desktop: false,
+            },
+        });
+
+        let args = ["procs"];
+        let mut opt = Opt::parse_from(args.iter());
+        let mut alerter = alert::Alerter::new(&config).unwrap();
+        let mut view = View::new(&mut opt, &config, false).unwrap();
+        alerter.evaluate(&mut view, &opt, &config).unwrap();
+        // Processes which keep matching are not notified again
+        alerter.evaluate(&mut view, &opt, &config).unwrap();
+
+        let mut notified = Vec::new();
+        for _ in 0..50 {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            notified = std::fs::read_to_string(&out)
+                .unwrap_or_default()
+                .lines()
+                .map(|x| serde_json::from_str::<serde_json::Value>(x).unwrap())
+                .filter(|x| x["matches"][0]["process"]["pid"] == pid)
+                .collect();
+            if !notified.is_empty() {
+                break;
+            }
+        }
+        let _ = std::fs::remove_file(&out);
+        let _ = child.kill();
+        let _ = child.wait();
+        assert_eq!(notified.len(), 1);
+        assert_eq!(notified[0]["alert"], format!("exists:{pid}"));
+        assert_eq!(notified[0]["matches"].as_array().unwrap().len(), 1);
+        assert!(alerter.last_error().is_none());
+
+        config.alerts[0].condition = format!("count({pid}) > 1");
+        assert!(alert::Alerter::new(&config).is_err());
+
+        let alerts = "[[alerts]]\ncondition = \"rss() > 4G\"\nnotify = \"desktop\"\n";
+        let alerts: Config = toml::from_str(&format!("{CONFIG_DEFAULT}\n{alerts}")).unwrap();
+        assert!(alerts.alerts[0].notify.desktop);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn test_run_net() {
+        let mut config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        config.pager.mode = ConfigPagerMode::Disable;
+        config.display.theme = ConfigTheme::Dark;
+
+        let args = ["procs", "--net"];
+        let mut opt = Opt::parse_from(args.iter());
+        let ret = run_net(&mut opt, &config);
+        assert!(ret.is_ok());
+
+        let args = ["procs", "--net", "--resolve"];
+        let mut opt = Opt::parse_from(args.iter());
+        let ret = run_net(&mut opt, &config);
+        assert!(ret.is_ok());
     }
 
     #[test]
@@ -464,12 +1223,12 @@ mod tests {
         config.pager.mode = ConfigPagerMode::Disable;
         config.display.theme = ConfigTheme::Dark;
 
-        let args = vec!["procs", "--sorta", "cpu"];
+        let args = ["procs", "--sorta", "cpu"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
 
-        let args = vec!["procs", "--sortd", "cpu"];
+        let args = ["procs", "--sortd", "cpu"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
@@ -481,7 +1240,7 @@ mod tests {
         config.pager.mode = ConfigPagerMode::Disable;
         config.display.theme = ConfigTheme::Dark;
 
-        let args = vec!["procs", "--tree"];
+        let args = ["procs", "--tree"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
@@ -496,7 +1255,7 @@ mod tests {
         let _tcp = std::net::TcpListener::bind("127.0.0.1:10000");
         let _udp = std::net::UdpSocket::bind("127.0.0.1:10000");
 
-        let args = vec!["procs"];
+        let args = ["procs"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
@@ -508,7 +1267,7 @@ mod tests {
         config.pager.mode = ConfigPagerMode::Disable;
         config.display.theme = ConfigTheme::Dark;
 
-        let args = vec!["procs", "--use-config", "large"];
+        let args = ["procs", "--use-config", "large"];
         let mut opt = Opt::parse_from(args.iter());
         let ret = run_default(&mut opt, &config);
         assert!(ret.is_ok());
@@ -0,0 +1,749 @@
+use crate::config::*;
+use crate::process::{is_local_procfs, open_process, read_procfs_si};
+use crate::view::View;
+use crate::Opt;
+use anyhow::Error;
+use procfs::net::{TcpNetEntries, UdpNetEntries};
+use procfs::process::FDTarget;
+use std::collections::{HashMap, HashSet};
+use std::ffi::CStr;
+use std::fs;
+use std::io;
+use std::mem;
+use std::net::{IpAddr, SocketAddr};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::ptr;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const SOCK_DIAG_BY_FAMILY: u16 = 20;
+const INET_DIAG_INFO: u16 = 2;
+const NLMSG_HDR_LEN: usize = 16;
+const INET_DIAG_REQ_V2_LEN: usize = 56;
+const INET_DIAG_MSG_LEN: usize = 72;
+const NLA_TYPE_MASK: u16 = 0x3fff;
+const CTNETLINK_MSG_CT_GET: u16 = (1 << 8) | 1;
+const CTA_TUPLE_ORIG: u16 = 1;
+const CTA_TUPLE_REPLY: u16 = 2;
+const CTA_TUPLE_IP: u16 = 1;
+const CTA_TUPLE_PROTO: u16 = 2;
+const CTA_IP_V4_SRC: u16 = 1;
+const CTA_IP_V4_DST: u16 = 2;
+const CTA_IP_V6_SRC: u16 = 3;
+const CTA_IP_V6_DST: u16 = 4;
+const CTA_PROTO_NUM: u16 = 1;
+const CTA_PROTO_SRC_PORT: u16 = 2;
+const CTA_PROTO_DST_PORT: u16 = 3;
+const HOST_LEN: usize = 1025;
+const RESOLVE_THREADS: usize = 16;
+
+/// TCP statistics of a socket from `tcp_info`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TcpInfo {
+    /// Smoothed round trip time in microseconds
+    pub rtt: u32,
+    /// Total count of retransmitted segments
+    pub total_retrans: u32,
+}
+
+pub struct Socket {
+    pub proto: &'static str,
+    pub local: SocketAddr,
+    pub remote: SocketAddr,
+    pub state: String,
+    pub inode: u64,
+}
+
+/// Collects TCP statistics of all sockets in the network namespace of self by sock_diag netlink.
+///
+/// The key of the returned map is the inode of socket.
+pub fn collect_tcp_info() -> HashMap<u64, TcpInfo> {
+    let mut ret = HashMap::new();
+    for family in [libc::AF_INET, libc::AF_INET6] {
+        let _ = query_tcp_info(family as u8, &mut ret);
+    }
+    ret
+}
+
+fn query_tcp_info(family: u8, ret: &mut HashMap<u64, TcpInfo>) -> io::Result<()> {
+    // inet_diag_req_v2
+    let mut req = vec![0u8; INET_DIAG_REQ_V2_LEN];
+    req[0] = family;
+    req[1] = libc::IPPROTO_TCP as u8;
+    req[2] = 1 << (INET_DIAG_INFO - 1);
+    req[4..8].copy_from_slice(&u32::MAX.to_ne_bytes());
+
+    netlink_dump(libc::NETLINK_SOCK_DIAG, SOCK_DIAG_BY_FAMILY, &req, |msg| {
+        if msg.len() >= INET_DIAG_MSG_LEN {
+            let inode = u64::from(read_u32(msg, 68));
+            if let Some(info) = parse_tcp_info(&msg[INET_DIAG_MSG_LEN..]) {
+                ret.insert(inode, info);
+            }
+        }
+    })
+}
+
+fn parse_tcp_info(attrs: &[u8]) -> Option<TcpInfo> {
+    let (_, data) = parse_attrs(attrs)
+        .into_iter()
+        .find(|(kind, _)| *kind == INET_DIAG_INFO)?;
+    // Offsets of fields in struct tcp_info
+    if data.len() < 104 {
+        return None;
+    }
+    Some(TcpInfo {
+        rtt: read_u32(data, 68),
+        total_retrans: read_u32(data, 100),
+    })
+}
+
+/// Sends a dump request to netlink, and calls `f` with the payload of each reply.
+fn netlink_dump<F: FnMut(&[u8])>(
+    protocol: i32,
+    msg_type: u16,
+    body: &[u8],
+    mut f: F,
+) -> io::Result<()> {
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_NETLINK,
+            libc::SOCK_DGRAM | libc::SOCK_CLOEXEC,
+            protocol,
+        )
+    };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    // nlmsghdr + body
+    let len = NLMSG_HDR_LEN + body.len();
+    let mut req = vec![0u8; NLMSG_HDR_LEN];
+    req[0..4].copy_from_slice(&(len as u32).to_ne_bytes());
+    req[4..6].copy_from_slice(&msg_type.to_ne_bytes());
+    let flags = (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16;
+    req[6..8].copy_from_slice(&flags.to_ne_bytes());
+    req[8..12].copy_from_slice(&1u32.to_ne_bytes());
+    req.extend_from_slice(body);
+
+    let sent = unsafe { libc::send(fd.as_raw_fd(), req.as_ptr() as *const _, len, 0) };
+    if sent < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let mut buf = vec![0u8; 32768];
+    loop {
+        let n = unsafe { libc::recv(fd.as_raw_fd(), buf.as_mut_ptr() as *mut _, buf.len(), 0) };
+        if n < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let n = n as usize;
+        if n == 0 {
+            return Ok(());
+        }
+
+        let mut offset = 0;
+        while offset + NLMSG_HDR_LEN <= n {
+            let msg_len = read_u32(&buf, offset) as usize;
+            let msg_type = read_u16(&buf, offset + 4);
+            if msg_len < NLMSG_HDR_LEN || offset + msg_len > n {
+                return Ok(());
+            }
+            match i32::from(msg_type) {
+                libc::NLMSG_DONE => return Ok(()),
+                libc::NLMSG_ERROR => {
+                    // nlmsgerr starts with negative errno
+                    let errno = buf
+                        .get(offset + NLMSG_HDR_LEN..offset + NLMSG_HDR_LEN + 4)
+                        .map(|x| i32::from_ne_bytes([x[0], x[1], x[2], x[3]]))
+                        .unwrap_or(0);
+                    return if errno == 0 {
+                        Ok(())
+                    } else {
+                        Err(io::Error::from_raw_os_error(-errno))
+                    };
+                }
+                _ => (),
+            }
+
+            f(&buf[offset + NLMSG_HDR_LEN..offset + msg_len]);
+
+            offset += align4(msg_len);
+        }
+    }
+}
+
+/// Connection tracked by netfilter conntrack
+struct Conntrack {
+    orig: Tuple,
+    reply: Tuple,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Tuple {
+    proto: u8,
+    src: SocketAddr,
+    dst: SocketAddr,
+}
+
+/// Collects conntrack entries by conntrack netlink.
+///
+/// This requires `CAP_NET_ADMIN`, so `None` is returned if the dump fails.
+fn collect_conntrack() -> Option<Vec<Conntrack>> {
+    let mut ret = Vec::new();
+    for family in [libc::AF_INET, libc::AF_INET6] {
+        // nfgenmsg
+        let req = [family as u8, 0, 0, 0];
+        netlink_dump(libc::NETLINK_NETFILTER, CTNETLINK_MSG_CT_GET, &req, |msg| {
+            if msg.len() < 4 {
+                return;
+            }
+            let mut orig = None;
+            let mut reply = None;
+            for (kind, data) in parse_attrs(&msg[4..]) {
+                match kind {
+                    CTA_TUPLE_ORIG => orig = parse_tuple(data),
+                    CTA_TUPLE_REPLY => reply = parse_tuple(data),
+                    _ => (),
+                }
+            }
+            if let (Some(orig), Some(reply)) = (orig, reply) {
+                ret.push(Conntrack { orig, reply });
+            }
+        })
+        .ok()?;
+    }
+    Some(ret)
+}
+
+fn parse_tuple(buf: &[u8]) -> Option<Tuple> {
+    let mut src = None;
+    let mut dst = None;
+    let mut proto = None;
+    let mut src_port = None;
+    let mut dst_port = None;
+    for (kind, data) in parse_attrs(buf) {
+        match kind {
+            CTA_TUPLE_IP => {
+                for (kind, data) in parse_attrs(data) {
+                    let ip = match data.len() {
+                        4 => IpAddr::from(<[u8; 4]>::try_from(data).ok()?),
+                        16 => IpAddr::from(<[u8; 16]>::try_from(data).ok()?),
+                        _ => continue,
+                    };
+                    match kind {
+                        CTA_IP_V4_SRC | CTA_IP_V6_SRC => src = Some(ip),
+                        CTA_IP_V4_DST | CTA_IP_V6_DST => dst = Some(ip),
+                        _ => (),
+                    }
+                }
+            }
+            CTA_TUPLE_PROTO => {
+                for (kind, data) in parse_attrs(data) {
+                    match (kind, data.len()) {
+                        (CTA_PROTO_NUM, 1) => proto = Some(data[0]),
+                        (CTA_PROTO_SRC_PORT, 2) => {
+                            src_port = Some(u16::from_be_bytes([data[0], data[1]]))
+                        }
+                        (CTA_PROTO_DST_PORT, 2) => {
+                            dst_port = Some(u16::from_be_bytes([data[0], data[1]]))
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    Some(Tuple {
+        proto: proto?,
+        src: SocketAddr::new(src?, src_port?),
+        dst: SocketAddr::new(dst?, dst_port?),
+    })
+}
+
+/// Builds NAT descriptions of connections from conntrack entries.
+///
+/// The key is the triple of protocol, local address and remote address of a socket,
+/// and the value is the translated address seen from the peer ( ex. `snat:203.0.113.1:40000` ).
+fn collect_nat() -> Option<HashMap<(u8, SocketAddr, SocketAddr), String>> {
+    let mut ret = HashMap::new();
+    for ct in collect_conntrack()? {
+        let (orig, reply) = (ct.orig, ct.reply);
+        if reply.src == orig.dst && reply.dst == orig.src {
+            continue;
+        }
+
+        // The socket which initiated the connection sees the original tuple
+        let mut nat = Vec::new();
+        if reply.src != orig.dst {
+            nat.push(format!("dnat:{}", reply.src));
+        }
+        if reply.dst != orig.src {
+            nat.push(format!("snat:{}", reply.dst));
+        }
+        ret.insert((orig.proto, orig.src, orig.dst), nat.join(","));
+
+        // The socket which accepted the connection sees the reply tuple
+        let mut nat = Vec::new();
+        if orig.dst != reply.src {
+            nat.push(format!("dnat:{}", orig.dst));
+        }
+        if orig.src != reply.dst {
+            nat.push(format!("snat:{}", orig.src));
+        }
+        ret.insert((reply.proto, reply.src, reply.dst), nat.join(","));
+    }
+    Some(ret)
+}
+
+/// Converts IPv4-mapped IPv6 address ( ex. `::ffff:192.0.2.1` ) of dual-stack sockets to IPv4.
+fn canonical(addr: &SocketAddr) -> SocketAddr {
+    match addr.ip() {
+        IpAddr::V6(x) => {
+            if let Some(x) = x.to_ipv4_mapped() {
+                SocketAddr::new(IpAddr::V4(x), addr.port())
+            } else {
+                *addr
+            }
+        }
+        IpAddr::V4(_) => *addr,
+    }
+}
+
+/// Parses netlink attributes to pairs of type and payload.
+fn parse_attrs(buf: &[u8]) -> Vec<(u16, &[u8])> {
+    let mut ret = Vec::new();
+    let mut offset = 0;
+    while offset + 4 <= buf.len() {
+        let len = read_u16(buf, offset) as usize;
+        let kind = read_u16(buf, offset + 2) & NLA_TYPE_MASK;
+        if len < 4 || offset + len > buf.len() {
+            break;
+        }
+        ret.push((kind, &buf[offset + 4..offset + len]));
+        offset += align4(len);
+    }
+    ret
+}
+
+fn read_u16(buf: &[u8], offset: usize) -> u16 {
+    u16::from_ne_bytes([buf[offset], buf[offset + 1]])
+}
+
+fn read_u32(buf: &[u8], offset: usize) -> u32 {
+    u32::from_ne_bytes([
+        buf[offset],
+        buf[offset + 1],
+        buf[offset + 2],
+        buf[offset + 3],
+    ])
+}
+
+fn align4(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+/// Collects TCP/UDP sockets from procfs.
+pub fn collect_sockets() -> Vec<Socket> {
+    let mut ret = Vec::new();
+    for path in ["net/tcp", "net/tcp6"] {
+        if let Ok(entries) = read_procfs_si::<TcpNetEntries>(path) {
+            for x in entries.0 {
+                ret.push(Socket {
+                    proto: "TCP",
+                    local: x.local_address,
+                    remote: x.remote_address,
+                    state: format!("{:?}", x.state),
+                    inode: x.inode,
+                });
+            }
+        }
+    }
+    for path in ["net/udp", "net/udp6"] {
+        if let Ok(entries) = read_procfs_si::<UdpNetEntries>(path) {
+            for x in entries.0 {
+                ret.push(Socket {
+                    proto: "UDP",
+                    local: x.local_address,
+                    remote: x.remote_address,
+                    state: format!("{:?}", x.state),
+                    inode: x.inode,
+                });
+            }
+        }
+    }
+    ret
+}
+
+/// Lists sockets of the matched processes.
+pub fn run_net(opt: &mut Opt, config: &Config) -> Result<(), Error> {
+    let mut view = View::new(opt, config, false)?;
+    view.filter(opt, config);
+
+    // sock_diag can see sockets of the network namespace of self only
+    let tcp_info = if is_local_procfs() {
+        collect_tcp_info()
+    } else {
+        HashMap::new()
+    };
+    let sockets = collect_sockets();
+    // conntrack can see connections of the network namespace of self only
+    let nat = if is_local_procfs() {
+        collect_nat()
+    } else {
+        None
+    };
+
+    let mut entries = Vec::new();
+    for pid in &view.visible_pids {
+        let proc = if let Ok(x) = open_process(*pid) {
+            x
+        } else {
+            continue;
+        };
+        let comm = proc.stat().map(|x| x.comm).unwrap_or_default();
+        let inodes: HashSet<u64> = if let Ok(fds) = proc.fd() {
+            fds.flatten()
+                .filter_map(|x| match x.target {
+                    FDTarget::Socket(x) => Some(x),
+                    _ => None,
+                })
+                .collect()
+        } else {
+            continue;
+        };
+
+        for socket in sockets.iter().filter(|x| inodes.contains(&x.inode)) {
+            entries.push((*pid, comm.clone(), socket));
+        }
+    }
+
+    let (hosts, services) = if opt.resolve {
+        let ips = entries
+            .iter()
+            .map(|(_, _, x)| x.remote.ip())
+            .filter(|x| !x.is_unspecified())
+            .collect();
+        let timeout = Duration::from_millis(config.net.resolve_timeout);
+        (resolve_hosts(ips, timeout), load_services())
+    } else {
+        (HashMap::new(), HashMap::new())
+    };
+
+    let geoip = GeoIp::new(&config.net);
+
+    let mut header = vec![
+        "PID", "Command", "Proto", "Local", "Remote", "State", "Retrans", "RTT[ms]",
+    ];
+    if nat.is_some() {
+        header.push("NAT");
+    }
+    if geoip.available() {
+        header.push("Geo");
+    }
+    let mut rows = Vec::new();
+    for (pid, comm, socket) in entries {
+        let (retrans, rtt) = if let Some(info) = tcp_info.get(&socket.inode) {
+            (
+                format!("{}", info.total_retrans),
+                format!("{:.3}", info.rtt as f64 / 1000.0),
+            )
+        } else {
+            (String::new(), String::new())
+        };
+        let remote = if opt.resolve && !socket.remote.ip().is_unspecified() {
+            format_resolved(&socket.remote, socket.proto, &hosts, &services)
+        } else {
+            format!("{}", socket.remote)
+        };
+        let mut row = vec![
+            format!("{pid}"),
+            comm,
+            String::from(socket.proto),
+            format!("{}", socket.local),
+            remote,
+            socket.state.clone(),
+            retrans,
+            rtt,
+        ];
+        if let Some(nat) = &nat {
+            let proto = if socket.proto == "TCP" {
+                libc::IPPROTO_TCP
+            } else {
+                libc::IPPROTO_UDP
+            };
+            let key = (
+                proto as u8,
+                canonical(&socket.local),
+                canonical(&socket.remote),
+            );
+            row.push(nat.get(&key).cloned().unwrap_or_default());
+        }
+        if geoip.available() {
+            row.push(geoip.annotate(socket.remote.ip()));
+        }
+        rows.push(row);
+    }
+
+    print_table(&header, &rows);
+    Ok(())
+}
+
+/// Country/AS annotation by local MaxMind databases
+#[cfg(feature = "geoip")]
+struct GeoIp {
+    country: Option<maxminddb::Reader<Vec<u8>>>,
+    asn: Option<maxminddb::Reader<Vec<u8>>>,
+}
+
+#[cfg(feature = "geoip")]
+impl GeoIp {
+    fn new(config: &ConfigNet) -> Self {
+        let open = |path: &Option<String>| {
+            path.as_ref()
+                .and_then(|x| maxminddb::Reader::open_readfile(x).ok())
+        };
+        Self {
+            country: open(&config.country_db),
+            asn: open(&config.asn_db),
+        }
+    }
+
+    fn available(&self) -> bool {
+        self.country.is_some() || self.asn.is_some()
+    }
+
+    fn annotate(&self, ip: IpAddr) -> String {
+        if is_local(ip) {
+            return String::new();
+        }
+
+        let mut ret = Vec::new();
+        if let Some(reader) = &self.country {
+            let country = reader
+                .lookup::<maxminddb::geoip2::Country>(ip)
+                .ok()
+                .and_then(|x| x.country)
+                .and_then(|x| x.iso_code);
+            if let Some(x) = country {
+                ret.push(x.to_string());
+            }
+        }
+        if let Some(reader) = &self.asn {
+            if let Ok(x) = reader.lookup::<maxminddb::geoip2::Asn>(ip) {
+                if let Some(number) = x.autonomous_system_number {
+                    ret.push(format!("AS{number}"));
+                }
+                if let Some(org) = x.autonomous_system_organization {
+                    ret.push(org.to_string());
+                }
+            }
+        }
+        ret.join(" ")
+    }
+}
+
+/// Whether the address is not routed on the internet, so it has no country/AS
+#[cfg(feature = "geoip")]
+fn is_local(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(x) => {
+            x.is_unspecified() || x.is_loopback() || x.is_private() || x.is_link_local()
+        }
+        IpAddr::V6(x) => {
+            if let Some(x) = x.to_ipv4_mapped() {
+                return is_local(IpAddr::V4(x));
+            }
+            // Unique local ( fc00::/7 ) and link-local ( fe80::/10 )
+            let head = x.segments()[0];
+            x.is_unspecified()
+                || x.is_loopback()
+                || head & 0xfe00 == 0xfc00
+                || head & 0xffc0 == 0xfe80
+        }
+    }
+}
+
+#[cfg(not(feature = "geoip"))]
+struct GeoIp;
+
+#[cfg(not(feature = "geoip"))]
+impl GeoIp {
+    fn new(_config: &ConfigNet) -> Self {
+        Self
+    }
+
+    fn available(&self) -> bool {
+        false
+    }
+
+    fn annotate(&self, _ip: IpAddr) -> String {
+        String::new()
+    }
+}
+
+fn format_resolved(
+    addr: &SocketAddr,
+    proto: &str,
+    hosts: &HashMap<IpAddr, String>,
+    services: &HashMap<(u16, String), String>,
+) -> String {
+    let host = match (hosts.get(&addr.ip()), addr.ip()) {
+        (Some(x), _) => x.clone(),
+        (None, IpAddr::V4(x)) => format!("{x}"),
+        (None, IpAddr::V6(x)) => format!("[{x}]"),
+    };
+    let port = if let Some(x) = services.get(&(addr.port(), proto.to_lowercase())) {
+        x.clone()
+    } else {
+        format!("{}", addr.port())
+    };
+    format!("{host}:{port}")
+}
+
+/// Resolves host names of the addresses by reverse DNS concurrently.
+///
+/// Each address is resolved once, and addresses which are not resolved within `timeout` are left numeric.
+fn resolve_hosts(ips: HashSet<IpAddr>, timeout: Duration) -> HashMap<IpAddr, String> {
+    let queue = Arc::new(Mutex::new(ips.into_iter().collect::<Vec<_>>()));
+    let (tx, rx) = mpsc::channel();
+    for _ in 0..RESOLVE_THREADS {
+        let queue = Arc::clone(&queue);
+        let tx = tx.clone();
+        thread::spawn(move || {
+            while let Some(ip) = queue.lock().ok().and_then(|mut x| x.pop()) {
+                if tx.send((ip, reverse_lookup(ip))).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+    drop(tx);
+
+    let deadline = Instant::now() + timeout;
+    let mut ret = HashMap::new();
+    while let Some(rest) = deadline.checked_duration_since(Instant::now()) {
+        match rx.recv_timeout(rest) {
+            Ok((ip, Some(name))) => {
+                ret.insert(ip, name);
+            }
+            Ok((_, None)) => (),
+            Err(_) => break,
+        }
+    }
+    ret
+}
+
+fn reverse_lookup(ip: IpAddr) -> Option<String> {
+    let mut host = [0 as libc::c_char; HOST_LEN];
+    let ret = match ip {
+        IpAddr::V4(x) => {
+            let mut addr: libc::sockaddr_in = unsafe { mem::zeroed() };
+            addr.sin_family = libc::AF_INET as libc::sa_family_t;
+            addr.sin_addr = libc::in_addr {
+                s_addr: u32::from_ne_bytes(x.octets()),
+            };
+            unsafe {
+                libc::getnameinfo(
+                    &addr as *const libc::sockaddr_in as *const libc::sockaddr,
+                    mem::size_of::<libc::sockaddr_in>() as libc::socklen_t,
+                    host.as_mut_ptr(),
+                    HOST_LEN as libc::socklen_t,
+                    ptr::null_mut(),
+                    0,
+                    libc::NI_NAMEREQD,
+                )
+            }
+        }
+        IpAddr::V6(x) => {
+            let mut addr: libc::sockaddr_in6 = unsafe { mem::zeroed() };
+            addr.sin6_family = libc::AF_INET6 as libc::sa_family_t;
+            addr.sin6_addr = libc::in6_addr {
+                s6_addr: x.octets(),
+            };
+            unsafe {
+                libc::getnameinfo(
+                    &addr as *const libc::sockaddr_in6 as *const libc::sockaddr,
+                    mem::size_of::<libc::sockaddr_in6>() as libc::socklen_t,
+                    host.as_mut_ptr(),
+                    HOST_LEN as libc::socklen_t,
+                    ptr::null_mut(),
+                    0,
+                    libc::NI_NAMEREQD,
+                )
+            }
+        }
+    };
+    if ret != 0 {
+        return None;
+    }
+    let host = unsafe { CStr::from_ptr(host.as_ptr()) };
+    host.to_str().ok().map(String::from)
+}
+
+/// Loads service names from `/etc/services`.
+///
+/// The key of the returned map is the pair of port and protocol ( ex. `(443, "tcp")` ).
+fn load_services() -> HashMap<(u16, String), String> {
+    let mut ret = HashMap::new();
+    if let Ok(s) = fs::read_to_string("/etc/services") {
+        for line in s.lines() {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut fields = line.split_whitespace();
+            if let (Some(name), Some(port)) = (fields.next(), fields.next()) {
+                if let Some((port, proto)) = port.split_once('/') {
+                    if let Ok(port) = port.parse::<u16>() {
+                        ret.entry((port, proto.to_string()))
+                            .or_insert_with(|| name.to_string());
+                    }
+                }
+            }
+        }
+    }
+    ret
+}
+
+fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|x| x.len()).collect();
+    for row in rows {
+        for (i, x) in row.iter().enumerate() {
+            widths[i] = widths[i].max(x.len());
+        }
+    }
+
+    let header: Vec<String> = header.iter().map(|x| x.to_string()).collect();
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let line: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(i, x)| format!("{:<width$}", x, width = widths[i]))
+            .collect();
+        println!("{}", line.join(" ").trim_end());
+    }
+}
+
+#[cfg(all(test, feature = "geoip"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_local() {
+        for (ip, local) in [
+            ("10.0.0.1", true),
+            ("169.254.1.1", true),
+            ("8.8.8.8", false),
+            ("::1", true),
+            ("fd12:3456::1", true),
+            ("fc00::1", true),
+            ("fe80::1", true),
+            ("febf::1", true),
+            ("fec0::1", false),
+            ("::ffff:192.168.0.1", true),
+            ("2001:4860:4860::8888", false),
+        ] {
+            assert_eq!(is_local(ip.parse().unwrap()), local, "{ip}");
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::config::*;
+use crate::util::render_template;
+use crate::view::View;
+use crate::Opt;
+use anyhow::{anyhow, Error};
+
+/// Prints a summary line of processes by the template for status bars.
+///
+/// Placeholders:
+///   - `{count}`       : the number of matched processes
+///   - `{topN.<kind>}` : the column of the N-th process in the sort order ( ex. `{top1.command}` )
+pub fn run_oneline(opt: &mut Opt, config: &Config, template: &str) -> Result<(), Error> {
+    println!("{}", summary(opt, config, template)?);
+    Ok(())
+}
+
+pub fn summary(opt: &mut Opt, config: &Config, template: &str) -> Result<String, Error> {
+    // "top" means the busiest processes unless the sort column is specified by options or the configuration
+    if opt.sorta.is_none() && opt.sortd.is_none() && config.sort.column.is_none() {
+        opt.sortd = Some(String::from("UsageCpu"));
+    }
+
+    let mut view = View::new(opt, config, false)?;
+    view.filter(opt, config);
+    view.fit_width(config);
+
+    render_template(template, |x| expand(&view, config, x))
+}
+
+fn expand(view: &View, config: &Config, placeholder: &str) -> Result<String, Error> {
+    if placeholder == "count" {
+        return Ok(view.visible_pids.len().to_string());
+    }
+
+    let (rank, field) = placeholder
+        .strip_prefix("top")
+        .and_then(|x| x.split_once('.'))
+        .and_then(|(rank, field)| rank.parse::<usize>().ok().map(|rank| (rank, field)))
+        .filter(|(rank, _)| *rank > 0)
+        .ok_or_else(|| anyhow!("unknown placeholder: {{{placeholder}}}"))?;
+
+    let column = view.find_column(config, field).ok_or_else(|| {
+        anyhow!("column \"{field}\" is not found in columns, it can be added by --insert")
+    })?;
+
+    // Ranks beyond the matched processes are empty so that the line keeps its shape
+    let content = view
+        .visible_pids
+        .get(rank - 1)
+        .and_then(|pid| {
+            column
+                .column
+                .display_content(*pid, &ConfigColumnAlign::Left)
+        })
+        .unwrap_or_default();
+    Ok(content.trim().to_string())
+}
@@ -0,0 +1,174 @@
+use crate::columns::ConfigColumnKind;
+use crate::config::*;
+use crate::util::{column_kind_name, http_post, insert_metric_columns, snake_case, split_http_url};
+use crate::view::View;
+use crate::Opt;
+use anyhow::{anyhow, Error};
+use serde_json::{json, Value};
+use std::sync::mpsc::{channel, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DEFAULT_PATH: &str = "/v1/metrics";
+
+/// Columns used as attributes of data points, and the keys by OpenTelemetry semantic conventions
+const ATTRIBUTES: &[(&str, &str)] = &[
+    ("Command", "process.command"),
+    ("User", "process.owner"),
+    ("Container", "container.name"),
+    ("Docker", "container.name"),
+];
+
+/// Pushes metrics of processes to an OTLP/HTTP endpoint as gauges.
+///
+/// Requests are sent by a background thread so that slow collectors don't block watch mode,
+/// and the last error is kept to be reported at exit.
+pub struct OtelExporter {
+    metrics: Vec<ConfigColumnKind>,
+    tx: Sender<String>,
+    error: Arc<Mutex<Option<String>>>,
+}
+
+impl OtelExporter {
+    /// Columns of metrics which are not in the configuration are inserted to slots like `--insert`.
+    pub fn new(endpoint: &str, opt: &mut Opt, config: &Config) -> Result<Self, Error> {
+        let (origin, path) = parse_endpoint(endpoint)?;
+        let metrics = insert_metric_columns(opt, config, &config.otel.metrics)?;
+
+        let (tx, rx) = channel::<String>();
+        let error = Arc::new(Mutex::new(None));
+        let thread_error = error.clone();
+        thread::spawn(move || {
+            for body in rx {
+                let res = http_post(&origin, &path, &body);
+                *thread_error.lock().unwrap() = res.err().map(|x| x.to_string());
+            }
+        });
+
+        Ok(Self { metrics, tx, error })
+    }
+
+    /// Exports visible processes of the view, which must be called before `View::adjust` truncates attributes.
+    pub fn export(&self, view: &mut View, config: &Config) {
+        view.fit_width(config);
+
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos()
+            .to_string();
+
+        let attribute_columns: Vec<_> = ATTRIBUTES
+            .iter()
+            .filter_map(|(kind, key)| {
+                view.columns
+                    .iter()
+                    .find(|c| column_kind_name(&c.kind) == *kind)
+                    .map(|c| (c, *key))
+            })
+            .collect();
+
+        let mut metrics = Vec::new();
+        for kind in &self.metrics {
+            // Unavailable columns ( ex. no permission ) are not shown
+            let column = if let Some(x) = view.columns.iter().find(|c| c.kind == *kind) {
+                x
+            } else {
+                continue;
+            };
+
+            let mut data_points = Vec::new();
+            for pid in &view.visible_pids {
+                let value = if let Some(x) = column.column.raw_value(*pid) {
+                    x
+                } else {
+                    continue;
+                };
+
+                let mut attributes =
+                    vec![json!({"key": "process.pid", "value": {"intValue": pid.to_string()}})];
+                for (c, key) in &attribute_columns {
+                    if let Some(x) = c.column.display_content(*pid, &ConfigColumnAlign::Left) {
+                        let x = x.trim();
+                        if !x.is_empty() {
+                            attributes.push(json!({"key": key, "value": {"stringValue": x}}));
+                        }
+                    }
+                }
+                data_points.push(json!({
+                    "attributes": attributes,
+                    "timeUnixNano": time,
+                    "asDouble": value,
+                }));
+            }
+
+            let unit = column.column.display_unit(&ConfigColumnAlign::Left);
+            metrics.push(json!({
+                "name": format!("procs.{}", snake_case(&column_kind_name(kind))),
+                "unit": unit.trim().trim_start_matches('[').trim_end_matches(']'),
+                "gauge": {"dataPoints": data_points},
+            }));
+        }
+
+        let body = json!({
+            "resourceMetrics": [{
+                "resource": {"attributes": [
+                    {"key": "service.name", "value": {"stringValue": "procs"}},
+                ]},
+                "scopeMetrics": [{
+                    "scope": {"name": "procs", "version": env!("CARGO_PKG_VERSION")},
+                    "metrics": metrics,
+                }],
+            }],
+        });
+        let _ = self.tx.send(Value::to_string(&body));
+    }
+
+    pub fn last_error(&self) -> Option<String> {
+        self.error.lock().unwrap().clone()
+    }
+}
+
+/// Uses the default path of OTLP/HTTP unless the path is specified.
+fn parse_endpoint(endpoint: &str) -> Result<(String, String), Error> {
+    let (origin, path) = split_http_url(endpoint)
+        .map_err(|e| anyhow!("invalid --otel-endpoint ( ex. http://localhost:4318 ): {e}"))?;
+    if path == "/" {
+        Ok((origin, String::from(DEFAULT_PATH)))
+    } else {
+        Ok((origin, path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_endpoint() {
+        assert_eq!(
+            parse_endpoint("http://localhost:4318").unwrap(),
+            (
+                String::from("http://localhost:4318"),
+                String::from("/v1/metrics")
+            )
+        );
+        assert_eq!(
+            parse_endpoint("http://collector/otlp/v1/metrics").unwrap(),
+            (
+                String::from("http://collector"),
+                String::from("/otlp/v1/metrics")
+            )
+        );
+        assert_eq!(
+            parse_endpoint("https://collector:4318/").unwrap(),
+            (
+                String::from("https://collector:4318"),
+                String::from("/v1/metrics")
+            )
+        );
+        assert!(parse_endpoint("grpc://localhost:4317").is_err());
+        assert!(parse_endpoint("http://").is_err());
+    }
+}
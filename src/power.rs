@@ -0,0 +1,104 @@
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use std::fs;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use std::path::Path;
+
+#[derive(Clone, Debug, Default)]
+pub struct PowerInfo {
+    /// Whether any AC adapter is online, `None` if there is no adapter ( ex. desktops )
+    pub ac_online: Option<bool>,
+    /// Capacity [%] and status ( ex. `Discharging` ) of each battery
+    pub batteries: Vec<(u64, String)>,
+}
+
+impl PowerInfo {
+    /// Returns a summary like `AC, Battery: 80% ( Charging )`, or `None` if there is no power supply.
+    pub fn summary(&self) -> Option<String> {
+        let mut ret = Vec::new();
+        match self.ac_online {
+            Some(true) => ret.push(String::from("AC")),
+            Some(false) => ret.push(String::from("On battery")),
+            None => (),
+        }
+        for (capacity, status) in &self.batteries {
+            ret.push(format!("Battery: {capacity}% ( {status} )"));
+        }
+        (!ret.is_empty()).then(|| ret.join(", "))
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_power_info() -> PowerInfo {
+    get_power_supply(Path::new("/sys/class/power_supply"))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn get_power_info() -> PowerInfo {
+    PowerInfo::default()
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_power_supply(power_supply: &Path) -> PowerInfo {
+    let mut ret = PowerInfo::default();
+    let read = |dir: &Path, file: &str| {
+        fs::read_to_string(dir.join(file))
+            .map(|x| x.trim().to_string())
+            .ok()
+    };
+    let Ok(entries) = fs::read_dir(power_supply) else {
+        return ret;
+    };
+    let mut dirs: Vec<_> = entries.flatten().map(|x| x.path()).collect();
+    dirs.sort();
+    for dir in dirs {
+        match read(&dir, "type").as_deref() {
+            Some("Mains") | Some("USB") => {
+                if let Some(online) = read(&dir, "online") {
+                    ret.ac_online = Some(ret.ac_online.unwrap_or(false) || online == "1");
+                }
+            }
+            // Batteries of peripherals ( ex. mouse ) have `scope` of `Device`
+            Some("Battery") if read(&dir, "scope").as_deref() != Some("Device") => {
+                if let Some(capacity) = read(&dir, "capacity").and_then(|x| x.parse().ok()) {
+                    let status = read(&dir, "status").unwrap_or_else(|| String::from("Unknown"));
+                    ret.batteries.push((capacity, status));
+                }
+            }
+            _ => (),
+        }
+    }
+    ret
+}
+
+#[cfg(all(test, any(target_os = "linux", target_os = "android")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_power_supply() {
+        let root = std::env::temp_dir().join(format!("procs-power-{}", std::process::id()));
+        let files = [
+            ("AC/type", "Mains"),
+            ("AC/online", "0"),
+            ("BAT0/type", "Battery"),
+            ("BAT0/capacity", "54"),
+            ("BAT0/status", "Discharging"),
+            ("hid-mouse/type", "Battery"),
+            ("hid-mouse/scope", "Device"),
+            ("hid-mouse/capacity", "10"),
+        ];
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, format!("{content}\n")).unwrap();
+        }
+
+        let info = get_power_supply(&root);
+        let _ = fs::remove_dir_all(&root);
+        assert_eq!(
+            info.summary().as_deref(),
+            Some("On battery, Battery: 54% ( Discharging )")
+        );
+        assert_eq!(PowerInfo::default().summary(), None);
+    }
+}
@@ -15,3 +15,13 @@ pub use self::linux::*;
 pub use self::macos::*;
 #[cfg(target_os = "windows")]
 pub use self::windows::*;
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn self_pid() -> Option<i32> {
+    Some(std::process::id() as i32)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn in_container() -> bool {
+    false
+}
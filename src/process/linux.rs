@@ -1,15 +1,439 @@
-use procfs::process::{FDInfo, Io, Process, Stat, Status, TasksIter};
-use procfs::ProcError;
+use chrono::{DateTime, Local, TimeZone};
+use once_cell::sync::Lazy;
+use once_cell::unsync::OnceCell;
+use procfs::process::{FDInfo, Io, Limits, MemoryMap, Process, Stat, Status, TasksIter};
 use procfs::ProcessCGroup;
+use procfs::{FromRead, FromReadSI, KernelStats, ProcError, ProcResult, Uptime};
 use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
 use std::time::{Duration, Instant};
 
+static PROCFS_ROOT: Lazy<RwLock<PathBuf>> = Lazy::new(|| RwLock::new(PathBuf::from("/proc")));
+
+static CGROUP2_MOUNT: Lazy<Option<PathBuf>> = Lazy::new(|| {
+    Process::myself()
+        .ok()?
+        .mountinfo()
+        .ok()?
+        .into_iter()
+        .find(|x| x.fs_type == "cgroup2")
+        .map(|x| x.mount_point)
+});
+
+pub fn cgroup2_mount() -> Option<&'static Path> {
+    CGROUP2_MOUNT.as_deref()
+}
+
+/// Reads a cgroup v2 interface file of the cgroup and all its ancestors from leaf to root.
+pub fn read_cgroup2_hierarchy(dir: &Path, file: &str) -> Vec<String> {
+    let mut ret = Vec::new();
+    let mount = if let Some(x) = CGROUP2_MOUNT.as_ref() {
+        x
+    } else {
+        return ret;
+    };
+    let mut dir = Some(dir);
+    while let Some(x) = dir {
+        if !x.starts_with(mount) {
+            break;
+        }
+        if let Ok(s) = fs::read_to_string(x.join(file)) {
+            ret.push(s.trim().to_string());
+        }
+        dir = x.parent();
+    }
+    ret
+}
+
+/// Changes the procfs path to read processes from (ex. a host /proc bind-mounted into a container).
+pub fn set_procfs_root(path: &Path) {
+    if let Ok(mut root) = PROCFS_ROOT.write() {
+        *root = path.to_path_buf();
+    }
+}
+
+pub fn procfs_root() -> PathBuf {
+    PROCFS_ROOT
+        .read()
+        .map(|x| x.clone())
+        .unwrap_or_else(|_| PathBuf::from("/proc"))
+}
+
+/// Returns true if procfs root is the one of self, so PIDs can be used for syscalls.
+pub fn is_local_procfs() -> bool {
+    procfs_root() == Path::new("/proc")
+}
+
+/// File descriptor which refers to a process, which isn't affected by reuse of PIDs.
+pub struct PidFd(std::os::fd::OwnedFd);
+
+impl PidFd {
+    pub fn open(pid: i32) -> io::Result<Self> {
+        use std::os::fd::FromRawFd;
+
+        let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid, 0) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self(unsafe {
+            std::os::fd::OwnedFd::from_raw_fd(fd as i32)
+        }))
+    }
+
+    /// Opens the process only if it was started by `ticks` since boot.
+    ///
+    /// A PID got from a scan may be reused by another process before it is opened,
+    /// so `ticks` should be got by `boot_ticks` before the scan.
+    pub fn open_started_by(pid: i32, ticks: u64) -> io::Result<Option<Self>> {
+        let pidfd = Self::open(pid)?;
+        let starttime = open_process(pid)
+            .and_then(|x| x.stat())
+            .map_err(io::Error::other)?
+            .starttime;
+        // The PID still refers to the opened process unless it exited while reading stat
+        if pidfd.exited() || starttime > ticks {
+            return Ok(None);
+        }
+        Ok(Some(pidfd))
+    }
+
+    /// Zombies are treated as exited because they are waiting to be reaped.
+    pub fn exited(&self) -> bool {
+        use std::os::fd::AsRawFd;
+
+        let mut fd = libc::pollfd {
+            fd: self.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        unsafe { libc::poll(&mut fd, 1, 0) > 0 }
+    }
+}
+
+/// Returns the time since boot in clock ticks, which is the unit of `starttime` in `/proc/<pid>/stat`.
+pub fn boot_ticks() -> u64 {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    unsafe { libc::clock_gettime(libc::CLOCK_BOOTTIME, &mut ts) };
+    let ticks = procfs::ticks_per_second();
+    ts.tv_sec as u64 * ticks + ts.tv_nsec as u64 * ticks / 1_000_000_000
+}
+
+impl std::os::fd::AsRawFd for PidFd {
+    fn as_raw_fd(&self) -> std::os::fd::RawFd {
+        self.0.as_raw_fd()
+    }
+}
+
+const SIGNALS: [&str; 31] = [
+    "SIGHUP",
+    "SIGINT",
+    "SIGQUIT",
+    "SIGILL",
+    "SIGTRAP",
+    "SIGABRT",
+    "SIGBUS",
+    "SIGFPE",
+    "SIGKILL",
+    "SIGUSR1",
+    "SIGSEGV",
+    "SIGUSR2",
+    "SIGPIPE",
+    "SIGALRM",
+    "SIGTERM",
+    "SIGSTKFLT",
+    "SIGCHLD",
+    "SIGCONT",
+    "SIGSTOP",
+    "SIGTSTP",
+    "SIGTTIN",
+    "SIGTTOU",
+    "SIGURG",
+    "SIGXCPU",
+    "SIGXFSZ",
+    "SIGVTALRM",
+    "SIGPROF",
+    "SIGWINCH",
+    "SIGIO",
+    "SIGPWR",
+    "SIGSYS",
+];
+
+pub fn signal_name(sig: i32) -> Option<&'static str> {
+    usize::try_from(sig)
+        .ok()
+        .and_then(|x| x.checked_sub(1))
+        .and_then(|x| SIGNALS.get(x))
+        .copied()
+}
+
+/// Returns names of signals in the mask of `/proc/<pid>/status` ( ex. `SigBlk` ), in the order of signal numbers.
+///
+/// Real-time signals don't have names, so they are shown as `SIG<number>`.
+pub fn signal_names(mask: u64) -> Vec<String> {
+    (0..64)
+        .filter(|x| mask & (1 << x) != 0)
+        .map(|x| {
+            let sig = x + 1;
+            signal_name(sig)
+                .map(String::from)
+                .unwrap_or_else(|| format!("SIG{sig}"))
+        })
+        .collect()
+}
+
+/// Number of signals shown before the rest is abbreviated
+const TOP_SIGNALS: usize = 3;
+
+/// Abbreviates signal names for display ( ex. `INT,QUIT,TERM,+2` ).
+pub fn abbr_signal_names(names: &[String]) -> String {
+    let mut ret: Vec<_> = names
+        .iter()
+        .take(TOP_SIGNALS)
+        .map(|x| x.trim_start_matches("SIG").to_string())
+        .collect();
+    if names.len() > TOP_SIGNALS {
+        ret.push(format!("+{}", names.len() - TOP_SIGNALS));
+    }
+    ret.join(",")
+}
+
+/// Mapping from CPU to NUMA node by `/sys/devices/system/node/node*/cpulist`.
+static CPU_NODES: Lazy<HashMap<u32, u32>> = Lazy::new(|| {
+    let mut ret = HashMap::new();
+    let dirs = if let Ok(x) = fs::read_dir("/sys/devices/system/node") {
+        x
+    } else {
+        return ret;
+    };
+    for dir in dirs.flatten() {
+        let name = dir.file_name();
+        let node = name
+            .to_str()
+            .and_then(|x| x.strip_prefix("node"))
+            .and_then(|x| x.parse::<u32>().ok());
+        let node = if let Some(x) = node {
+            x
+        } else {
+            continue;
+        };
+        if let Ok(list) = fs::read_to_string(dir.path().join("cpulist")) {
+            for cpu in parse_cpulist(&list) {
+                ret.insert(cpu, node);
+            }
+        }
+    }
+    ret
+});
+
+/// Parses a CPU list like `0-3,8-11`.
+fn parse_cpulist(s: &str) -> Vec<u32> {
+    let mut ret = Vec::new();
+    for range in s.trim().split(',').filter(|x| !x.is_empty()) {
+        let (start, end) = range.split_once('-').unwrap_or((range, range));
+        if let (Ok(start), Ok(end)) = (start.parse::<u32>(), end.parse::<u32>()) {
+            ret.extend(start..=end);
+        }
+    }
+    ret
+}
+
+/// Returns the NUMA node which the CPU belongs to.
+pub fn cpu_node(cpu: i32) -> Option<u32> {
+    CPU_NODES.get(&u32::try_from(cpu).ok()?).copied()
+}
+
+/// Sums up pages of each node ( `N<node>=<pages>` ) in numa_maps.
+fn parse_numa_maps(s: &str) -> Vec<(u32, u64)> {
+    let mut pages = HashMap::new();
+    for field in s.split_whitespace() {
+        let node = field
+            .strip_prefix('N')
+            .and_then(|x| x.split_once('='))
+            .and_then(|(node, n)| Some((node.parse::<u32>().ok()?, n.parse::<u64>().ok()?)));
+        if let Some((node, n)) = node {
+            *pages.entry(node).or_insert(0) += n;
+        }
+    }
+    let mut ret: Vec<_> = pages.into_iter().collect();
+    ret.sort();
+    ret
+}
+
+pub fn open_process(pid: i32) -> ProcResult<Process> {
+    Process::new_with_root(procfs_root().join(pid.to_string()))
+}
+
+/// Reads a system-wide file under procfs root (ex. `stat`, `meminfo`).
+pub fn read_procfs<T: FromRead>(path: &str) -> ProcResult<T> {
+    T::from_file(procfs_root().join(path))
+}
+
+/// Reads a system-wide file which depends on system info under procfs root (ex. `net/tcp`).
+pub fn read_procfs_si<T: FromReadSI>(path: &str) -> ProcResult<T> {
+    T::from_file(procfs_root().join(path), procfs::current_system_info())
+}
+
+pub fn boot_time() -> ProcResult<DateTime<Local>> {
+    let stat: KernelStats = read_procfs_si("stat")?;
+    Local
+        .timestamp_opt(stat.btime as i64, 0)
+        .single()
+        .ok_or_else(|| ProcError::Other(String::from("invalid boot time")))
+}
+
+/// Returns seconds since boot, which is consistent with `starttime` of processes in captured snapshots.
+pub fn uptime() -> ProcResult<f64> {
+    let uptime: Uptime = read_procfs("uptime")?;
+    Ok(uptime.uptime)
+}
+
+/// Returns true if self runs inside a container.
+pub fn in_container() -> bool {
+    Path::new("/.dockerenv").exists()
+        || Path::new("/run/.containerenv").exists()
+        || fs::read_to_string("/proc/1/cgroup")
+            .map(|x| x.contains("/docker") || x.contains("/kubepods") || x.contains("/libpod"))
+            .unwrap_or(false)
+}
+
+/// Returns PID of self in the PID namespace of procfs root.
+///
+/// If procfs root is not the one of self (ex. procs runs inside a container with `--host-proc`),
+/// self is searched by matching the PID namespace and the innermost PID of `NSpid`.
+pub fn self_pid() -> Option<i32> {
+    let pid = std::process::id() as i32;
+    if is_local_procfs() {
+        return Some(pid);
+    }
+    let root = procfs_root();
+
+    let self_ns = fs::read_link("/proc/self/ns/pid").ok()?;
+    for proc in procfs::process::all_processes_with_root(&root)
+        .ok()?
+        .flatten()
+    {
+        let nspid = proc.status().ok().and_then(|x| x.nspid);
+        if nspid.as_ref().and_then(|x| x.last()) == Some(&pid)
+            && fs::read_link(root.join(proc.pid().to_string()).join("ns/pid")).ok()
+                == Some(self_ns.clone())
+        {
+            return Some(proc.pid());
+        }
+    }
+    None
+}
+
+/// Returns the count of wakeups of the process.
+///
+/// The count is the sum of voluntary context switches of all threads,
+/// because each of them means that a thread slept and was woken up.
+pub fn wakeup_count(pid: i32) -> Option<u64> {
+    let task_dir = procfs_root().join(pid.to_string()).join("task");
+    let mut ret = None;
+    for entry in fs::read_dir(task_dir).ok()?.flatten() {
+        if let Ok(status) = fs::read_to_string(entry.path().join("status")) {
+            let count = status.lines().find_map(|x| {
+                x.strip_prefix("voluntary_ctxt_switches:")
+                    .and_then(|x| x.trim().parse::<u64>().ok())
+            });
+            if let Some(count) = count {
+                ret = Some(ret.unwrap_or(0) + count);
+            }
+        }
+    }
+    ret
+}
+
+/// Counts of wakeups of all processes sampled before collecting processes, and the time of the sample.
+pub struct WakeupSample {
+    pub counts: HashMap<i32, u64>,
+    pub time: Instant,
+}
+
+impl WakeupSample {
+    /// Wakeups per second of the process since the sample.
+    pub fn rate(&self, proc: &ProcessInfo) -> Option<f64> {
+        let elapsed = self.time.elapsed().as_secs_f64();
+        match (self.counts.get(&proc.pid), proc.curr_proc.wakeup_count()) {
+            (Some(prev), Some(curr)) if elapsed > 0.0 => {
+                Some(curr.saturating_sub(*prev) as f64 / elapsed)
+            }
+            _ => None,
+        }
+    }
+}
+
+static WAKEUP_SAMPLE: Mutex<Option<Arc<WakeupSample>>> = Mutex::new(None);
+
+/// Returns the count of wakeups of all processes.
+///
+/// The sample is shared by columns of a view until `finish_wakeup_sample` is called,
+/// because reading `status` of all threads is slow.
+pub fn wakeup_sample() -> Arc<WakeupSample> {
+    let mut sample = WAKEUP_SAMPLE.lock().unwrap_or_else(|x| x.into_inner());
+    sample
+        .get_or_insert_with(|| {
+            let mut counts = HashMap::new();
+            if let Ok(entries) = fs::read_dir(procfs_root()) {
+                for entry in entries.flatten() {
+                    if let Ok(pid) = entry.file_name().to_string_lossy().parse::<i32>() {
+                        if let Some(count) = wakeup_count(pid) {
+                            counts.insert(pid, count);
+                        }
+                    }
+                }
+            }
+            Arc::new(WakeupSample {
+                counts,
+                time: Instant::now(),
+            })
+        })
+        .clone()
+}
+
+/// Makes columns of the next view take a new sample.
+pub fn finish_wakeup_sample() {
+    *WAKEUP_SAMPLE.lock().unwrap_or_else(|x| x.into_inner()) = None;
+}
+
+/// Reads `fdinfo` of the file descriptor of the process.
+pub fn read_fdinfo(pid: i32, fd: i32) -> Option<String> {
+    fs::read_to_string(
+        procfs_root()
+            .join(pid.to_string())
+            .join("fdinfo")
+            .join(fd.to_string()),
+    )
+    .ok()
+}
+
+/// Sources which are read on demand and shared between columns.
+///
+/// stat and status are read by `collect_proc` already, and the others are read once
+/// at the first access, so enabling many columns doesn't multiply reads of the same files.
+#[derive(Default)]
+pub struct ProcessCache {
+    cmdline: OnceCell<Option<Vec<String>>>,
+    cgroups: OnceCell<Option<Vec<ProcessCGroup>>>,
+    fd: OnceCell<Option<Vec<FDInfo>>>,
+    smaps_rollup: OnceCell<Option<HashMap<String, u64>>>,
+    wakeup_count: OnceCell<Option<u64>>,
+}
+
 pub enum ProcessTask {
     Process {
         stat: Stat,
         owner: u32,
         proc: Process,
+        cache: ProcessCache,
     },
     Task {
         stat: Stat,
@@ -27,25 +451,194 @@ impl ProcessTask {
 
     pub fn cmdline(&self) -> Result<Vec<String>, ProcError> {
         match self {
-            ProcessTask::Process { proc: x, .. } => x.cmdline(),
+            ProcessTask::Process { proc: x, cache, .. } => cache
+                .cmdline
+                .get_or_init(|| x.cmdline().ok())
+                .clone()
+                .ok_or_else(|| ProcError::Other("failed to read cmdline".to_string())),
             _ => Err(ProcError::Other("not supported".to_string())),
         }
     }
 
     pub fn cgroups(&self) -> Result<Vec<ProcessCGroup>, ProcError> {
         match self {
-            ProcessTask::Process { proc: x, .. } => x.cgroups().map(|x| x.0),
+            ProcessTask::Process { proc: x, cache, .. } => cache
+                .cgroups
+                .get_or_init(|| x.cgroups().map(|x| x.0).ok())
+                .clone()
+                .ok_or_else(|| ProcError::Other("failed to read cgroup".to_string())),
+            _ => Err(ProcError::Other("not supported".to_string())),
+        }
+    }
+
+    /// Returns the directory of cgroup v2 hierarchy which the process belongs to.
+    pub fn cgroup2_dir(&self) -> Option<PathBuf> {
+        let cgroups = self.cgroups().ok()?;
+        let cgroup = cgroups
+            .iter()
+            .find(|x| x.hierarchy == 0 && x.controllers.is_empty())?;
+        let mount = CGROUP2_MOUNT.as_ref()?;
+        Some(mount.join(cgroup.pathname.trim_start_matches('/')))
+    }
+
+    pub fn cwd(&self) -> Result<PathBuf, ProcError> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => x.cwd(),
+            _ => Err(ProcError::Other("not supported".to_string())),
+        }
+    }
+
+    pub fn root(&self) -> Result<PathBuf, ProcError> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => x.root(),
+            _ => Err(ProcError::Other("not supported".to_string())),
+        }
+    }
+
+    /// Opens the root directory through `root` link to identify it across mount namespaces.
+    pub fn root_file(&self) -> Result<fs::File, ProcError> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => x.open_relative("root"),
+            _ => Err(ProcError::Other("not supported".to_string())),
+        }
+    }
+
+    /// Reads `/.flatpak-info` in the root directory, which is placed by Flatpak in its sandboxes.
+    pub fn flatpak_info(&self) -> Option<String> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => {
+                let mut s = String::new();
+                x.open_relative("root/.flatpak-info")
+                    .ok()?
+                    .read_to_string(&mut s)
+                    .ok()?;
+                Some(s)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn exe(&self) -> Result<PathBuf, ProcError> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => x.exe(),
+            _ => Err(ProcError::Other("not supported".to_string())),
+        }
+    }
+
+    /// Owner of entries in `/proc/<pid>`, which is root instead of the effective UID if the process is not dumpable.
+    pub fn dump_owner(&self) -> Option<u32> {
+        use std::os::unix::fs::MetadataExt;
+        match self {
+            ProcessTask::Process { proc: x, .. } => x
+                .open_relative("stat")
+                .ok()?
+                .metadata()
+                .ok()
+                .map(|x| x.uid()),
+            _ => None,
+        }
+    }
+
+    pub fn limits(&self) -> Result<Limits, ProcError> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => x.limits(),
+            _ => Err(ProcError::Other("not supported".to_string())),
+        }
+    }
+
+    /// Opens the executable through `exe` link, which is readable even if the file is deleted.
+    pub fn exe_file(&self) -> Result<fs::File, ProcError> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => x.open_relative("exe"),
             _ => Err(ProcError::Other("not supported".to_string())),
         }
     }
 
     pub fn fd(&self) -> Result<Vec<FDInfo>, ProcError> {
         match self {
-            ProcessTask::Process { proc: x, .. } => x.fd()?.collect(),
+            ProcessTask::Process { proc: x, cache, .. } => cache
+                .fd
+                .get_or_init(|| x.fd().and_then(|x| x.collect()).ok())
+                .clone()
+                .ok_or_else(|| ProcError::Other("failed to read fd".to_string())),
+            _ => Err(ProcError::Other("not supported".to_string())),
+        }
+    }
+
+    /// Counts open file descriptors without reading their targets, unless they are already read.
+    pub fn fd_count(&self) -> Result<usize, ProcError> {
+        match self {
+            ProcessTask::Process { proc: x, cache, .. } => {
+                if let Some(Some(fds)) = cache.fd.get() {
+                    Ok(fds.len())
+                } else {
+                    x.fd_count()
+                }
+            }
+            _ => Err(ProcError::Other("not supported".to_string())),
+        }
+    }
+
+    pub fn maps(&self) -> Result<Vec<MemoryMap>, ProcError> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => x.maps().map(|x| x.0),
             _ => Err(ProcError::Other("not supported".to_string())),
         }
     }
 
+    /// Returns the count of wakeups of the process ( see `wakeup_count` ), which is shared by columns.
+    pub fn wakeup_count(&self) -> Option<u64> {
+        match self {
+            ProcessTask::Process { proc: x, cache, .. } => {
+                *cache.wakeup_count.get_or_init(|| wakeup_count(x.pid()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Reads smaps_rollup, which is slow because the kernel walks all pages of the process.
+    fn smaps_rollup(&self) -> Option<&HashMap<String, u64>> {
+        match self {
+            ProcessTask::Process { proc: x, cache, .. } => cache
+                .smaps_rollup
+                .get_or_init(|| {
+                    let rollup = x.smaps_rollup().ok()?;
+                    let map = rollup.memory_map_rollup.0.into_iter().next()?;
+                    Some(map.extension.map)
+                })
+                .as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns proportional set size from smaps_rollup.
+    pub fn pss(&self) -> Option<u64> {
+        self.smaps_rollup()?.get("Pss").copied()
+    }
+
+    /// Returns unique set size ( private pages ) from smaps_rollup.
+    pub fn uss(&self) -> Option<u64> {
+        let rollup = self.smaps_rollup()?;
+        Some(rollup.get("Private_Clean")? + rollup.get("Private_Dirty")?)
+    }
+
+    /// Returns NUMA nodes which memory of the process is allocated on, and the number of pages on each node.
+    ///
+    /// numa_maps is slow like smaps_rollup because the kernel walks all pages of the process.
+    pub fn numa_pages(&self) -> Option<Vec<(u32, u64)>> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => {
+                let mut s = String::new();
+                x.open_relative("numa_maps")
+                    .ok()?
+                    .read_to_string(&mut s)
+                    .ok()?;
+                Some(parse_numa_maps(&s))
+            }
+            _ => None,
+        }
+    }
+
     pub fn loginuid(&self) -> Result<u32, ProcError> {
         match self {
             ProcessTask::Process { proc: x, .. } => x.loginuid(),
@@ -66,6 +659,40 @@ impl ProcessTask {
             _ => Err(ProcError::Other("not supported".to_string())),
         }
     }
+
+    /// Gets the inode number of the namespace ( ex. `net` ), which is shared by processes in the same namespace.
+    pub fn ns_inode(&self, ns: &str) -> Option<u64> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => {
+                let path = procfs_root().join(x.pid().to_string()).join("ns").join(ns);
+                // The link is formatted as `net:[4026531840]`
+                let link = fs::read_link(path).ok()?;
+                let link = link.to_string_lossy();
+                link.split_once(":[")?.1.strip_suffix(']')?.parse().ok()
+            }
+            _ => None,
+        }
+    }
+
+    pub fn oom_score(&self) -> Result<u32, ProcError> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => x.oom_score(),
+            _ => Err(ProcError::Other("not supported".to_string())),
+        }
+    }
+
+    pub fn oom_score_adj(&self) -> Result<i32, ProcError> {
+        match self {
+            ProcessTask::Process { proc: x, .. } => {
+                let mut adj = String::new();
+                x.open_relative("oom_score_adj")?.read_to_string(&mut adj)?;
+                adj.trim()
+                    .parse()
+                    .map_err(|_| ProcError::Other("invalid oom_score_adj".to_string()))
+            }
+            _ => Err(ProcError::Other("not supported".to_string())),
+        }
+    }
 }
 
 pub struct ProcessInfo {
@@ -79,20 +706,122 @@ pub struct ProcessInfo {
     pub interval: Duration,
 }
 
+/// Source of the two snapshots of processes compared by `collect_proc`.
+///
+/// Processes are read through this, so a captured copy of procfs ( ex. test fixtures ) can be used
+/// instead of the live system.
+///
+/// Only procfs is abstracted for now, so collectors of macOS, FreeBSD and Windows still read the live system.
+pub trait ProcSource {
+    /// Returns procfs root of the first snapshot.
+    fn first(&self) -> PathBuf;
+    /// Waits until the second snapshot, and returns procfs root of it.
+    fn second(&self) -> PathBuf;
+    /// Returns the interval between snapshots of a process whose first snapshot was taken at `since`.
+    fn interval(&self, since: Instant) -> Duration;
+}
+
+/// procfs of the running system, which is read twice at the interval
+pub struct LiveProcfs {
+    pub interval: Duration,
+}
+
+impl ProcSource for LiveProcfs {
+    fn first(&self) -> PathBuf {
+        procfs_root()
+    }
+
+    fn second(&self) -> PathBuf {
+        thread::sleep(self.interval);
+        procfs_root()
+    }
+
+    fn interval(&self, since: Instant) -> Duration {
+        Instant::now() - since
+    }
+}
+
+/// A captured copy of procfs.
+///
+/// If the directory has `prev` and `curr` directories, they are the snapshots taken at the interval.
+/// Otherwise the directory is used as both of the snapshots, so all rates are zero.
+pub struct CapturedProcfs {
+    prev: PathBuf,
+    curr: PathBuf,
+    interval: Duration,
+}
+
+impl CapturedProcfs {
+    pub fn new(path: &Path, interval: Duration) -> Self {
+        let (prev, curr) = (path.join("prev"), path.join("curr"));
+        if prev.is_dir() && curr.is_dir() {
+            Self {
+                prev,
+                curr,
+                interval,
+            }
+        } else {
+            Self {
+                prev: path.to_path_buf(),
+                curr: path.to_path_buf(),
+                interval,
+            }
+        }
+    }
+
+    /// Returns procfs root which columns should read.
+    pub fn root(&self) -> &Path {
+        &self.curr
+    }
+}
+
+impl ProcSource for CapturedProcfs {
+    fn first(&self) -> PathBuf {
+        self.prev.clone()
+    }
+
+    fn second(&self) -> PathBuf {
+        self.curr.clone()
+    }
+
+    fn interval(&self, _since: Instant) -> Duration {
+        self.interval
+    }
+}
+
+/// Returns the fixture in `tests/fixtures`, whose snapshots are taken at 1 second.
+///
+/// CPU times of the fixture assume 100 ticks per second.
+#[cfg(test)]
+pub fn fixture_procfs() -> CapturedProcfs {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/proc");
+    CapturedProcfs::new(&path, Duration::from_secs(1))
+}
+
 pub fn collect_proc(
     interval: Duration,
     with_thread: bool,
     show_kthreads: bool,
+) -> Vec<ProcessInfo> {
+    collect_proc_from(&LiveProcfs { interval }, with_thread, show_kthreads)
+}
+
+pub fn collect_proc_from(
+    source: &dyn ProcSource,
+    with_thread: bool,
+    show_kthreads: bool,
 ) -> Vec<ProcessInfo> {
     let mut base_procs = Vec::new();
     let mut base_tasks = HashMap::new();
     let mut ret = Vec::new();
 
-    if let Ok(all_proc) = procfs::process::all_processes() {
+    // Both snapshots are taken from this enumeration, so processes which appear after it are not shown,
+    // and processes which disappear or are replaced by PID reuse before the second snapshot are dropped.
+    if let Ok(all_proc) = procfs::process::all_processes_with_root(source.first()) {
         for proc in all_proc.flatten() {
             if let Ok(stat) = proc.stat() {
-                let io = proc.io().ok();
                 let time = Instant::now();
+                let io = proc.io().ok();
                 if with_thread {
                     if let Ok(iter) = proc.tasks() {
                         collect_task(iter, &mut base_tasks);
@@ -103,10 +832,11 @@ pub fn collect_proc(
         }
     }
 
-    thread::sleep(interval);
+    let root = source.second();
+    let local = root == Path::new("/proc");
 
     for (pid, prev_stat, prev_io, prev_time) in base_procs {
-        let curr_proc = if let Ok(proc) = Process::new(pid) {
+        let curr_proc = if let Ok(proc) = Process::new_with_root(root.join(pid.to_string())) {
             proc
         } else {
             continue;
@@ -117,17 +847,28 @@ pub fn collect_proc(
         } else {
             continue;
         };
+        let interval = source.interval(prev_time);
 
-        let curr_owner = if let Ok(owner) = curr_proc.uid() {
-            owner
-        } else {
+        // Counters of another process can't be compared with the previous snapshot
+        if curr_stat.starttime != prev_stat.starttime {
             continue;
-        };
+        }
 
         let curr_io = curr_proc.io().ok();
         let curr_status = curr_proc.status().ok();
-        let curr_time = Instant::now();
-        let interval = curr_time - prev_time;
+
+        // The owner of a copied /proc tree is not the owner of the process,
+        // so effective UID is used if procfs root is not the default.
+        let curr_owner = match (&curr_status, local) {
+            (Some(status), false) => status.euid,
+            _ => {
+                if let Ok(owner) = curr_proc.uid() {
+                    owner
+                } else {
+                    continue;
+                }
+            }
+        };
         let ppid = curr_stat.ppid;
 
         if !show_kthreads && (ppid == 2 || pid == 2) {
@@ -145,6 +886,7 @@ pub fn collect_proc(
             stat: curr_stat,
             owner: curr_owner,
             proc: curr_proc,
+            cache: ProcessCache::default(),
         };
 
         let proc = ProcessInfo {
@@ -161,7 +903,10 @@ pub fn collect_proc(
         ret.push(proc);
 
         for (tid, (pid, curr_stat, curr_status, curr_io)) in curr_tasks {
-            if let Some((_, prev_stat, _, prev_io)) = base_tasks.remove(&tid) {
+            if let Some((_, prev_stat, _, prev_io)) = base_tasks
+                .remove(&tid)
+                .filter(|(_, x, _, _)| x.starttime == curr_stat.starttime)
+            {
                 let proc = ProcessInfo {
                     pid: tid,
                     ppid: pid,
@@ -203,3 +948,57 @@ fn collect_task(iter: TasksIter, map: &mut HashMap<i32, (i32, Stat, Option<Statu
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signal_names() {
+        assert_eq!(signal_names(0), Vec::<String>::new());
+        assert_eq!(signal_names(0x4002), vec!["SIGINT", "SIGTERM"]);
+        assert_eq!(signal_names(1 << 33), vec!["SIG34"]);
+        assert_eq!(abbr_signal_names(&signal_names(0x4002)), "INT,TERM");
+        assert_eq!(abbr_signal_names(&signal_names(0x1f)), "HUP,INT,QUIT,+2");
+    }
+
+    #[test]
+    fn test_parse_cpulist() {
+        assert_eq!(parse_cpulist("0-3,8,10-11\n"), vec![0, 1, 2, 3, 8, 10, 11]);
+        assert_eq!(parse_cpulist("\n"), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_parse_numa_maps() {
+        let s = "\
+7f0000000000 default file=/usr/lib/libc.so.6 mapped=40 mapmax=90 N0=30 N1=10 kernelpagesize_kB=4
+7f0000100000 bind:1 anon=200 dirty=200 active=0 N1=200 kernelpagesize_kB=4
+7ffc00000000 default stack anon=3 dirty=3 N0=3 kernelpagesize_kB=4
+";
+        assert_eq!(parse_numa_maps(s), vec![(0, 33), (1, 210)]);
+    }
+
+    #[test]
+    fn test_collect_proc_from() {
+        let procs = collect_proc_from(&fixture_procfs(), true, true);
+        let mut pids: Vec<_> = procs.iter().map(|x| (x.pid, x.ppid)).collect();
+        pids.sort();
+        // 300 exited, 400 started after the first snapshot, and 500 is reused by another process
+        assert_eq!(pids, vec![(1, 0), (2, 0), (100, 1), (200, 100), (201, 200)]);
+
+        let worker = procs.iter().find(|x| x.pid == 200).unwrap();
+        assert_eq!(worker.interval, Duration::from_secs(1));
+        assert_eq!(worker.prev_stat.utime, 1000);
+        assert_eq!(worker.curr_proc.stat().utime, 1100);
+        assert_eq!(worker.curr_proc.owner(), 1000);
+        assert_eq!(
+            worker.curr_proc.cmdline().unwrap(),
+            vec!["python3", "worker.py", "--jobs", "2"]
+        );
+
+        let procs = collect_proc_from(&fixture_procfs(), false, false);
+        let pids: Vec<_> = procs.iter().map(|x| x.pid).collect();
+        assert!(!pids.contains(&2));
+        assert!(!pids.contains(&201));
+    }
+}
@@ -54,6 +54,12 @@ pub fn collect_proc(
             clone_task_all_info(&prev_task)
         };
 
+        // Counters of another process can't be compared with the previous snapshot
+        let start = |x: &TaskAllInfo| (x.pbsd.pbi_start_tvsec, x.pbsd.pbi_start_tvusec);
+        if start(&curr_task) != start(&prev_task) {
+            continue;
+        }
+
         let curr_path = get_path_info(pid, arg_max);
 
         let threadids = listpidinfo::<ListThreads>(pid, curr_task.ptinfo.pti_threadnum as usize);
@@ -0,0 +1,292 @@
+use crate::config::*;
+use crate::view::View;
+use crate::Opt;
+use anyhow::{bail, Error};
+use serde_json::{json, Value};
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+const PROCESS_NOT_FOUND: i64 = 1;
+
+/// Default interval of `subscribe` in milliseconds
+const DEFAULT_SUBSCRIBE_INTERVAL: u64 = 1000;
+
+/// Minimum interval of `subscribe` in milliseconds, so that a client can't make the server collect processes
+/// without pause
+const MIN_SUBSCRIBE_INTERVAL: u64 = 100;
+
+/// Serves JSON-RPC 2.0 on a UNIX domain socket.
+///
+/// Each line of the connection is a request, and each response is written as a line.
+/// Methods:
+///   - `list`      : processes matched by `keyword` ( with `and`/`or`/`nand`/`nor` ) sorted by `sorta`/`sortd`
+///   - `get`       : the process of `pid`
+///   - `subscribe` : `update` notifications of `list` every `interval` milliseconds until the connection is closed
+pub fn run_serve(opt: &Opt, config: &Config, path: &Path) -> Result<(), Error> {
+    if path.exists() {
+        // A socket left by a killed server is removed, but a running server is kept
+        if UnixStream::connect(path).is_ok() {
+            bail!("{} is used by another server", path.display());
+        }
+        fs::remove_file(path)?;
+    }
+    // Processes of the server user are exposed through the socket, so it's created with mode 0600 by umask
+    // instead of changing the mode after binding, when other users could connect
+    let mask = unsafe { libc::umask(0o177) };
+    let listener = UnixListener::bind(path);
+    unsafe { libc::umask(mask) };
+    let listener = listener?;
+    if opt.debug {
+        eprintln!("Info: listening on {}", path.display());
+    }
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(x) => x,
+            Err(_) => continue,
+        };
+        let opt = opt.clone();
+        let config = config.clone();
+        thread::spawn(move || {
+            let _ = serve_connection(stream, &opt, &config);
+        });
+    }
+    Ok(())
+}
+
+fn serve_connection(stream: UnixStream, opt: &Opt, config: &Config) -> Result<(), Error> {
+    let mut writer = stream.try_clone()?;
+    let reader = BufReader::new(stream);
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let req: Value = match serde_json::from_str(&line) {
+            Ok(x) => x,
+            Err(e) => {
+                send(&mut writer, error(Value::Null, PARSE_ERROR, &e.to_string()))?;
+                continue;
+            }
+        };
+        let id = req.get("id").cloned().unwrap_or(Value::Null);
+        let method = if let Some(x) = req["method"].as_str() {
+            x
+        } else {
+            send(&mut writer, error(id, INVALID_REQUEST, "method is missing"))?;
+            continue;
+        };
+        let params = &req["params"];
+
+        let res = match method {
+            "list" => list(opt, config, params),
+            "get" => get(opt, config, params),
+            "subscribe" => {
+                let interval = params["interval"]
+                    .as_u64()
+                    .unwrap_or(DEFAULT_SUBSCRIBE_INTERVAL);
+                if interval < MIN_SUBSCRIBE_INTERVAL {
+                    let msg = format!("interval must be {MIN_SUBSCRIBE_INTERVAL} or more");
+                    send(&mut writer, error(id, INVALID_PARAMS, &msg))?;
+                    continue;
+                }
+                let mut opt = match request_opt(opt, params) {
+                    Ok(x) => x,
+                    Err(e) => {
+                        send(&mut writer, error(id, INVALID_PARAMS, &e.to_string()))?;
+                        continue;
+                    }
+                };
+                send(
+                    &mut writer,
+                    json!({"jsonrpc": "2.0", "id": id, "result": true}),
+                )?;
+                // The connection is occupied by the subscription until a write fails
+                let mut prev = None;
+                loop {
+                    // The view is renewed like watch mode so that columns keep samples across updates
+                    let update = match View::renew(&mut opt, config, prev.take()) {
+                        Ok(mut view) => {
+                            let x = rows(&mut view, &opt, config, None);
+                            prev = Some(view);
+                            json!({"jsonrpc": "2.0", "method": "update", "params": x})
+                        }
+                        Err(e) => error(Value::Null, INTERNAL_ERROR, &e.to_string()),
+                    };
+                    send(&mut writer, update)?;
+                    thread::sleep(Duration::from_millis(interval));
+                }
+            }
+            _ => Err((METHOD_NOT_FOUND, format!("unknown method: {method}"))),
+        };
+
+        let res = match res {
+            Ok(x) => json!({"jsonrpc": "2.0", "id": id, "result": x}),
+            Err((code, msg)) => error(id, code, &msg),
+        };
+        send(&mut writer, res)?;
+    }
+    Ok(())
+}
+
+fn list(opt: &Opt, config: &Config, params: &Value) -> Result<Value, (i64, String)> {
+    let mut opt = request_opt(opt, params).map_err(|e| (INVALID_PARAMS, e.to_string()))?;
+    processes(&mut opt, config, None).map_err(|e| (INTERNAL_ERROR, e.to_string()))
+}
+
+fn get(opt: &Opt, config: &Config, params: &Value) -> Result<Value, (i64, String)> {
+    let pid = if let Some(x) = params["pid"].as_i64() {
+        i32::try_from(x).map_err(|_| (INVALID_PARAMS, format!("invalid pid: {x}")))?
+    } else {
+        return Err((INVALID_PARAMS, String::from("pid is missing")));
+    };
+    let mut opt = opt.clone();
+    opt.keyword = vec![];
+
+    let mut ret =
+        processes(&mut opt, config, Some(pid)).map_err(|e| (INTERNAL_ERROR, e.to_string()))?;
+    match ret.as_array_mut().and_then(|x| x.pop()) {
+        Some(x) => Ok(x),
+        None => Err((PROCESS_NOT_FOUND, format!("process not found: {pid}"))),
+    }
+}
+
+/// Builds options of a request from options of the server.
+fn request_opt(opt: &Opt, params: &Value) -> Result<Opt, Error> {
+    let mut opt = opt.clone();
+    opt.keyword = match &params["keyword"] {
+        Value::Null => vec![],
+        Value::String(x) => vec![x.clone()],
+        Value::Array(x) => x
+            .iter()
+            .map(|x| x.as_str().map(String::from))
+            .collect::<Option<_>>()
+            .ok_or_else(|| anyhow::anyhow!("keyword must be strings"))?,
+        _ => bail!("keyword must be strings"),
+    };
+    opt.and = params["and"].as_bool().unwrap_or(false);
+    opt.or = params["or"].as_bool().unwrap_or(false);
+    opt.nand = params["nand"].as_bool().unwrap_or(false);
+    opt.nor = params["nor"].as_bool().unwrap_or(false);
+    opt.sorta = params["sorta"].as_str().map(String::from);
+    opt.sortd = params["sortd"].as_str().map(String::from);
+    if opt.sorta.is_some() && opt.sortd.is_some() {
+        bail!("sorta and sortd can't be used together");
+    }
+    Ok(opt)
+}
+
+/// Collects processes, and returns contents of each column without padding.
+fn processes(opt: &mut Opt, config: &Config, pid: Option<i32>) -> Result<Value, Error> {
+    let mut view = View::new(opt, config, false)?;
+    Ok(rows(&mut view, opt, config, pid))
+}
+
+fn rows(view: &mut View, opt: &Opt, config: &Config, pid: Option<i32>) -> Value {
+    view.filter(opt, config);
+    if let Some(pid) = pid {
+        view.visible_pids.retain(|x| *x == pid);
+    }
+
+    view.fit_width(config);
+
+    let ret = view
+        .visible_pids
+        .iter()
+        .map(|pid| view.row_json(*pid))
+        .collect();
+    Value::Array(ret)
+}
+
+fn error(id: Value, code: i64, message: &str) -> Value {
+    json!({"jsonrpc": "2.0", "id": id, "error": {"code": code, "message": message}})
+}
+
+fn send(writer: &mut UnixStream, msg: Value) -> Result<(), Error> {
+    writeln!(writer, "{msg}")?;
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CONFIG_DEFAULT;
+    use clap::Parser;
+
+    #[test]
+    fn test_request_opt() {
+        let opt = Opt::parse_from(["procs", "--or", "bash"]);
+        let ret = request_opt(
+            &opt,
+            &json!({"keyword": ["sshd", "nginx"], "and": true, "sortd": "cpu"}),
+        )
+        .unwrap();
+        assert_eq!(ret.keyword, ["sshd", "nginx"]);
+        assert!(ret.and);
+        assert!(!ret.or);
+        assert_eq!(ret.sortd.as_deref(), Some("cpu"));
+
+        let ret = request_opt(&opt, &json!({"keyword": "sshd"})).unwrap();
+        assert_eq!(ret.keyword, ["sshd"]);
+        let ret = request_opt(&opt, &json!(null)).unwrap();
+        assert!(ret.keyword.is_empty());
+
+        assert!(request_opt(&opt, &json!({"keyword": 1})).is_err());
+        assert!(request_opt(&opt, &json!({"keyword": ["sshd", 1]})).is_err());
+        assert!(request_opt(&opt, &json!({"sorta": "cpu", "sortd": "mem"})).is_err());
+    }
+
+    #[test]
+    fn test_error_codes() {
+        let opt = Opt::parse_from(["procs"]);
+        let config: Config = toml::from_str(CONFIG_DEFAULT).unwrap();
+        let (mut client, server) = UnixStream::pair().unwrap();
+        let handle = thread::spawn(move || serve_connection(server, &opt, &config));
+
+        let requests = [
+            "{",
+            r#"{"jsonrpc": "2.0", "id": 1}"#,
+            r#"{"jsonrpc": "2.0", "id": 2, "method": "kill"}"#,
+            r#"{"jsonrpc": "2.0", "id": 3, "method": "get", "params": {}}"#,
+            r#"{"jsonrpc": "2.0", "id": 4, "method": "list", "params": {"keyword": 1}}"#,
+            r#"{"jsonrpc": "2.0", "id": 5, "method": "get", "params": {"pid": 4294967297}}"#,
+            r#"{"jsonrpc": "2.0", "id": 6, "method": "subscribe", "params": {"interval": 0}}"#,
+        ];
+        for x in requests {
+            writeln!(client, "{x}").unwrap();
+        }
+        client.shutdown(std::net::Shutdown::Write).unwrap();
+
+        let codes: Vec<_> = BufReader::new(client)
+            .lines()
+            .map(|x| {
+                let res: Value = serde_json::from_str(&x.unwrap()).unwrap();
+                (res["id"].clone(), res["error"]["code"].as_i64().unwrap())
+            })
+            .collect();
+        assert_eq!(
+            codes,
+            [
+                (Value::Null, PARSE_ERROR),
+                (json!(1), INVALID_REQUEST),
+                (json!(2), METHOD_NOT_FOUND),
+                (json!(3), INVALID_PARAMS),
+                (json!(4), INVALID_PARAMS),
+                (json!(5), INVALID_PARAMS),
+                (json!(6), INVALID_PARAMS),
+            ]
+        );
+        assert!(handle.join().unwrap().is_ok());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::columns::ConfigColumnKind;
+use crate::config::*;
+use crate::util::{column_kind_name, insert_metric_columns, render_template, snake_case};
+use crate::view::View;
+use crate::Opt;
+use anyhow::{anyhow, Error};
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+
+/// Maximum payload of a packet to avoid IP fragmentation on typical networks
+const MAX_PACKET: usize = 1432;
+
+/// Sends metrics of processes to a StatsD server as gauges.
+///
+/// Metric names are built from the template in `[statsd]` section ( ex. `procs.{command}.{pid}.{kind}` ).
+pub struct StatsdEmitter {
+    metrics: Vec<ConfigColumnKind>,
+    template: String,
+    socket: UdpSocket,
+}
+
+impl StatsdEmitter {
+    /// Columns of metrics which are not in the configuration are inserted to slots like `--insert`.
+    pub fn new(addr: &str, opt: &mut Opt, config: &Config) -> Result<Self, Error> {
+        let metrics = insert_metric_columns(opt, config, &config.statsd.metrics)?;
+
+        // Unknown placeholders are reported at start instead of each update
+        let template = config.statsd.template.clone();
+        render(&template, |x| {
+            matches!(x, "kind" | "pid" | "command" | "user").then(String::new)
+        })?;
+
+        let addr: SocketAddr = addr
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| anyhow!("failed to resolve --statsd address: {addr}"))?;
+        let local = if addr.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(local)?;
+        socket.connect(addr)?;
+
+        Ok(Self {
+            metrics,
+            template,
+            socket,
+        })
+    }
+
+    /// Emits visible processes of the view, which must be called before `View::adjust` truncates contents.
+    pub fn emit(&self, view: &mut View, config: &Config) {
+        view.fit_width(config);
+
+        let find = |kind: &str| {
+            view.columns
+                .iter()
+                .find(|c| column_kind_name(&c.kind) == kind)
+        };
+        let command = find("Command");
+        let user = find("User");
+        let metrics: Vec<_> = self
+            .metrics
+            .iter()
+            .filter_map(|kind| view.columns.iter().find(|c| c.kind == *kind))
+            .collect();
+
+        let mut lines = Vec::new();
+        for pid in &view.visible_pids {
+            let content = |c: Option<&ColumnInfo>| {
+                c.and_then(|c| c.column.display_content(*pid, &ConfigColumnAlign::Left))
+                    .map(|x| x.trim().to_string())
+                    .unwrap_or_default()
+            };
+            // Arguments are dropped because metric names should be stable
+            let command = content(command);
+            let command = command.split_whitespace().next().unwrap_or_default();
+            let command = command.rsplit('/').next().unwrap_or_default();
+            let user = content(user);
+
+            for column in &metrics {
+                let value = if let Some(x) = column.column.raw_value(*pid) {
+                    x
+                } else {
+                    continue;
+                };
+                let kind = snake_case(&column_kind_name(&column.kind));
+                let name = render(&self.template, |x| match x {
+                    "kind" => Some(kind.clone()),
+                    "pid" => Some(pid.to_string()),
+                    "command" => Some(sanitize(command)),
+                    "user" => Some(sanitize(&user)),
+                    _ => None,
+                });
+                if let Ok(name) = name {
+                    lines.push(format!("{name}:{value}|g"));
+                }
+            }
+        }
+
+        // Packets are dropped silently if the server isn't running, as StatsD clients usually do
+        for packet in pack(&lines) {
+            let _ = self.socket.send(packet.as_bytes());
+        }
+    }
+}
+
+/// Expands `{placeholder}` in the template by `lookup`.
+fn render<F: Fn(&str) -> Option<String>>(template: &str, lookup: F) -> Result<String, Error> {
+    render_template(template, |x| {
+        lookup(x).ok_or_else(|| anyhow!("unknown placeholder in statsd template: {{{x}}}"))
+    })
+}
+
+/// Replaces characters which have special meanings in StatsD or Graphite ( ex. `.`, `:`, `|` ).
+fn sanitize(s: &str) -> String {
+    if s.is_empty() {
+        return String::from("unknown");
+    }
+    s.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Joins lines into packets by newline.
+fn pack(lines: &[String]) -> Vec<String> {
+    let mut ret = Vec::new();
+    let mut packet = String::new();
+    for line in lines {
+        if !packet.is_empty() && packet.len() + 1 + line.len() > MAX_PACKET {
+            ret.push(std::mem::take(&mut packet));
+        }
+        if !packet.is_empty() {
+            packet.push('\n');
+        }
+        packet.push_str(line);
+    }
+    if !packet.is_empty() {
+        ret.push(packet);
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let lookup = |x: &str| match x {
+            "command" => Some(sanitize("nginx: worker")),
+            "pid" => Some(String::from("42")),
+            "kind" => Some(snake_case("UsageCpu")),
+            _ => None,
+        };
+        assert_eq!(
+            render("procs.{command}.{pid}.{kind}", lookup).unwrap(),
+            "procs.nginx__worker.42.usage_cpu"
+        );
+        assert!(render("procs.{host}", lookup).is_err());
+        assert!(render("procs.{pid", lookup).is_err());
+    }
+
+    #[test]
+    fn test_pack() {
+        let lines: Vec<_> = (0..200).map(|i| format!("procs.{i}:{i}|g")).collect();
+        let packets = pack(&lines);
+        assert!(packets.len() > 1);
+        assert!(packets.iter().all(|x| x.len() <= MAX_PACKET));
+        assert_eq!(packets.join("\n"), lines.join("\n"));
+    }
+}
@@ -0,0 +1,334 @@
+use crate::process::procfs_root;
+use anyhow::{bail, Context, Error};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy)]
+enum Arg {
+    Int,
+    Hex,
+    Fd,
+    Pid,
+    Clock,
+    FutexOp,
+}
+
+/// Syscall numbers of each architecture, which are built for tests of other architectures too
+#[cfg(any(target_arch = "x86_64", test))]
+static X86_64_SYSCALLS: &[(u64, &str)] = &[
+    (0, "read"),
+    (1, "write"),
+    (3, "close"),
+    (7, "poll"),
+    (8, "lseek"),
+    (9, "mmap"),
+    (11, "munmap"),
+    (16, "ioctl"),
+    (17, "pread64"),
+    (18, "pwrite64"),
+    (19, "readv"),
+    (20, "writev"),
+    (23, "select"),
+    (24, "sched_yield"),
+    (34, "pause"),
+    (35, "nanosleep"),
+    (42, "connect"),
+    (43, "accept"),
+    (44, "sendto"),
+    (45, "recvfrom"),
+    (46, "sendmsg"),
+    (47, "recvmsg"),
+    (61, "wait4"),
+    (65, "semop"),
+    (69, "msgrcv"),
+    (72, "fcntl"),
+    (73, "flock"),
+    (74, "fsync"),
+    (75, "fdatasync"),
+    (128, "rt_sigtimedwait"),
+    (130, "rt_sigsuspend"),
+    (202, "futex"),
+    (208, "io_getevents"),
+    (219, "restart_syscall"),
+    (230, "clock_nanosleep"),
+    (232, "epoll_wait"),
+    (247, "waitid"),
+    (257, "openat"),
+    (270, "pselect6"),
+    (271, "ppoll"),
+    (281, "epoll_pwait"),
+    (288, "accept4"),
+    (299, "recvmmsg"),
+    (307, "sendmmsg"),
+    (318, "getrandom"),
+    (426, "io_uring_enter"),
+    (441, "epoll_pwait2"),
+];
+
+#[cfg(any(target_arch = "aarch64", test))]
+static AARCH64_SYSCALLS: &[(u64, &str)] = &[
+    (4, "io_getevents"),
+    (22, "epoll_pwait"),
+    (25, "fcntl"),
+    (29, "ioctl"),
+    (32, "flock"),
+    (56, "openat"),
+    (57, "close"),
+    (62, "lseek"),
+    (63, "read"),
+    (64, "write"),
+    (65, "readv"),
+    (66, "writev"),
+    (67, "pread64"),
+    (68, "pwrite64"),
+    (72, "pselect6"),
+    (73, "ppoll"),
+    (82, "fsync"),
+    (83, "fdatasync"),
+    (95, "waitid"),
+    (98, "futex"),
+    (101, "nanosleep"),
+    (115, "clock_nanosleep"),
+    (124, "sched_yield"),
+    (128, "restart_syscall"),
+    (133, "rt_sigsuspend"),
+    (137, "rt_sigtimedwait"),
+    (188, "msgrcv"),
+    (193, "semop"),
+    (202, "accept"),
+    (203, "connect"),
+    (206, "sendto"),
+    (207, "recvfrom"),
+    (211, "sendmsg"),
+    (212, "recvmsg"),
+    (215, "munmap"),
+    (222, "mmap"),
+    (242, "accept4"),
+    (243, "recvmmsg"),
+    (260, "wait4"),
+    (269, "sendmmsg"),
+    (278, "getrandom"),
+    (426, "io_uring_enter"),
+    (441, "epoll_pwait2"),
+];
+
+#[cfg(target_arch = "x86_64")]
+static SYSCALL_NAMES: &[(u64, &str)] = X86_64_SYSCALLS;
+#[cfg(target_arch = "aarch64")]
+static SYSCALL_NAMES: &[(u64, &str)] = AARCH64_SYSCALLS;
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+static SYSCALL_NAMES: &[(u64, &str)] = &[];
+
+fn syscall_args(name: &str) -> &'static [Arg] {
+    match name {
+        "close" | "fsync" | "fdatasync" => &[Arg::Fd],
+        "read" | "write" | "readv" | "writev" | "lseek" | "ioctl" | "fcntl" => {
+            &[Arg::Fd, Arg::Hex, Arg::Int]
+        }
+        "flock" => &[Arg::Fd, Arg::Int],
+        "pread64" | "pwrite64" => &[Arg::Fd, Arg::Hex, Arg::Int, Arg::Int],
+        "accept" | "connect" => &[Arg::Fd, Arg::Hex, Arg::Int],
+        "accept4" => &[Arg::Fd, Arg::Hex, Arg::Hex, Arg::Hex],
+        "recvmsg" | "sendmsg" => &[Arg::Fd, Arg::Hex, Arg::Hex],
+        "recvfrom" | "sendto" => &[Arg::Fd, Arg::Hex, Arg::Int, Arg::Hex],
+        "recvmmsg" | "sendmmsg" => &[Arg::Fd, Arg::Hex, Arg::Int, Arg::Hex],
+        "epoll_wait" | "epoll_pwait" | "epoll_pwait2" => &[Arg::Fd, Arg::Hex, Arg::Int, Arg::Int],
+        "io_uring_enter" => &[Arg::Fd, Arg::Int, Arg::Int, Arg::Hex],
+        "openat" => &[Arg::Fd, Arg::Hex, Arg::Hex],
+        "poll" | "ppoll" => &[Arg::Hex, Arg::Int, Arg::Int],
+        "select" | "pselect6" => &[Arg::Int, Arg::Hex, Arg::Hex, Arg::Hex, Arg::Hex],
+        "futex" => &[Arg::Hex, Arg::FutexOp, Arg::Int, Arg::Hex],
+        "nanosleep" => &[Arg::Hex, Arg::Hex],
+        "clock_nanosleep" => &[Arg::Clock, Arg::Int, Arg::Hex, Arg::Hex],
+        "wait4" => &[Arg::Pid, Arg::Hex, Arg::Int, Arg::Hex],
+        "waitid" => &[Arg::Int, Arg::Pid, Arg::Hex, Arg::Int],
+        "rt_sigsuspend" => &[Arg::Hex, Arg::Int],
+        "rt_sigtimedwait" => &[Arg::Hex, Arg::Hex, Arg::Hex, Arg::Int],
+        "msgrcv" => &[Arg::Int, Arg::Hex, Arg::Int, Arg::Int, Arg::Hex],
+        "semop" => &[Arg::Int, Arg::Hex, Arg::Int],
+        "io_getevents" => &[Arg::Hex, Arg::Int, Arg::Int, Arg::Hex, Arg::Hex],
+        "getrandom" => &[Arg::Hex, Arg::Int, Arg::Hex],
+        "mmap" => &[Arg::Hex, Arg::Int, Arg::Hex, Arg::Hex, Arg::Fd, Arg::Hex],
+        "munmap" => &[Arg::Hex, Arg::Int],
+        _ => &[],
+    }
+}
+
+/// Shows the syscall which each thread of `pid` is currently blocked in.
+pub fn run_syscall(pid: i32) -> Result<(), Error> {
+    let task_dir = procfs_root().join(pid.to_string()).join("task");
+    let mut tids: Vec<i32> = fs::read_dir(&task_dir)
+        .context(format!("process {pid} is not found"))?
+        .flatten()
+        .filter_map(|x| x.file_name().to_string_lossy().parse().ok())
+        .collect();
+    tids.sort_unstable();
+    if tids.is_empty() {
+        bail!("process {} is not found", pid);
+    }
+
+    println!("{:>7} {:<16} Syscall", "TID", "Name");
+    for tid in tids {
+        let dir = task_dir.join(tid.to_string());
+        let comm = fs::read_to_string(dir.join("comm")).unwrap_or_default();
+        let syscall = match fs::read_to_string(dir.join("syscall")) {
+            Ok(x) => decode(SYSCALL_NAMES, pid, &x),
+            Err(x) if x.kind() == std::io::ErrorKind::PermissionDenied => {
+                String::from("-permission denied-")
+            }
+            Err(_) => String::from("-not available-"),
+        };
+        println!("{:>7} {:<16} {}", tid, comm.trim_end(), syscall);
+    }
+
+    Ok(())
+}
+
+/// Decodes a line of `/proc/<pid>/task/<tid>/syscall` by the syscall numbers of the architecture.
+fn decode(names: &[(u64, &str)], pid: i32, s: &str) -> String {
+    let fields: Vec<&str> = s.split_whitespace().collect();
+    match fields.first() {
+        Some(&"running") => return String::from("-running-"),
+        Some(&"-1") => return String::from("-blocked outside syscall-"),
+        None => return String::from("-not available-"),
+        _ => (),
+    }
+
+    let nr: u64 = fields[0].parse().unwrap_or(u64::MAX);
+    let args: Vec<u64> = fields
+        .iter()
+        .skip(1)
+        .take(6)
+        .map(|x| u64::from_str_radix(x.trim_start_matches("0x"), 16).unwrap_or(0))
+        .collect();
+
+    let name = names.iter().find(|(n, _)| *n == nr).map(|(_, x)| *x);
+    if let Some(name) = name {
+        let kinds = syscall_args(name);
+        let args: Vec<String> = kinds
+            .iter()
+            .zip(args.iter())
+            .map(|(kind, arg)| decode_arg(pid, *kind, *arg))
+            .collect();
+        format!("{}({})", name, args.join(", "))
+    } else {
+        let args: Vec<String> = args.iter().map(|x| format!("{x:#x}")).collect();
+        format!("syscall_{}({})", nr, args.join(", "))
+    }
+}
+
+fn decode_arg(pid: i32, kind: Arg, arg: u64) -> String {
+    match kind {
+        Arg::Int => format!("{}", arg as i64),
+        Arg::Hex => format!("{arg:#x}"),
+        Arg::Pid => format!("{}", arg as i32),
+        Arg::Fd => {
+            let fd = arg as i32;
+            let path: PathBuf = procfs_root()
+                .join(pid.to_string())
+                .join("fd")
+                .join(fd.to_string());
+            if let Ok(target) = fs::read_link(path) {
+                format!("{}<{}>", fd, target.to_string_lossy())
+            } else {
+                format!("{fd}")
+            }
+        }
+        Arg::Clock => match arg {
+            0 => String::from("CLOCK_REALTIME"),
+            1 => String::from("CLOCK_MONOTONIC"),
+            7 => String::from("CLOCK_BOOTTIME"),
+            x => format!("{x}"),
+        },
+        Arg::FutexOp => {
+            let op = match arg & 0x7f {
+                0 => "FUTEX_WAIT",
+                1 => "FUTEX_WAKE",
+                9 => "FUTEX_WAIT_BITSET",
+                10 => "FUTEX_WAKE_BITSET",
+                6 => "FUTEX_LOCK_PI",
+                11 => "FUTEX_WAIT_REQUEUE_PI",
+                _ => return format!("{arg:#x}"),
+            };
+            if arg & 0x80 != 0 {
+                format!("{op}_PRIVATE")
+            } else {
+                String::from(op)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        // PID 0 has no fd directory, so fds are shown as numbers
+        let cases = [
+            (X86_64_SYSCALLS, "running", "-running-"),
+            (
+                X86_64_SYSCALLS,
+                "-1 0x7ffd1000 0x7f001000",
+                "-blocked outside syscall-",
+            ),
+            (X86_64_SYSCALLS, "", "-not available-"),
+            (
+                X86_64_SYSCALLS,
+                "0 0x3 0x7f00 0x1000 0x0 0x0 0x0 0x7ffd1000 0x7f001000",
+                "read(3, 0x7f00, 4096)",
+            ),
+            (
+                X86_64_SYSCALLS,
+                "202 0x55d0 0x80 0x2 0x0 0x0 0x0 0x7ffd1000 0x7f001000",
+                "futex(0x55d0, FUTEX_WAIT_PRIVATE, 2, 0x0)",
+            ),
+            (
+                X86_64_SYSCALLS,
+                "230 0x1 0x0 0x7ffd 0x0 0x0 0x0 0x7ffd1000 0x7f001000",
+                "clock_nanosleep(CLOCK_MONOTONIC, 0, 0x7ffd, 0x0)",
+            ),
+            (
+                X86_64_SYSCALLS,
+                "61 0xffffffffffffffff 0x0 0x0 0x0 0x0 0x0 0x7ffd1000 0x7f001000",
+                "wait4(-1, 0x0, 0, 0x0)",
+            ),
+            (
+                X86_64_SYSCALLS,
+                "999 0x1 0x2 0x3 0x4 0x5 0x6 0x7ffd1000 0x7f001000",
+                "syscall_999(0x1, 0x2, 0x3, 0x4, 0x5, 0x6)",
+            ),
+            (AARCH64_SYSCALLS, "running", "-running-"),
+            (
+                AARCH64_SYSCALLS,
+                "-1 0xffffd000 0xffff1000",
+                "-blocked outside syscall-",
+            ),
+            (
+                AARCH64_SYSCALLS,
+                "63 0x3 0x7f00 0x1000 0x0 0x0 0x0 0xffffd000 0xffff1000",
+                "read(3, 0x7f00, 4096)",
+            ),
+            (
+                AARCH64_SYSCALLS,
+                "98 0x55d0 0x80 0x2 0x0 0x0 0x0 0xffffd000 0xffff1000",
+                "futex(0x55d0, FUTEX_WAIT_PRIVATE, 2, 0x0)",
+            ),
+            (
+                AARCH64_SYSCALLS,
+                "115 0x7 0x1 0xffff 0x0 0x0 0x0 0xffffd000 0xffff1000",
+                "clock_nanosleep(CLOCK_BOOTTIME, 1, 0xffff, 0x0)",
+            ),
+            // x86_64 read is unknown on aarch64
+            (
+                AARCH64_SYSCALLS,
+                "0 0x3 0x7f00 0x1000 0x0 0x0 0x0 0xffffd000 0xffff1000",
+                "syscall_0(0x3, 0x7f00, 0x1000, 0x0, 0x0, 0x0)",
+            ),
+        ];
+        for (names, line, expected) in cases {
+            assert_eq!(decode(names, 0, line), expected, "{line}");
+        }
+    }
+}
@@ -16,7 +16,8 @@ pub struct TermInfo {
 
 impl TermInfo {
     pub fn new(clear_by_line: bool, use_pager: bool) -> Result<Self, Error> {
-        let term = Term::stdout();
+        // Lines are buffered and written at once by flush, so many rows don't cause many writes
+        let term = Term::buffered_stdout();
         let pager = RefCell::new(Some(gen_pager()?));
         let (term_h, term_w) = term.size();
         let height = term_h as usize;
@@ -44,13 +45,20 @@ impl TermInfo {
         Ok(())
     }
 
+    pub fn flush(&self) -> Result<(), Error> {
+        self.term.flush()?;
+        Ok(())
+    }
+
     pub fn clear_screen(&self) -> Result<(), Error> {
         self.term.clear_screen()?;
+        self.term.flush()?;
         Ok(())
     }
 
     pub fn move_cursor_to(&self, x: usize, y: usize) -> Result<(), Error> {
         self.term.move_cursor_to(x, y)?;
+        self.term.flush()?;
         Ok(())
     }
 
@@ -59,6 +67,7 @@ impl TermInfo {
             self.term.clear_line()?;
             self.term.move_cursor_down(1)?;
         }
+        self.term.flush()?;
         Ok(())
     }
 }
@@ -0,0 +1,288 @@
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use std::fs;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use std::path::Path;
+
+/// hwmon drivers which report CPU temperature
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const CPU_HWMON_NAMES: &[&str] = &[
+    "coretemp",
+    "k10temp",
+    "zenpower",
+    "cpu_thermal",
+    "cpu-thermal",
+    "soc_thermal",
+    "acpitz",
+];
+
+#[derive(Clone, Debug, Default)]
+pub struct ThermalInfo {
+    /// Maximum CPU temperature in degrees Celsius
+    pub temp: Option<f64>,
+    /// Count of thermal throttling events since boot, only the increase is meaningful
+    pub throttle_count: Option<u64>,
+    /// Whether the CPU speed is limited now, for systems which don't count throttling events
+    pub throttling: Option<bool>,
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_thermal_info() -> ThermalInfo {
+    ThermalInfo {
+        temp: get_cpu_temp(Path::new("/sys/class/hwmon")),
+        throttle_count: get_throttle_count(Path::new("/sys/devices/system/cpu")),
+        throttling: None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub fn get_thermal_info() -> ThermalInfo {
+    ThermalInfo {
+        temp: smc::get_cpu_temp(),
+        throttle_count: None,
+        throttling: smc::get_cpu_speed_limit().map(|x| x < 100),
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos")))]
+pub fn get_thermal_info() -> ThermalInfo {
+    ThermalInfo::default()
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_cpu_temp(hwmon: &Path) -> Option<f64> {
+    let mut ret: Option<f64> = None;
+    for entry in fs::read_dir(hwmon).ok()?.flatten() {
+        let dir = entry.path();
+        let name = fs::read_to_string(dir.join("name")).unwrap_or_default();
+        if !CPU_HWMON_NAMES.contains(&name.trim()) {
+            continue;
+        }
+        for file in fs::read_dir(&dir).into_iter().flatten().flatten() {
+            let file_name = file.file_name();
+            let file_name = file_name.to_string_lossy();
+            if !file_name.starts_with("temp") || !file_name.ends_with("_input") {
+                continue;
+            }
+            if let Ok(x) = fs::read_to_string(file.path()) {
+                if let Ok(x) = x.trim().parse::<i64>() {
+                    let temp = x as f64 / 1000.0;
+                    ret = Some(ret.map_or(temp, |y| y.max(temp)));
+                }
+            }
+        }
+    }
+    ret
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_throttle_count(cpu: &Path) -> Option<u64> {
+    let mut ret: Option<u64> = None;
+    for entry in fs::read_dir(cpu).ok()?.flatten() {
+        let dir = entry.path().join("thermal_throttle");
+        for file in ["core_throttle_count", "package_throttle_count"] {
+            if let Ok(x) = fs::read_to_string(dir.join(file)) {
+                if let Ok(x) = x.trim().parse::<u64>() {
+                    ret = Some(ret.unwrap_or(0) + x);
+                }
+            }
+        }
+    }
+    ret
+}
+
+#[cfg(target_os = "macos")]
+mod smc {
+    use libc::{c_char, c_void};
+    use std::mem;
+
+    /// SMC keys of CPU temperature sensors ( Intel and Apple Silicon )
+    const CPU_TEMP_KEYS: &[&[u8; 4]] = &[
+        b"TC0P", b"TC0D", b"TC0E", b"TC0F", b"Tp01", b"Tp05", b"Tp09", b"Tp0D", b"Tp0H", b"Tp0L",
+        b"Tp0P", b"Tp0T", b"Tp0X", b"Tp0b", b"Tp0f", b"Tp0j", b"Tp1h", b"Tp1l", b"Tp1p", b"Tp1t",
+    ];
+
+    const KERNEL_INDEX_SMC: u32 = 2;
+    const SMC_CMD_READ_BYTES: u8 = 5;
+    const SMC_CMD_READ_KEYINFO: u8 = 9;
+    const CF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+    const CF_NUMBER_SINT32_TYPE: isize = 3;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct SmcVersion {
+        major: u8,
+        minor: u8,
+        build: u8,
+        reserved: u8,
+        release: u16,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct SmcPLimitData {
+        version: u16,
+        length: u16,
+        cpu_p_limit: u32,
+        gpu_p_limit: u32,
+        mem_p_limit: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct SmcKeyInfo {
+        data_size: u32,
+        data_type: u32,
+        data_attributes: u8,
+    }
+
+    /// `SMCKeyData_t` of AppleSMC user client
+    #[repr(C)]
+    #[derive(Default)]
+    struct SmcKeyData {
+        key: u32,
+        vers: SmcVersion,
+        p_limit_data: SmcPLimitData,
+        key_info: SmcKeyInfo,
+        result: u8,
+        status: u8,
+        data8: u8,
+        data32: u32,
+        bytes: [u8; 32],
+    }
+
+    #[link(name = "IOKit", kind = "framework")]
+    extern "C" {
+        fn IOServiceMatching(name: *const c_char) -> *mut c_void;
+        fn IOServiceGetMatchingService(main_port: u32, matching: *mut c_void) -> u32;
+        fn IOServiceOpen(service: u32, owning_task: u32, kind: u32, connect: *mut u32) -> i32;
+        fn IOServiceClose(connect: u32) -> i32;
+        fn IOObjectRelease(object: u32) -> i32;
+        fn IOConnectCallStructMethod(
+            connect: u32,
+            selector: u32,
+            input: *const c_void,
+            input_size: usize,
+            output: *mut c_void,
+            output_size: *mut usize,
+        ) -> i32;
+        fn IOPMCopyCPUPowerStatus(status: *mut *const c_void) -> i32;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFStringCreateWithCString(
+            alloc: *const c_void,
+            c_str: *const c_char,
+            encoding: u32,
+        ) -> *const c_void;
+        fn CFDictionaryGetValue(dict: *const c_void, key: *const c_void) -> *const c_void;
+        fn CFNumberGetValue(number: *const c_void, kind: isize, value: *mut c_void) -> bool;
+        fn CFRelease(cf: *const c_void);
+    }
+
+    extern "C" {
+        static mach_task_self_: u32;
+    }
+
+    struct Connection(u32);
+
+    impl Connection {
+        fn open() -> Option<Self> {
+            unsafe {
+                let service = IOServiceGetMatchingService(
+                    0,
+                    IOServiceMatching(b"AppleSMC\0".as_ptr() as *const c_char),
+                );
+                if service == 0 {
+                    return None;
+                }
+                let mut connect = 0;
+                let ret = IOServiceOpen(service, mach_task_self_, 0, &mut connect);
+                IOObjectRelease(service);
+                (ret == 0).then_some(Connection(connect))
+            }
+        }
+
+        fn call(&self, input: &SmcKeyData) -> Option<SmcKeyData> {
+            let mut output = SmcKeyData::default();
+            let mut output_size = mem::size_of::<SmcKeyData>();
+            let ret = unsafe {
+                IOConnectCallStructMethod(
+                    self.0,
+                    KERNEL_INDEX_SMC,
+                    input as *const SmcKeyData as *const c_void,
+                    mem::size_of::<SmcKeyData>(),
+                    &mut output as *mut SmcKeyData as *mut c_void,
+                    &mut output_size,
+                )
+            };
+            (ret == 0 && output.result == 0).then_some(output)
+        }
+
+        /// Reads a temperature key in degrees Celsius
+        fn read_temp(&self, key: &[u8; 4]) -> Option<f64> {
+            let key = u32::from_be_bytes(*key);
+            let info = self.call(&SmcKeyData {
+                key,
+                data8: SMC_CMD_READ_KEYINFO,
+                ..Default::default()
+            })?;
+            let data = self.call(&SmcKeyData {
+                key,
+                key_info: info.key_info,
+                data8: SMC_CMD_READ_BYTES,
+                ..Default::default()
+            })?;
+            let bytes = data.bytes;
+            let temp = match &data.key_info.data_type.to_be_bytes() {
+                b"sp78" => f64::from(i16::from_be_bytes([bytes[0], bytes[1]])) / 256.0,
+                b"flt " => f64::from(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+                _ => return None,
+            };
+            // Missing sensors read as 0 or out of range values
+            (temp > 0.0 && temp < 150.0).then_some(temp)
+        }
+    }
+
+    impl Drop for Connection {
+        fn drop(&mut self) {
+            unsafe {
+                IOServiceClose(self.0);
+            }
+        }
+    }
+
+    pub fn get_cpu_temp() -> Option<f64> {
+        let conn = Connection::open()?;
+        CPU_TEMP_KEYS
+            .iter()
+            .filter_map(|key| conn.read_temp(key))
+            .reduce(f64::max)
+    }
+
+    /// CPU speed limit in percent by thermal or power management
+    pub fn get_cpu_speed_limit() -> Option<i32> {
+        unsafe {
+            let mut status = std::ptr::null();
+            if IOPMCopyCPUPowerStatus(&mut status) != 0 || status.is_null() {
+                return None;
+            }
+            let key = CFStringCreateWithCString(
+                std::ptr::null(),
+                b"CPU_Speed_Limit\0".as_ptr() as *const c_char,
+                CF_STRING_ENCODING_UTF8,
+            );
+            let value = CFDictionaryGetValue(status, key);
+            let mut limit: i32 = 0;
+            let ret = !value.is_null()
+                && CFNumberGetValue(
+                    value,
+                    CF_NUMBER_SINT32_TYPE,
+                    &mut limit as *mut i32 as *mut c_void,
+                );
+            CFRelease(key);
+            CFRelease(status);
+            ret.then_some(limit)
+        }
+    }
+}
@@ -1,17 +1,29 @@
 use crate::column::Column;
-use crate::columns::{ConfigColumnKind, KIND_LIST};
+use crate::columns::{kind_platforms, ConfigColumnKind, Platform, KIND_LIST};
 use crate::config::{Config, ConfigColumnAlign, ConfigSearchCase, ConfigSearchLogic, ConfigTheme};
 use crate::Opt;
 use byte_unit::{Byte, UnitType};
 use clap::ValueEnum;
+#[cfg(not(target_os = "windows"))]
+use once_cell::sync::Lazy;
 use std::borrow::Cow;
+#[cfg(not(target_os = "windows"))]
+use std::collections::HashMap;
+#[cfg(not(target_os = "windows"))]
+use std::fs;
 use std::io;
 use std::io::IsTerminal;
+#[cfg(not(target_os = "windows"))]
+use std::path::Path;
+#[cfg(not(target_os = "windows"))]
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+#[cfg(not(target_os = "windows"))]
+use std::sync::{mpsc, Arc, Mutex};
+#[cfg(not(target_os = "windows"))]
+use std::thread;
 use std::time::Duration;
 use std::time::Instant;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
-#[cfg(not(target_os = "windows"))]
-use uzers::UsersCache;
 
 #[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ArgColorMode {
@@ -150,24 +162,22 @@ pub fn classify(keyword: &str) -> KeywordClass {
 }
 
 pub fn adjust(x: &str, len: usize, align: &ConfigColumnAlign) -> String {
-    if len < UnicodeWidthStr::width(x) {
-        String::from(truncate(x, len))
-    } else {
-        match align {
-            ConfigColumnAlign::Left => {
-                format!("{}{}", x, " ".repeat(len - UnicodeWidthStr::width(x)))
-            }
-            ConfigColumnAlign::Right => {
-                format!("{}{}", " ".repeat(len - UnicodeWidthStr::width(x)), x)
-            }
-            ConfigColumnAlign::Center => {
-                let space = len - UnicodeWidthStr::width(x);
-                let left = space / 2;
-                let right = space / 2 + space % 2;
-                format!("{}{}{}", " ".repeat(left), x, " ".repeat(right))
-            }
-        }
+    let width = UnicodeWidthStr::width(x);
+    if len < width {
+        return String::from(truncate(x, len));
     }
+
+    let space = len - width;
+    let (left, right) = match align {
+        ConfigColumnAlign::Left => (0, space),
+        ConfigColumnAlign::Right => (space, 0),
+        ConfigColumnAlign::Center => (space / 2, space / 2 + space % 2),
+    };
+    let mut ret = String::with_capacity(x.len() + space);
+    ret.extend(std::iter::repeat(' ').take(left));
+    ret.push_str(x);
+    ret.extend(std::iter::repeat(' ').take(right));
+    ret
 }
 
 pub fn parse_time(x: u64) -> String {
@@ -193,12 +203,10 @@ pub fn parse_time(x: u64) -> String {
     }
 }
 
-pub fn truncate(s: &'_ str, width: usize) -> Cow<'_, str> {
+pub fn truncate(s: &str, width: usize) -> &str {
     let mut total_width = 0;
-    let mut ret = None;
-    let mut buf = String::new();
     let mut escape = false;
-    for c in s.chars() {
+    for (i, c) in s.char_indices() {
         if c == '\u{1b}' {
             escape = true;
         }
@@ -206,24 +214,35 @@ pub fn truncate(s: &'_ str, width: usize) -> Cow<'_, str> {
             if c == 'm' {
                 escape = false;
             }
-            buf.push(c);
             continue;
         }
         total_width += UnicodeWidthChar::width(c).unwrap_or_default();
         if total_width > width {
-            ret = Some(buf);
-            break;
+            return &s[..i];
         }
-        buf.push(c);
     }
-    if let Some(buf) = ret {
-        Cow::Owned(buf)
-    } else {
-        Cow::Borrowed(s)
+    s
+}
+
+/// Returns the name of the column kind including its parameter.
+pub fn column_kind_name(kind: &ConfigColumnKind) -> Cow<'static, str> {
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+    if let ConfigColumnKind::EnvVar(x) = kind {
+        return Cow::Owned(format!("Env:{x}"));
     }
+    // Some kinds ( ex. Tree inserted by --tree ) are not listed
+    Cow::Borrowed(KIND_LIST.get(kind).map_or("", |x| x.0))
 }
 
 pub fn find_column_kind(pat: &str) -> Option<ConfigColumnKind> {
+    // parameterized kinds like `Env:DISPLAY`
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+    if let Some((kind, param)) = pat.split_once(':') {
+        if kind.eq_ignore_ascii_case("env") && !param.is_empty() {
+            return Some(ConfigColumnKind::EnvVar(String::from(param)));
+        }
+    }
+
     // strict search at first
     for (k, (v, _)) in KIND_LIST.iter() {
         if v.to_lowercase().eq(&pat.to_lowercase()) {
@@ -236,6 +255,15 @@ pub fn find_column_kind(pat: &str) -> Option<ConfigColumnKind> {
             return Some(k.clone());
         }
     }
+    if let Some(x) = kind_platforms(pat) {
+        let x: Vec<_> = x.iter().map(|x| x.to_string()).collect();
+        eprintln!(
+            "Column kind {pat} is not supported on {}, only on {}",
+            Platform::CURRENT,
+            x.join(", ")
+        );
+        return None;
+    }
     eprintln!("Can't find column kind: {pat}");
     None
 }
@@ -286,12 +314,292 @@ pub fn format_sid(sid: &[u64], abbr: bool) -> String {
     ret
 }
 
-pub fn bytify(x: u64) -> String {
+/// Abbreviates the home directory at the head of the path to `~`.
+#[cfg(not(target_os = "windows"))]
+pub fn abbr_path(path: &str, home: Option<&Path>) -> String {
+    if let Some(rest) = home.and_then(|x| Path::new(path).strip_prefix(x).ok()) {
+        if rest.as_os_str().is_empty() {
+            String::from("~")
+        } else {
+            format!("~/{}", rest.to_string_lossy())
+        }
+    } else {
+        path.to_string()
+    }
+}
+
+/// Formats a duration with the largest unit more than 1 ( ex. `1.5hours` ).
+pub fn format_duration(duration: chrono::Duration) -> String {
+    let years = duration.num_weeks() as f64 / 52.0;
+    let weeks = duration.num_days() as f64 / 7.0;
+    let days = duration.num_hours() as f64 / 24.0;
+    let hours = duration.num_minutes() as f64 / 60.0;
+    let minutes = duration.num_seconds() as f64 / 60.0;
+    let seconds = duration.num_seconds();
+
+    if years > 1.0 {
+        format!("{years:.1}years")
+    } else if weeks > 1.0 {
+        format!("{weeks:.1}weeks")
+    } else if days > 1.0 {
+        format!("{days:.1}days")
+    } else if hours > 1.0 {
+        format!("{hours:.1}hours")
+    } else if minutes > 1.0 {
+        format!("{minutes:.1}minutes")
+    } else {
+        format!("{seconds:.1}seconds")
+    }
+}
+
+pub fn bytify(x: u64, unit_suffix: bool) -> String {
+    if !unit_suffix {
+        return x.to_string();
+    }
     let byte = Byte::from_u64(x);
     let byte = byte.get_appropriate_unit(UnitType::Binary);
     format!("{:.3}", byte).replace([' ', 'B', 'i'], "")
 }
 
+/// Parses a number formatted by `bytify` ( ex. `1.5M` )
+pub fn unbytify(s: &str) -> Option<f64> {
+    let units = ['K', 'M', 'G', 'T', 'P', 'E'];
+    let (num, exp) = match s.char_indices().last() {
+        Some((i, c)) => match units.iter().position(|x| *x == c.to_ascii_uppercase()) {
+            Some(exp) => (&s[..i], exp as i32 + 1),
+            None => (s, 0),
+        },
+        None => return None,
+    };
+    num.parse::<f64>().ok().map(|x| x * 1024f64.powi(exp))
+}
+
+/// Splits `http://host:port/path` or `https://host:port/path` into the origin ( ex. `https://host:port` ) and the path,
+/// where the path is `/` by default.
+pub fn split_http_url(url: &str) -> Result<(String, String), anyhow::Error> {
+    let (scheme, rest) = if let Some(x) = url.strip_prefix("http://") {
+        ("http", x)
+    } else if let Some(x) = url.strip_prefix("https://") {
+        ("https", x)
+    } else {
+        anyhow::bail!("only http:// and https:// are supported: {url}");
+    };
+    let (host, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    if host.is_empty() {
+        anyhow::bail!("host is empty: {url}");
+    }
+    Ok((format!("{scheme}://{host}"), path.to_string()))
+}
+
+/// Fails if the strftime format has unknown specifiers, which would panic on formatting.
+pub fn check_time_format(format: &str) -> Result<(), anyhow::Error> {
+    use chrono::format::{Item, StrftimeItems};
+
+    if StrftimeItems::new(format).any(|x| matches!(x, Item::Error)) {
+        anyhow::bail!("invalid start_time_format in [display]: {format:?}");
+    }
+    Ok(())
+}
+
+/// Posts a JSON body, and fails unless the status is 2xx.
+pub fn http_post(origin: &str, path: &str, body: &str) -> Result<(), anyhow::Error> {
+    let res = ureq::post(&format!("{origin}{path}"))
+        .timeout(Duration::from_secs(5))
+        .set("Content-Type", "application/json")
+        .send_string(body);
+    match res {
+        Ok(_) => Ok(()),
+        Err(ureq::Error::Status(code, _)) => {
+            Err(anyhow::anyhow!("failed to post to {origin}{path}: {code}"))
+        }
+        Err(e) => Err(anyhow::anyhow!("failed to post to {origin}{path}: {e}")),
+    }
+}
+
+/// Parses PIDs of `--pids`, which are read from stdin if it's `-`.
+///
+/// PIDs are separated by commas or whitespaces, so outputs of `pgrep` and `pidof` can be piped as is.
+pub fn parse_pids(arg: &str) -> Result<Vec<i32>, anyhow::Error> {
+    let input = if arg == "-" {
+        io::read_to_string(io::stdin())?
+    } else {
+        arg.to_string()
+    };
+    input
+        .split(|x: char| x == ',' || x.is_whitespace())
+        .filter(|x| !x.is_empty())
+        .map(|x| {
+            x.parse()
+                .map_err(|_| anyhow::anyhow!("invalid PID in --pids: {x:?}"))
+        })
+        .collect()
+}
+
+/// Resolves an alias of `[aliases]` to the kind name, or returns the name as is.
+pub fn resolve_alias<'a>(config: &'a Config, name: &'a str) -> &'a str {
+    config
+        .aliases
+        .iter()
+        .find(|(x, _)| x.eq_ignore_ascii_case(name))
+        .map_or(name, |(_, x)| x.kind.as_str())
+}
+
+/// Replaces aliases in options which take a kind name.
+pub fn apply_aliases(opt: &mut Opt, config: &Config) {
+    for x in opt
+        .insert
+        .iter_mut()
+        .chain(opt.sorta.iter_mut())
+        .chain(opt.sortd.iter_mut())
+        .chain(opt.only.iter_mut())
+    {
+        *x = resolve_alias(config, x).to_string();
+    }
+}
+
+/// Returns the header given by `[aliases]` for the kind.
+pub fn alias_header(config: &Config, kind: &ConfigColumnKind) -> Option<String> {
+    let name = column_kind_name(kind);
+    config
+        .aliases
+        .values()
+        .find(|x| x.header.is_some() && x.kind.eq_ignore_ascii_case(&name))
+        .and_then(|x| x.header.clone())
+}
+
+/// Finds column kinds of metrics for exporters, and inserts the columns which are not in the configuration to slots like `--insert`.
+pub fn insert_metric_columns(
+    opt: &mut Opt,
+    config: &Config,
+    names: &[String],
+) -> Result<Vec<ConfigColumnKind>, anyhow::Error> {
+    let kinds = names
+        .iter()
+        .map(|x| {
+            find_column_kind(resolve_alias(config, x))
+                .ok_or_else(|| anyhow::anyhow!("unknown metric kind: {x}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for kind in &kinds {
+        let name = column_kind_name(kind).into_owned();
+        if config.columns.iter().all(|c| c.kind != *kind) && !opt.insert.contains(&name) {
+            opt.insert.push(name);
+        }
+    }
+    Ok(kinds)
+}
+
+/// Expands `{placeholder}` in the template by `expand`, which fails for unknown placeholders.
+pub fn render_template<F>(template: &str, mut expand: F) -> Result<String, anyhow::Error>
+where
+    F: FnMut(&str) -> Result<String, anyhow::Error>,
+{
+    let mut ret = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        ret.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| anyhow::anyhow!("unclosed placeholder in template: {template}"))?;
+        ret.push_str(&expand(&rest[start + 1..start + end])?);
+        rest = &rest[start + end + 1..];
+    }
+    ret.push_str(rest);
+    Ok(ret)
+}
+
+/// Converts a kind name to snake case for metric names ( ex. `UsageCpu` -> `usage_cpu` )
+pub fn snake_case(kind: &str) -> String {
+    let mut ret = String::new();
+    for (i, c) in kind.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                ret.push('_');
+            }
+            ret.push(c.to_ascii_lowercase());
+        } else {
+            ret.push(c);
+        }
+    }
+    ret
+}
+
+/// Calculates per-second rates of cumulative counters of processes.
+///
+/// The sample of the previous refresh in watch mode is used if it exists,
+/// so the rate covers the whole period between refreshes instead of the collection interval only.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[derive(Default)]
+pub struct CounterRate {
+    /// Start time of the process to detect PID reuse, the counter, and the time of it
+    samples: HashMap<i32, (u64, u64, Instant)>,
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl CounterRate {
+    /// `prev` is the counter at the beginning of `interval`, and `None` if it isn't available.
+    pub fn rate(
+        &mut self,
+        pid: i32,
+        start: u64,
+        curr: u64,
+        prev: Option<u64>,
+        interval: Duration,
+    ) -> Option<u64> {
+        let now = Instant::now();
+        // The previous sample is ignored if the PID is reused
+        match (self.samples.insert(pid, (start, curr, now)), prev) {
+            (Some((x, count, time)), _) if x == start && count <= curr => {
+                per_second(curr - count, now.duration_since(time))
+            }
+            (_, Some(prev)) => per_second(curr.saturating_sub(prev), interval),
+            _ => None,
+        }
+    }
+
+    /// Drops samples of exited processes.
+    pub fn retain<T>(&mut self, pids: &HashMap<i32, T>) {
+        self.samples.retain(|pid, _| pids.contains_key(pid));
+    }
+}
+
+/// Count per second, or `None` if the period is too short to calculate
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn per_second(count: u64, period: Duration) -> Option<u64> {
+    (u128::from(count) * 1000)
+        .checked_div(period.as_millis())
+        .map(|x| x as u64)
+}
+
+/// Sums values of each process and all its descendants.
+///
+/// Processes which don't exist in `parent_pids` ( ex. threads ) are counted by themselves only.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn roll_up<T: Copy + std::ops::AddAssign>(
+    values: &HashMap<i32, T>,
+    parent_pids: &HashMap<i32, i32>,
+) -> HashMap<i32, T> {
+    let mut ret = values.clone();
+    for (pid, value) in values {
+        let mut parent = parent_pids.get(pid);
+        // The depth is limited to avoid infinite loop by PID reuse while collecting
+        let mut depth = 0;
+        while let Some(ppid) = parent {
+            if let (Some(total), true) = (ret.get_mut(ppid), depth < values.len()) {
+                *total += *value;
+            } else {
+                break;
+            }
+            parent = parent_pids.get(ppid);
+            depth += 1;
+        }
+    }
+    ret
+}
+
 pub fn lap(instant: &mut Instant, msg: &str) {
     let period = instant.elapsed();
     eprintln!(
@@ -303,6 +611,52 @@ pub fn lap(instant: &mut Instant, msg: &str) {
     instant.clone_from(&Instant::now());
 }
 
+/// Durations of startup phases shown by `--profile`
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    time: Option<Instant>,
+    /// Name, duration, and whether the phase ran concurrently with others
+    laps: Vec<(&'static str, Duration, bool)>,
+}
+
+impl Profile {
+    pub fn start(&mut self) {
+        self.time = Some(Instant::now());
+    }
+
+    /// Records the period since the previous lap as `name`.
+    pub fn lap(&mut self, name: &'static str) {
+        let now = Instant::now();
+        if let Some(x) = self.time {
+            self.laps.push((name, now.duration_since(x), false));
+        }
+        self.time = Some(now);
+    }
+
+    /// Records a phase which ran in another thread, so it isn't included in the total.
+    pub fn concurrent(&mut self, name: &'static str, period: Duration) {
+        self.laps.push((name, period, true));
+    }
+
+    pub fn report(&self) {
+        let width = self.laps.iter().map(|x| x.0.len()).max().unwrap_or(0);
+        let mut total = Duration::ZERO;
+        eprintln!("Profile:");
+        for (name, period, concurrent) in &self.laps {
+            if *concurrent {
+                eprintln!(
+                    "  {name:width$} {:.3}s ( concurrent )",
+                    period.as_secs_f64()
+                );
+            } else {
+                eprintln!("  {name:width$} {:.3}s", period.as_secs_f64());
+                total += *period;
+            }
+        }
+        eprintln!("  {:width$} {:.3}s", "total", total.as_secs_f64());
+    }
+}
+
 pub fn get_theme(opt: &Opt, config: &Config) -> ConfigTheme {
     let theme = match (opt.theme, &config.display.theme) {
         (Some(x), _) => x.into(),
@@ -310,7 +664,12 @@ pub fn get_theme(opt: &Opt, config: &Config) -> ConfigTheme {
     };
     match theme {
         ConfigTheme::Auto => {
-            if io::stdout().is_terminal() && io::stderr().is_terminal() && io::stdin().is_terminal()
+            if opt.deterministic || !config.display.detect_theme {
+                // Skip probing the terminal to keep output reproducible or to avoid glitches
+                ConfigTheme::Dark
+            } else if io::stdout().is_terminal()
+                && io::stderr().is_terminal()
+                && io::stdin().is_terminal()
             {
                 let minimum_timeout = Duration::from_millis(100);
                 let timeout = if let Ok(latency) = termbg::latency(Duration::from_millis(1000)) {
@@ -342,9 +701,210 @@ pub fn get_theme(opt: &Opt, config: &Config) -> ConfigTheme {
     }
 }
 
+// The cache is shared between threads and persists across watch refreshes
+// because passwd/group lookups may be slow on NSS/LDAP systems.
+// Each entry has the time of the lookup, so that IDs which are not found can be looked up again.
+#[cfg(not(target_os = "windows"))]
+type LookupCache<T> = Lazy<Mutex<HashMap<u32, (Option<Arc<T>>, Instant)>>>;
+
+#[cfg(not(target_os = "windows"))]
+static USERS_CACHE: LookupCache<uzers::User> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(not(target_os = "windows"))]
+static GROUPS_CACHE: LookupCache<uzers::Group> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// IDs which are not found are looked up again after this, so users added while watching are resolved
+#[cfg(not(target_os = "windows"))]
+const LOOKUP_NOT_FOUND_TTL: Duration = Duration::from_secs(60);
+
+#[cfg(not(target_os = "windows"))]
+static LOOKUP_NUMERIC: AtomicBool = AtomicBool::new(false);
+
+#[cfg(not(target_os = "windows"))]
+static LOOKUP_TIMEOUT_MS: AtomicU64 = AtomicU64::new(1000);
+
+// User/group names are resolved from preloaded passwd/group files only
+#[cfg(not(target_os = "windows"))]
+static LOOKUP_PRELOADED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(not(target_os = "windows"))]
+static LOOKUP: Lazy<Lookup> = Lazy::new(|| Lookup::new(Duration::from_secs(5)));
+
+/// Maximum interval of retries after lookups timed out
+#[cfg(not(target_os = "windows"))]
+const LOOKUP_RETRY_MAX: Duration = Duration::from_secs(300);
+
+#[cfg(not(target_os = "windows"))]
+type LookupJob = Box<dyn FnOnce() + Send>;
+
+/// Lookups which give up if the backend ( ex. broken sssd/LDAP ) doesn't respond.
+///
+/// Lookups are run one by one in a single worker thread, and callers wait for the result until the timeout.
+/// After a timeout, lookups are skipped until the retry time, and the interval of retries is doubled while the backend
+/// keeps timing out.
+#[cfg(not(target_os = "windows"))]
+struct Lookup {
+    /// Time to retry after a timeout, and the interval to it
+    retry: Mutex<Option<(Instant, Duration)>>,
+    retry_min: Duration,
+    /// Queue of the worker thread, which is spawned by the first lookup
+    worker: Mutex<Option<mpsc::Sender<LookupJob>>>,
+}
+
+#[cfg(not(target_os = "windows"))]
+impl Lookup {
+    fn new(retry_min: Duration) -> Self {
+        Self {
+            retry: Mutex::new(None),
+            retry_min,
+            worker: Mutex::new(None),
+        }
+    }
+
+    fn run<T, F>(&self, timeout: Duration, f: F) -> Result<Option<T>, ()>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> Option<T> + Send + 'static,
+    {
+        if matches!(*self.retry.lock().map_err(|_| ())?, Some((x, _)) if Instant::now() < x) {
+            return Err(());
+        }
+
+        let (tx, rx) = mpsc::channel();
+        self.send(Box::new(move || {
+            let _ = tx.send(f());
+        }))?;
+
+        // Locks are not held while waiting, so other lookups are not blocked by this
+        let ret = rx.recv_timeout(timeout);
+        let mut retry = self.retry.lock().map_err(|_| ())?;
+        match ret {
+            Ok(x) => {
+                *retry = None;
+                Ok(x)
+            }
+            Err(_) => {
+                // Lookups which timed out at the same time extend the retry time once
+                if !matches!(*retry, Some((x, _)) if Instant::now() < x) {
+                    let interval =
+                        retry.map_or(self.retry_min, |(_, x)| (x * 2).min(LOOKUP_RETRY_MAX));
+                    *retry = Some((Instant::now() + interval, interval));
+                }
+                Err(())
+            }
+        }
+    }
+
+    fn send(&self, job: LookupJob) -> Result<(), ()> {
+        let mut worker = self.worker.lock().map_err(|_| ())?;
+        let tx = worker.get_or_insert_with(|| {
+            let (tx, rx) = mpsc::channel::<LookupJob>();
+            thread::spawn(move || {
+                for job in rx {
+                    job();
+                }
+            });
+            tx
+        });
+        tx.send(job).map_err(|_| ())
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn set_lookup_option(numeric: bool, timeout: Duration) {
+    LOOKUP_NUMERIC.store(numeric, Ordering::Relaxed);
+    LOOKUP_TIMEOUT_MS.store(timeout.as_millis() as u64, Ordering::Relaxed);
+}
+
+#[cfg(target_os = "windows")]
+pub fn set_lookup_option(_numeric: bool, _timeout: Duration) {}
+
 #[cfg(not(target_os = "windows"))]
-thread_local! {
-    pub static USERS_CACHE: std::cell::RefCell<UsersCache> = UsersCache::new().into();
+fn lookup_with_timeout<T, F>(f: F) -> Result<Option<T>, ()>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Option<T> + Send + 'static,
+{
+    if LOOKUP_NUMERIC.load(Ordering::Relaxed) || LOOKUP_PRELOADED.load(Ordering::Relaxed) {
+        return Err(());
+    }
+
+    let timeout = Duration::from_millis(LOOKUP_TIMEOUT_MS.load(Ordering::Relaxed));
+    LOOKUP.run(timeout, f)
+}
+
+/// Resolves user/group names from the specified passwd/group files instead of NSS.
+///
+/// This is used when the processes belong to another system (ex. host /proc through `--host-proc`).
+/// IDs which are not found in the files are shown as numeric.
+/// If the passwd file can't be read, NSS is used as usual.
+#[cfg(not(target_os = "windows"))]
+pub fn preload_user_db(passwd: &Path, group: &Path) {
+    let s = if let Ok(s) = fs::read_to_string(passwd) {
+        s
+    } else {
+        return;
+    };
+    if let Ok(mut cache) = USERS_CACHE.lock() {
+        for line in s.lines() {
+            let x: Vec<_> = line.split(':').collect();
+            if let (Some(name), Some(Ok(uid)), Some(Ok(gid))) = (
+                x.first(),
+                x.get(2).map(|x| x.parse()),
+                x.get(3).map(|x| x.parse()),
+            ) {
+                cache.insert(
+                    uid,
+                    (
+                        Some(Arc::new(uzers::User::new(uid, name, gid))),
+                        Instant::now(),
+                    ),
+                );
+            }
+        }
+    }
+    if let (Ok(s), Ok(mut cache)) = (fs::read_to_string(group), GROUPS_CACHE.lock()) {
+        for line in s.lines() {
+            let x: Vec<_> = line.split(':').collect();
+            if let (Some(name), Some(Ok(gid))) = (x.first(), x.get(2).map(|x| x.parse())) {
+                cache.insert(
+                    gid,
+                    (Some(Arc::new(uzers::Group::new(gid, name))), Instant::now()),
+                );
+            }
+        }
+    }
+    LOOKUP_PRELOADED.store(true, Ordering::Relaxed);
+}
+
+/// Returns `None` if the user is not found, or the lookup is disabled or timed out.
+/// The caller should fallback to numeric UID.
+#[cfg(not(target_os = "windows"))]
+pub fn get_user_by_uid(uid: u32) -> Option<Arc<uzers::User>> {
+    cached_lookup(&USERS_CACHE, uid, move || uzers::get_user_by_uid(uid))
+}
+
+/// Returns `None` if the group is not found, or the lookup is disabled or timed out.
+/// The caller should fallback to numeric GID.
+#[cfg(not(target_os = "windows"))]
+pub fn get_group_by_gid(gid: u32) -> Option<Arc<uzers::Group>> {
+    cached_lookup(&GROUPS_CACHE, gid, move || uzers::get_group_by_gid(gid))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn cached_lookup<T, F>(cache: &LookupCache<T>, id: u32, f: F) -> Option<Arc<T>>
+where
+    T: Send + Sync + 'static,
+    F: FnOnce() -> Option<T> + Send + 'static,
+{
+    match cache.lock().ok()?.get(&id) {
+        Some((Some(x), _)) => return Some(x.clone()),
+        Some((None, time)) if time.elapsed() < LOOKUP_NOT_FOUND_TTL => return None,
+        _ => (),
+    }
+    let x = lookup_with_timeout(move || f().map(Arc::new)).ok()?;
+    cache.lock().ok()?.insert(id, (x.clone(), Instant::now()));
+    x
 }
 
 #[cfg(target_os = "freebsd")]
@@ -358,3 +918,63 @@ pub fn ptr_to_cstr(
     let x = unsafe { std::slice::from_raw_parts::<u8>(ptr, len) };
     std::ffi::CStr::from_bytes_until_nul(x)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unbytify() {
+        assert_eq!(unbytify("12.5"), Some(12.5));
+        assert_eq!(unbytify("2K"), Some(2048.0));
+        assert_eq!(unbytify("00:01:02"), None);
+        assert_eq!(unbytify(""), None);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_lookup_timeout() {
+        let lookup = Lookup::new(Duration::from_secs(100));
+        let wait = Duration::from_secs(10);
+        let timeout = Duration::from_millis(10);
+        assert_eq!(lookup.run(wait, || Some(1)), Ok(Some(1)));
+
+        // The lookup is blocked until it's released
+        let (release, blocked) = mpsc::channel::<()>();
+        let slow = move || {
+            let _ = blocked.recv();
+            Some(2)
+        };
+        assert_eq!(lookup.run(timeout, slow), Err(()));
+        // Skipped until the retry time
+        assert_eq!(lookup.run(wait, || Some(3)), Err(()));
+
+        // The interval of retries is doubled while the worker is still blocked
+        let expire = |lookup: &Lookup| {
+            let mut retry = lookup.retry.lock().unwrap();
+            *retry = retry.map(|(_, x)| (Instant::now(), x));
+        };
+        expire(&lookup);
+        assert_eq!(lookup.run(timeout, || Some(3)), Err(()));
+        assert_eq!(
+            lookup.retry.lock().unwrap().map(|(_, x)| x),
+            Some(Duration::from_secs(200))
+        );
+
+        // Queued lookups are run after the blocked one is released
+        release.send(()).unwrap();
+        expire(&lookup);
+        assert_eq!(lookup.run(wait, || Some(4)), Ok(Some(4)));
+        assert!(lookup.retry.lock().unwrap().is_none());
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn test_counter_rate_pid_reuse() {
+        let mut rate = CounterRate::default();
+        let interval = Duration::from_secs(1);
+        rate.rate(100, 10, 1000, Some(0), interval);
+        // The sample of the previous process with the same PID is not used
+        assert_eq!(rate.rate(100, 20, 5000, Some(4000), interval), Some(1000));
+    }
+}
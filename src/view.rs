@@ -1,19 +1,40 @@
 use crate::column::Column;
 use crate::columns::*;
 use crate::config::*;
-use crate::process::collect_proc;
+use crate::power::get_power_info;
+use crate::process::{collect_proc, self_pid, ProcessInfo};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::process::{collect_proc_from, set_procfs_root, CapturedProcfs};
 use crate::style::{apply_color, apply_style, color_to_column_style};
 use crate::term_info::TermInfo;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::util::preload_user_db;
 use crate::util::{
-    classify, find_column_kind, find_exact, find_partial, truncate, ArgColorMode, ArgPagerMode,
-    KeywordClass,
+    adjust, alias_header, check_time_format, classify, column_kind_name, find_column_kind,
+    find_exact, find_partial, resolve_alias, set_lookup_option, truncate, ArgColorMode,
+    ArgPagerMode, KeywordClass,
 };
 use crate::Opt;
 use anyhow::{bail, Error};
 #[cfg(not(target_os = "windows"))]
 use pager::Pager;
-use std::collections::HashMap;
-use std::time::Duration;
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+use unicode_width::UnicodeWidthStr;
+
+/// Column kinds inserted by `--power`
+const POWER_PRESET_KINDS: &[&str] = &["Wakeups", "Energy"];
+
+/// Column kinds inserted by `--baseline`
+const BASELINE_KINDS: &[&str] = &["Baseline", "DeltaRss", "DeltaCpuTime"];
+
+/// Number of processes rendered at once in streaming output
+const STREAM_CHUNK: usize = 256;
+
+/// Width of columns in `--deterministic` mode if neither `max_width` nor `min_width` is configured
+const FIXED_WIDTH: usize = 10;
 
 pub struct SortInfo {
     pub idx: usize,
@@ -26,12 +47,195 @@ pub struct View {
     pub sort_info: SortInfo,
     pub visible_pids: Vec<i32>,
     pub auxiliary_pids: Vec<i32>,
+    /// Number of matched processes which are not rendered by `limit_rows`
+    pub hidden_rows: usize,
+    /// Whether the footer row of aggregates is rendered
+    pub show_aggregate: bool,
+    /// Aggregates of each column over the visible processes, computed by `adjust`
+    pub aggregates: Vec<Option<String>>,
+    /// PIDs and commands of processes in the baseline of `--baseline` which are not running now
+    pub gone: Vec<(i32, String)>,
+    /// Whether widths of columns are fixed by `--deterministic`
+    pub fixed_width: bool,
+    /// Processes followed by `--follow`, which are carried over views of watch mode
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub follow: Option<crate::follow::Follow>,
     pub parent_pids: HashMap<i32, i32>,
     pub child_pids: HashMap<i32, Vec<i32>>,
+    pub pids: HashSet<i32>,
 }
 
 impl View {
     pub fn new(opt: &mut Opt, config: &Config, clear_by_line: bool) -> Result<Self, Error> {
+        let (mut view, proc) = View::prepare(opt, config, clear_by_line)?;
+        view.add(&proc);
+        Ok(view)
+    }
+
+    pub fn add(&mut self, proc: &[ProcessInfo]) {
+        for c in self.columns.iter_mut() {
+            for p in proc {
+                c.column.add(p);
+            }
+            c.column.finish();
+        }
+    }
+
+    /// Builds a view for watch mode.
+    ///
+    /// Columns with `refresh` interval reuse contents of the previous view until the interval is elapsed,
+    /// and only processes started after the previous view are added.
+    /// Persistent columns are reused with all processes to keep their state.
+    pub fn renew(opt: &mut Opt, config: &Config, prev: Option<View>) -> Result<Self, Error> {
+        let (mut view, proc) = View::prepare(opt, config, true)?;
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let mut prev = prev;
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let prev_follow = prev.as_mut().and_then(|x| x.follow.take());
+        let (mut prev_columns, prev_pids): (Vec<_>, _) = if let Some(prev) = prev {
+            (prev.columns.into_iter().map(Some).collect(), prev.pids)
+        } else {
+            (Vec::new(), HashSet::new())
+        };
+
+        for (i, c) in view.columns.iter_mut().enumerate() {
+            let prev = prev_columns.get_mut(i).and_then(|x| x.take());
+            let reusable = match (&prev, c.refresh) {
+                (Some(prev), Some(refresh)) => {
+                    prev.kind == c.kind
+                        && prev.column.streamable()
+                        && prev.refreshed.elapsed() < refresh
+                }
+                _ => false,
+            };
+            match prev {
+                Some(prev) if reusable => {
+                    c.column = prev.column;
+                    c.refreshed = prev.refreshed;
+                    for p in proc.iter().filter(|x| !prev_pids.contains(&x.pid)) {
+                        c.column.add(p);
+                    }
+                }
+                Some(prev) if prev.kind == c.kind && prev.column.persistent() => {
+                    c.column = prev.column;
+                    c.column.clear();
+                    for p in &proc {
+                        c.column.add(p);
+                    }
+                    c.column.finish();
+                }
+                _ => {
+                    for p in &proc {
+                        c.column.add(p);
+                    }
+                    c.column.finish();
+                }
+            }
+        }
+
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if let Some(keyword) = &opt.follow {
+            view.follow(keyword, opt, config, prev_follow, &proc);
+        }
+        Ok(view)
+    }
+
+    /// Resolves processes matched by the keyword of `--follow` until any process is matched,
+    /// and then follows them by identities.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn follow(
+        &mut self,
+        keyword: &str,
+        opt: &Opt,
+        config: &Config,
+        prev: Option<crate::follow::Follow>,
+        proc: &[ProcessInfo],
+    ) {
+        let mut follow = prev.unwrap_or_default();
+        if follow.resolved() {
+            follow.update(proc);
+        } else {
+            let mut opt = opt.clone();
+            opt.keyword = keyword.split_whitespace().map(String::from).collect();
+            opt.tree = false;
+            self.filter(&opt, config);
+            follow.resolve(proc, &self.visible_pids);
+        }
+        self.follow = Some(follow);
+    }
+
+    /// Whether the process is followed by `--follow`, which overrides keywords.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn followed(&self, pid: i32) -> Option<bool> {
+        self.follow.as_ref().map(|x| x.contains(pid))
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn followed(&self, _pid: i32) -> Option<bool> {
+        None
+    }
+
+    /// Rows of `--follow` shown below processes.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn follow_notes(&self, opt: &Opt) -> Vec<String> {
+        match (&self.follow, &opt.follow) {
+            (Some(follow), Some(keyword)) => follow.notes(keyword),
+            _ => Vec::new(),
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn follow_notes(&self, _opt: &Opt) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Builds columns and collects processes, but processes are not added to columns yet.
+    pub fn prepare(
+        opt: &mut Opt,
+        config: &Config,
+        clear_by_line: bool,
+    ) -> Result<(Self, Vec<ProcessInfo>), Error> {
+        check_time_format(&config.display.start_time_format)?;
+        set_lookup_option(
+            config.display.numeric_id,
+            Duration::from_millis(config.display.lookup_timeout),
+        );
+
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        {
+            if let Some(path) = &opt.from_dir {
+                set_procfs_root(CapturedProcfs::new(path, Duration::ZERO).root());
+            } else if let Some(path) = opt.host_proc.as_ref().or(opt.procfs.as_ref()) {
+                set_procfs_root(path);
+            }
+            if let Some(path) = &opt.from_dir {
+                // sosreport has etc/ beside proc/
+                if let Some(parent) = path.parent() {
+                    preload_user_db(
+                        &parent.join("etc").join("passwd"),
+                        &parent.join("etc").join("group"),
+                    );
+                }
+            }
+            if let Some(path) = &opt.host_proc {
+                // UID/GID of host should be resolved by passwd/group of host
+                let host_root = path.join("1").join("root");
+                preload_user_db(
+                    &host_root.join("etc").join("passwd"),
+                    &host_root.join("etc").join("group"),
+                );
+            }
+        }
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        {
+            if opt.host_proc.is_some() {
+                bail!("--host-proc is supported on Linux only");
+            }
+            if opt.from_dir.is_some() {
+                bail!("--from-dir is supported on Linux only");
+            }
+        }
+
         let mut slot_idx = 0;
         let mut columns = Vec::new();
         let mut only_kind_found = false;
@@ -46,16 +250,87 @@ impl View {
             max_width: None,
             min_width: None,
             header: None,
+            unit_suffix: true,
+            refresh: None,
+            sort_empty: ConfigSortEmpty::Value,
+            aggregate: ConfigAggregate::Disable,
+            group: None,
         };
 
-        // Adding the sort column to inserts if not already present
-        match (&opt.sorta, &opt.sortd) {
-            (_, Some(col)) | (Some(col), _) => {
-                if !opt.insert.contains(col) {
-                    opt.insert.push(col.clone());
+        // Power preset adds columns related to energy and sorts by energy impact
+        #[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos")))]
+        if opt.power {
+            bail!("--power is supported on Linux and macOS only");
+        }
+        if opt.power {
+            if opt.sorta.is_none() && opt.sortd.is_none() && !opt.tree {
+                opt.sortd = Some(String::from("Energy"));
+            }
+            for kind in POWER_PRESET_KINDS {
+                if !opt.insert.iter().any(|x| x.eq_ignore_ascii_case(kind)) {
+                    opt.insert.push(String::from(*kind));
                 }
             }
-            _ => {}
+        }
+
+        // Audit preset adds findings and shows flagged processes only by `filter`
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        if opt.audit {
+            bail!("--audit is supported on Linux only");
+        }
+        if opt.audit {
+            if opt.sorta.is_none() && opt.sortd.is_none() && !opt.tree {
+                opt.sortd = Some(String::from("Audit"));
+            }
+            if !opt.insert.iter().any(|x| x.eq_ignore_ascii_case("Audit")) {
+                opt.insert.push(String::from("Audit"));
+            }
+        }
+
+        // Baseline is loaded before columns are created, and shared by views of watch mode
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        if opt.follow.is_some() {
+            bail!("--follow is supported on Linux only");
+        }
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        if opt.baseline.is_some() || opt.save_baseline.is_some() {
+            bail!("--baseline and --save-baseline are supported on Linux only");
+        }
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if let Some(path) = &opt.baseline {
+            crate::baseline::load(path)?;
+            for kind in BASELINE_KINDS {
+                if !opt.insert.iter().any(|x| x.eq_ignore_ascii_case(kind)) {
+                    opt.insert.push(String::from(*kind));
+                }
+            }
+        }
+
+        // YARA rules are compiled before columns are created
+        #[cfg(all(feature = "yara", not(any(target_os = "linux", target_os = "android"))))]
+        if opt.yara.is_some() {
+            bail!("--yara is supported on Linux only");
+        }
+        #[cfg(all(feature = "yara", any(target_os = "linux", target_os = "android")))]
+        if let Some(path) = &opt.yara {
+            crate::columns::yara_hits::load_rules(path, opt.yara_memory)?;
+            if !opt
+                .insert
+                .iter()
+                .any(|x| x.eq_ignore_ascii_case("YaraHits"))
+            {
+                opt.insert.push(String::from("YaraHits"));
+            }
+        }
+
+        // Adding the sort column to inserts if not already present
+        if let (_, Some(col)) | (Some(col), _) = (&opt.sorta, &opt.sortd) {
+            // Compared by the kind instead of a part of the name ( ex. "Pid" shouldn't match "Ppid" )
+            let in_config = find_column_kind(resolve_alias(config, col))
+                .is_some_and(|kind| config.columns.iter().any(|x| x.kind == kind));
+            if !in_config && !opt.insert.contains(col) {
+                opt.insert.push(col.clone());
+            }
         }
 
         // Add default TreeSlot if there is not TreeSlot in config
@@ -74,7 +349,10 @@ impl View {
                 .iter()
                 .map(|x| {
                     if x.kind == ConfigColumnKind::TreeSlot {
-                        tree_slot.clone()
+                        ConfigColumn {
+                            group: x.group.clone(),
+                            ..tree_slot.clone()
+                        }
                     } else {
                         x.clone()
                     }
@@ -82,7 +360,14 @@ impl View {
                 .collect()
         };
 
+        let mut groups: Vec<&String> = Vec::new();
         for c in &config_columns {
+            let group = c.group.as_ref().map(|x| {
+                groups.iter().position(|y| *y == x).unwrap_or_else(|| {
+                    groups.push(x);
+                    groups.len() - 1
+                })
+            });
             let kinds = match &c.kind {
                 ConfigColumnKind::Slot => {
                     let kinds = if let Some(insert) = opt.insert.get(slot_idx) {
@@ -115,7 +400,7 @@ impl View {
 
             for kind in kinds {
                 let visible = if let Some(only) = &opt.only {
-                    let kind_name = KIND_LIST[&kind].0.to_lowercase();
+                    let kind_name = column_kind_name(&kind).to_lowercase();
                     if !kind_name.contains(&only.to_lowercase()) {
                         false
                     } else {
@@ -128,11 +413,18 @@ impl View {
 
                 let column = gen_column(
                     &kind,
-                    c.header.clone(),
+                    c.header.clone().or_else(|| alias_header(config, &kind)),
+                    c.unit_suffix,
                     &config.docker.path,
                     &config.display.separator,
                     config.display.abbr_sid,
+                    config.display.abbr_path,
+                    config.display.abbr_cgroup,
                     &config.display.tree_symbols,
+                    config.display.map_count_warn,
+                    &config.history,
+                    opt.smaps || config.display.smaps,
+                    &config.display.start_time_format,
                 );
                 if column.available() {
                     columns.push(ColumnInfo {
@@ -145,6 +437,11 @@ impl View {
                         max_width: c.max_width,
                         min_width: c.min_width,
                         visible,
+                        refresh: c.refresh.map(Duration::from_secs),
+                        refreshed: Instant::now(),
+                        sort_empty: c.sort_empty.clone(),
+                        aggregate: c.aggregate.clone(),
+                        group,
                     });
                 }
             }
@@ -168,17 +465,37 @@ impl View {
             config.display.show_thread
         };
 
-        let proc = collect_proc(
-            Duration::from_millis(opt.interval),
-            show_thread,
-            config.display.show_kthreads,
-        );
-        for c in columns.iter_mut() {
-            for p in &proc {
-                c.column.add(p);
+        // Columns of the next view sample wakeups again
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        crate::process::finish_wakeup_sample();
+
+        let interval = Duration::from_millis(opt.interval);
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let mut proc = if let Some(path) = &opt.from_dir {
+            let source = CapturedProcfs::new(path, interval);
+            collect_proc_from(&source, show_thread, config.display.show_kthreads)
+        } else {
+            collect_proc(interval, show_thread, config.display.show_kthreads)
+        };
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        let mut proc = collect_proc(interval, show_thread, config.display.show_kthreads);
+
+        // Use the nominal interval instead of the measured one to suppress jitter of throughput
+        if opt.deterministic {
+            for p in proc.iter_mut() {
+                p.interval = interval;
             }
         }
 
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if let Some(path) = &opt.save_baseline {
+            crate::baseline::save(path, &proc)?;
+        }
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let gone = View::gone(&proc);
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        let gone = Vec::new();
+
         let mut parent_pids = HashMap::new();
         let mut child_pids = HashMap::<i32, Vec<i32>>::new();
         if opt.tree || !config.display.show_self_parents {
@@ -198,16 +515,202 @@ impl View {
         if opt.only.is_some() {
             sort_info.idx = 0;
         }
+        View::separate_groups(&mut columns, &mut sort_info, opt, config);
 
-        Ok(View {
+        let view = View {
             columns,
             term_info,
             sort_info,
             visible_pids: vec![],
             auxiliary_pids: vec![],
+            hidden_rows: 0,
+            show_aggregate: opt.aggregate || config.display.show_aggregate,
+            aggregates: vec![],
+            gone,
+            fixed_width: opt.deterministic,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            follow: None,
             parent_pids,
             child_pids,
-        })
+            pids: proc.iter().map(|x| x.pid).collect(),
+        };
+        Ok((view, proc))
+    }
+
+    /// Rows can be rendered in the order of collection if any sort and pager are not requested.
+    pub fn streamable(&self, opt: &Opt, config: &Config) -> bool {
+        let use_builtin_pager = cfg!(target_os = "windows") || config.pager.use_builtin;
+        // External pager is not spawned if stdout is not a terminal
+        let no_pager = opt.deterministic
+            || matches!(
+                (opt.pager.as_ref(), &config.pager.mode),
+                (Some(ArgPagerMode::Disable), _) | (None, ConfigPagerMode::Disable)
+            )
+            || (!console::user_attended() && !use_builtin_pager);
+
+        // Processes are collected in ascending order of PID
+        let sorted_by_pid = self.columns[self.sort_info.idx].kind == ConfigColumnKind::Pid
+            && matches!(self.sort_info.order, ConfigSortOrder::Ascending);
+
+        !opt.watch_mode
+            && !opt.tree
+            && opt.sorta.is_none()
+            && opt.sortd.is_none()
+            && no_pager
+            && sorted_by_pid
+            && config.display.max_rows == 0
+            && !self.show_aggregate
+            && self.columns.iter().all(|c| c.column.streamable())
+    }
+
+    /// Adds processes chunk by chunk, and renders each chunk before the next is added.
+    ///
+    /// All processes are collected before this, so only contents of columns are bounded by the chunk.
+    ///
+    /// Widths of columns are decided by the first chunk, and only grow by later chunks,
+    /// so a row can be misaligned with previous rows if it has a longer content.
+    pub fn display_stream(
+        &mut self,
+        opt: &Opt,
+        config: &Config,
+        theme: &ConfigTheme,
+        proc: Vec<ProcessInfo>,
+    ) -> Result<(), Error> {
+        self.setup_output(opt, config, false);
+
+        let mut proc = proc.into_iter();
+        let mut first = true;
+        let mut row = String::new();
+        loop {
+            let chunk: Vec<_> = proc.by_ref().take(STREAM_CHUNK).collect();
+            if chunk.is_empty() && !first {
+                break;
+            }
+
+            for c in self.columns.iter_mut() {
+                for p in &chunk {
+                    c.column.add(p);
+                }
+            }
+            self.filter(opt, config);
+
+            let fixed_widths = self.fixed_widths();
+            for (i, c) in self.columns.iter_mut().enumerate() {
+                if first {
+                    let order = if i == self.sort_info.idx {
+                        Some(self.sort_info.order.clone())
+                    } else {
+                        None
+                    };
+                    match fixed_widths[i] {
+                        Some(x) => c.column.reset_width(order, config, None, Some(x)),
+                        None => c
+                            .column
+                            .reset_width(order, config, c.max_width, c.min_width),
+                    }
+                }
+                if fixed_widths[i].is_some() {
+                    continue;
+                }
+                for pid in &self.visible_pids {
+                    c.column.update_width(*pid, c.max_width);
+                }
+            }
+
+            if first && !opt.no_header && config.display.show_header {
+                let _ = self.display_header(config, theme);
+                let _ = self.display_unit(config, theme);
+            }
+            for pid in &self.visible_pids {
+                let _ = self.display_content(&mut row, config, *pid, theme, false);
+            }
+            let _ = self.term_info.flush();
+
+            for c in self.columns.iter_mut() {
+                c.column.clear();
+            }
+            first = false;
+        }
+
+        if !opt.no_header && config.display.show_footer {
+            let _ = self.display_unit(config, theme);
+            let _ = self.display_header(config, theme);
+        }
+        let _ = self.term_info.flush();
+
+        Ok(())
+    }
+
+    /// Expands columns so that contents of visible processes are not truncated.
+    ///
+    /// This is for consumers of contents other than the terminal ( ex. `--serve` ).
+    pub fn fit_width(&mut self, config: &Config) {
+        for c in &mut self.columns {
+            c.column.apply_visible(&self.visible_pids);
+            c.column.reset_width(None, config, None, None);
+            for pid in &self.visible_pids {
+                c.column.update_width(*pid, None);
+            }
+        }
+    }
+
+    /// Finds the column by the kind name ( or its alias ), the header, or a part of the kind name in this order.
+    pub fn find_column(&self, config: &Config, name: &str) -> Option<&ColumnInfo> {
+        let name = resolve_alias(config, name).to_lowercase();
+        let columns = || self.columns.iter().filter(|c| c.visible);
+        columns()
+            .find(|c| column_kind_name(&c.kind).to_lowercase() == name)
+            .or_else(|| {
+                columns().find(|c| {
+                    c.column
+                        .display_header(&ConfigColumnAlign::Left, None, config)
+                        .trim()
+                        .to_lowercase()
+                        == name
+                })
+            })
+            .or_else(|| {
+                columns().find(|c| column_kind_name(&c.kind).to_lowercase().contains(&name))
+            })
+    }
+
+    /// Returns contents of visible columns of the process as `{"pid": .., "columns": {<kind>: <content>}}`.
+    ///
+    /// Ages of contents which couldn't be refreshed are added as `"stale": {<kind>: <seconds>}`.
+    /// `fit_width` should be called before it so that contents are not truncated.
+    pub fn row_json(&self, pid: i32) -> Value {
+        let mut columns = Map::new();
+        let mut stale = Map::new();
+        for c in &self.columns {
+            if !c.visible
+                || matches!(
+                    c.kind,
+                    ConfigColumnKind::Empty
+                        | ConfigColumnKind::Separator
+                        | ConfigColumnKind::Tree
+                        | ConfigColumnKind::TreeSlot
+                )
+            {
+                continue;
+            }
+            if let Some(content) = c.column.display_content(pid, &ConfigColumnAlign::Left) {
+                columns.insert(
+                    column_kind_name(&c.kind).into_owned(),
+                    Value::String(content.trim_end().to_string()),
+                );
+            }
+            if let Some(x) = c.column.stale_since(pid) {
+                stale.insert(
+                    column_kind_name(&c.kind).into_owned(),
+                    json!(x.elapsed().as_secs()),
+                );
+            }
+        }
+        if stale.is_empty() {
+            json!({"pid": pid, "columns": columns})
+        } else {
+            json!({"pid": pid, "columns": columns, "stale": stale})
+        }
     }
 
     pub fn filter(&mut self, opt: &Opt, config: &Config) {
@@ -232,28 +735,41 @@ impl View {
             }
         }
 
-        let pids = self.columns[self.sort_info.idx]
+        let sort_column = &self.columns[self.sort_info.idx];
+        let tie_break = self.tie_break_rank(config);
+        let mut pids = sort_column
             .column
-            .sorted_pid(&self.sort_info.order);
-
-        let self_pid = std::process::id() as i32;
-
-        let self_parents = if !config.display.show_self_parents {
-            let mut self_parents = Vec::new();
-            self.get_parent_pids(self_pid, &mut self_parents);
-            self_parents
-                .into_iter()
-                .filter(|x| {
-                    if let Some(x) = self.child_pids.get(x) {
-                        x.len() == 1
-                    } else {
-                        false
-                    }
-                })
-                .collect()
-        } else {
-            Vec::new()
-        };
+            .sorted_pid(&self.sort_info.order, tie_break.as_ref());
+        // Empty cells are matched with an empty keyword, and the sort is stable
+        match sort_column.sort_empty {
+            ConfigSortEmpty::First => {
+                pids.sort_by_key(|x| !sort_column.column.find_exact(*x, "", false))
+            }
+            ConfigSortEmpty::Last => {
+                pids.sort_by_key(|x| sort_column.column.find_exact(*x, "", false))
+            }
+            ConfigSortEmpty::Value => (),
+        }
+
+        let self_pid = self_pid();
+
+        let self_parents =
+            if let (false, Some(self_pid)) = (config.display.show_self_parents, self_pid) {
+                let mut self_parents = Vec::new();
+                self.get_parent_pids(self_pid, &mut self_parents);
+                self_parents
+                    .into_iter()
+                    .filter(|x| {
+                        if let Some(x) = self.child_pids.get(x) {
+                            x.len() == 1
+                        } else {
+                            false
+                        }
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
 
         let logic = if opt.and {
             ConfigSearchLogic::And
@@ -267,13 +783,29 @@ impl View {
             config.search.logic.clone()
         };
 
+        let audit = self
+            .columns
+            .iter()
+            .find(|c| opt.audit && column_kind_name(&c.kind) == "Audit");
+
         let mut candidate_pids = Vec::new();
         for pid in &pids {
-            let hidden_process = (!config.display.show_self && *pid == self_pid)
-                || (!config.display.show_self_parents && self_parents.contains(pid));
+            // Reused contents in watch mode may have exited processes
+            if !self.pids.contains(pid) {
+                continue;
+            }
 
-            let candidate = if hidden_process {
+            let hidden_process = (!config.display.show_self && Some(*pid) == self_pid)
+                || (!config.display.show_self_parents && self_parents.contains(pid))
+                || opt.pid_list.as_ref().is_some_and(|x| !x.contains(pid));
+
+            // Contents can't be displayed before `adjust`, so processes without findings are matched with empty
+            let flagged = audit.map_or(true, |c| !c.column.find_exact(*pid, "", false));
+
+            let candidate = if hidden_process || !flagged {
                 false
+            } else if let Some(followed) = self.followed(*pid) {
+                followed
             } else if opt.keyword.is_empty() {
                 true
             } else {
@@ -320,14 +852,32 @@ impl View {
             if candidate_pids.contains(pid) {
                 visible_pids.push(*pid);
             }
-
-            if opt.watch_mode && visible_pids.len() >= self.term_info.height - 5 {
-                break;
-            }
         }
 
         self.visible_pids = visible_pids;
         self.auxiliary_pids = auxiliary_pids;
+        self.hidden_rows = 0;
+    }
+
+    /// Limits rendered processes by `max_rows` of `[display]` and the terminal height in watch mode.
+    ///
+    /// Sorted processes are cut from the bottom, and the number of them is shown by the footer of `display`.
+    pub fn limit_rows(&mut self, opt: &Opt, config: &Config) {
+        let mut max_rows = match config.display.max_rows {
+            0 => usize::MAX,
+            x => x,
+        };
+        if opt.watch_mode {
+            // Header of watch mode, header/unit line, the aggregate row, rows of `--follow` and next prompt
+            let reserved = 5 + usize::from(self.show_aggregate) + self.follow_notes(opt).len();
+            let height = self.term_info.height.saturating_sub(reserved);
+            if self.visible_pids.len() > height {
+                // One line is left for the footer
+                max_rows = max_rows.min(height.saturating_sub(1));
+            }
+        }
+        self.hidden_rows = self.visible_pids.len().saturating_sub(max_rows);
+        self.visible_pids.truncate(max_rows);
     }
 
     fn get_parent_pids(&self, pid: i32, parent_pids: &mut Vec<i32>) {
@@ -351,6 +901,7 @@ impl View {
     }
 
     pub fn adjust(&mut self, config: &Config, min_widths: &HashMap<usize, usize>) {
+        let fixed_widths = self.fixed_widths();
         for (i, ref mut c) in self.columns.iter_mut().enumerate() {
             let order = if i == self.sort_info.idx {
                 Some(self.sort_info.order.clone())
@@ -358,12 +909,93 @@ impl View {
                 None
             };
             c.column.apply_visible(&self.visible_pids);
+            if let Some(width) = fixed_widths[i] {
+                c.column.reset_width(order, config, None, Some(width));
+                continue;
+            }
             let min_width = min_widths.get(&i).map(|x| Some(*x)).unwrap_or(c.min_width);
             c.column.reset_width(order, config, c.max_width, min_width);
             for pid in &self.visible_pids {
                 c.column.update_width(*pid, c.max_width);
             }
         }
+
+        if !self.show_aggregate {
+            return;
+        }
+        self.aggregates = self.columns.iter().map(|c| self.aggregate(c)).collect();
+        for (i, c) in self.columns.iter_mut().enumerate() {
+            let width = match &self.aggregates[i] {
+                Some(x) if fixed_widths[i].is_none() => UnicodeWidthStr::width(x.as_str()),
+                _ => continue,
+            };
+            if width > c.column.get_width() {
+                let order = (i == self.sort_info.idx).then(|| self.sort_info.order.clone());
+                c.column
+                    .reset_width(order, config, c.max_width, Some(width));
+                for pid in &self.visible_pids {
+                    c.column.update_width(*pid, c.max_width);
+                }
+            }
+        }
+    }
+
+    /// Widths of columns fixed by `--deterministic`, so that outputs of different runs can be compared line by line.
+    ///
+    /// The last visible column is not fixed because it doesn't shift other columns, and separators and the tree are not
+    /// fixed because they don't depend on values of processes.
+    fn fixed_widths(&self) -> Vec<Option<usize>> {
+        let last = self.columns.iter().rposition(|c| c.visible);
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let fixed = self.fixed_width
+                    && Some(i) != last
+                    && !matches!(c.kind, ConfigColumnKind::Separator | ConfigColumnKind::Tree);
+                fixed.then(|| c.max_width.or(c.min_width).unwrap_or(FIXED_WIDTH))
+            })
+            .collect()
+    }
+
+    /// Sums or averages raw values of the column over the visible processes.
+    ///
+    /// The aggregate is formatted in the same way as contents ( ex. `1.500G` for byte sizes, `01:02:03` for times ),
+    /// and processes without values are skipped.
+    fn aggregate(&self, c: &ColumnInfo) -> Option<String> {
+        let average = match c.aggregate {
+            ConfigAggregate::Auto => {
+                if c.column
+                    .display_unit(&ConfigColumnAlign::Left)
+                    .trim()
+                    .is_empty()
+                    || c.kind == ConfigColumnKind::Separator
+                {
+                    return None;
+                }
+                false
+            }
+            ConfigAggregate::Sum => false,
+            ConfigAggregate::Average => true,
+            ConfigAggregate::Disable => return None,
+        };
+
+        let values: Vec<f64> = self
+            .visible_pids
+            .iter()
+            .filter_map(|pid| c.column.raw_value(*pid))
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+
+        let sum: f64 = values.iter().sum();
+        let value = if average {
+            sum / values.len() as f64
+        } else {
+            sum
+        };
+        Some(c.column.value_format().format(value))
     }
 
     pub fn display(
@@ -372,10 +1004,18 @@ impl View {
         config: &Config,
         theme: &ConfigTheme,
     ) -> Result<(), Error> {
-        let use_terminal = console::user_attended();
-
         // +3 means header/unit line and next prompt
-        let pager_threshold_height = self.visible_pids.len() + 3;
+        let power = if opt.power && !opt.watch_mode {
+            get_power_info().summary()
+        } else {
+            None
+        };
+        let pager_threshold_height = self.visible_pids.len()
+            + usize::from(self.hidden_rows > 0)
+            + usize::from(power.is_some())
+            + usize::from(self.show_aggregate)
+            + self.gone.len()
+            + 3;
 
         // "self.columns.len() - 1" means spacing between columns
         let pager_threshold_width = if config.pager.detect_width {
@@ -389,14 +1029,9 @@ impl View {
             usize::MIN
         };
 
-        let use_builtin_pager = if cfg!(target_os = "windows") {
-            true
-        } else {
-            config.pager.use_builtin
-        };
-
         let use_pager = match (opt.watch_mode, opt.pager.as_ref(), &config.pager.mode) {
             (true, _, _) => false,
+            (false, _, _) if opt.deterministic => false,
             (false, Some(ArgPagerMode::Auto), _) => {
                 self.term_info.height < pager_threshold_height
                     || self.term_info.width < pager_threshold_width
@@ -411,6 +1046,75 @@ impl View {
             (false, None, ConfigPagerMode::Disable) => false,
         };
 
+        self.setup_output(opt, config, use_pager);
+
+        if !opt.no_header && config.display.show_header {
+            // Ignore display_* error
+            //   `Broken pipe` may occur at pager mode. It can be ignored safely.
+            let _ = self.display_header(config, theme);
+            let _ = self.display_unit(config, theme);
+        }
+
+        let auxiliary_pids: HashSet<&i32> = self.auxiliary_pids.iter().collect();
+        let mut row = String::new();
+        for pid in &self.visible_pids {
+            let auxiliary = auxiliary_pids.contains(pid);
+            let _ = self.display_content(&mut row, config, *pid, theme, auxiliary);
+        }
+        if self.show_aggregate && !self.visible_pids.is_empty() {
+            let _ = self.display_aggregate(config, theme);
+        }
+        if self.hidden_rows > 0 {
+            let _ = self.write_row(&format!(
+                "… and {} more matching processes",
+                self.hidden_rows
+            ));
+        }
+        for note in self.follow_notes(opt) {
+            let _ = self.write_row(&note);
+        }
+        // The header of watch mode shows it instead
+        if let Some(power) = power {
+            let _ = self.write_row(&format!("Power: {power}"));
+        }
+        // Gone processes can't be searched by columns, so commands are matched with keywords
+        if !opt.watch_mode {
+            for (pid, command) in &self.gone {
+                let command_lower = command.to_lowercase();
+                let matched = opt.keyword.is_empty()
+                    || opt
+                        .keyword
+                        .iter()
+                        .any(|x| command_lower.contains(&x.to_lowercase()));
+                if matched {
+                    let _ = self.write_row(&format!("gone since the baseline: {pid} {command}"));
+                }
+            }
+        }
+
+        if !opt.no_header && config.display.show_footer {
+            let _ = self.display_unit(config, theme);
+            let _ = self.display_header(config, theme);
+        }
+
+        let _ = self.term_info.flush();
+
+        if self.term_info.use_pager {
+            minus::page_all(self.term_info.pager.replace(None).unwrap())?;
+        }
+
+        Ok(())
+    }
+
+    fn setup_output(&mut self, opt: &Opt, config: &Config, use_pager: bool) {
+        let use_terminal = console::user_attended();
+
+        let use_builtin_pager = if cfg!(target_os = "windows") {
+            true
+        } else {
+            config.pager.use_builtin
+        };
+
         // Minus doesn't support horizontal scroll yet
         // https://github.com/arijit79/minus/issues/59
         let cut_to_pager = if use_builtin_pager {
@@ -422,6 +1126,7 @@ impl View {
         let mut truncate = use_terminal && use_pager && cut_to_pager;
         truncate |= use_terminal && !use_pager && config.display.cut_to_terminal;
         truncate |= !use_terminal && config.display.cut_to_pipe;
+        truncate &= !opt.deterministic;
 
         if !truncate {
             self.term_info.width = usize::MAX;
@@ -451,29 +1156,6 @@ impl View {
                 View::pager(config);
             }
         }
-
-        if !opt.no_header && config.display.show_header {
-            // Ignore display_* error
-            //   `Broken pipe` may occur at pager mode. It can be ignored safely.
-            let _ = self.display_header(config, theme);
-            let _ = self.display_unit(config, theme);
-        }
-
-        for pid in &self.visible_pids {
-            let auxiliary = self.auxiliary_pids.contains(pid);
-            let _ = self.display_content(config, *pid, theme, auxiliary);
-        }
-
-        if !opt.no_header && config.display.show_footer {
-            let _ = self.display_unit(config, theme);
-            let _ = self.display_header(config, theme);
-        }
-
-        if self.term_info.use_pager {
-            minus::page_all(self.term_info.pager.replace(None).unwrap())?;
-        }
-
-        Ok(())
     }
 
     fn display_header(&self, config: &Config, theme: &ConfigTheme) -> Result<(), Error> {
@@ -485,31 +1167,28 @@ impl View {
                 } else {
                     None
                 };
-                row = format!(
-                    "{} {}",
+                let _ = write!(
                     row,
+                    " {}",
                     apply_color(
                         c.column.display_header(&c.align, order, config),
-                        &config.style.header,
+                        View::header_color(c, config),
                         theme,
                         false
                     )
                 );
             }
         }
-        row = row.trim_end().to_string();
-        row = truncate(&row, self.term_info.width).to_string();
-        self.term_info.write_line(&row)?;
-        Ok(())
+        self.write_row(&row)
     }
 
     fn display_unit(&self, config: &Config, theme: &ConfigTheme) -> Result<(), Error> {
         let mut row = String::new();
         for c in &self.columns {
             if c.visible {
-                row = format!(
-                    "{} {}",
+                let _ = write!(
                     row,
+                    " {}",
                     apply_color(
                         c.column.display_unit(&c.align),
                         &config.style.unit,
@@ -519,61 +1198,207 @@ impl View {
                 );
             }
         }
-        row = row.trim_end().to_string();
-        row = truncate(&row, self.term_info.width).to_string();
-        self.term_info.write_line(&row)?;
-        Ok(())
+        self.write_row(&row)
     }
 
+    /// Renders aggregates with the header style, and `Total` in the first column without aggregate.
+    fn display_aggregate(&self, config: &Config, theme: &ConfigTheme) -> Result<(), Error> {
+        let mut row = String::new();
+        let mut label = Some("Total");
+        for (c, aggregate) in self.columns.iter().zip(&self.aggregates) {
+            if c.visible {
+                let content = match aggregate {
+                    Some(x) => x.as_str(),
+                    None if c.kind == ConfigColumnKind::Separator => &config.display.separator,
+                    None => label.take().unwrap_or_default(),
+                };
+                let width = c.column.get_width();
+                let _ = write!(
+                    row,
+                    " {}",
+                    apply_color(
+                        adjust(content, width, &c.align),
+                        &config.style.header,
+                        theme,
+                        false
+                    )
+                );
+            }
+        }
+        self.write_row(&row)
+    }
+
+    /// Renders a row into `row`, which is reused between rows to avoid allocation.
     fn display_content(
         &self,
+        row: &mut String,
         config: &Config,
         pid: i32,
         theme: &ConfigTheme,
         auxiliary: bool,
     ) -> Result<(), Error> {
-        let mut row = String::new();
+        let deleted = self
+            .deleted_exe_column()
+            .is_some_and(|c| c.column.find_exact(pid, "deleted", false));
+        row.clear();
         for c in &self.columns {
             if c.visible {
-                row = format!(
-                    "{} {}",
-                    row,
-                    apply_style(
-                        c.column.display_content(pid, &c.align).unwrap(),
-                        &c.style,
-                        &config.style,
-                        theme,
-                        auxiliary
-                    )
-                );
+                let content = c.column.display_content(pid, &c.align).unwrap();
+                let content = if deleted && c.kind == ConfigColumnKind::Command {
+                    apply_color(content, &config.style.deleted_exe, theme, auxiliary)
+                } else {
+                    apply_style(content, &c.style, &config.style, theme, auxiliary)
+                };
+                // The previous sample is shown if the cell couldn't be refreshed
+                let content = if c.column.stale_since(pid).is_some() {
+                    content.dim()
+                } else {
+                    content
+                };
+                let _ = write!(row, " {content}");
             }
         }
-        row = row.trim_end().to_string();
-        row = truncate(&row, self.term_info.width).to_string();
-        self.term_info.write_line(&row)?;
-        Ok(())
+        self.write_row(row)
+    }
+
+    /// Processes running a deleted executable are highlighted only if `ExeDeleted` column is in the columns.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn deleted_exe_column(&self) -> Option<&ColumnInfo> {
+        self.columns
+            .iter()
+            .find(|c| c.kind == ConfigColumnKind::ExeDeleted)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn deleted_exe_column(&self) -> Option<&ColumnInfo> {
+        None
+    }
+
+    fn write_row(&self, row: &str) -> Result<(), Error> {
+        let row = truncate(row.trim_end(), self.term_info.width);
+        self.term_info.write_line(row)
+    }
+
+    /// Ranks processes by `tie_break` column of `[sort]` in ascending order, or returns `None` to break ties by PID.
+    ///
+    /// The column must be in the columns ( it can be hidden ), otherwise it is ignored.
+    fn tie_break_rank(&self, config: &Config) -> Option<HashMap<i32, usize>> {
+        let name = resolve_alias(config, &config.sort.tie_break).to_lowercase();
+        let column = self
+            .columns
+            .iter()
+            .find(|c| column_kind_name(&c.kind).to_lowercase() == name)?;
+        if column.kind == ConfigColumnKind::Pid {
+            return None;
+        }
+        let pids = column.column.sorted_pid(&ConfigSortOrder::Ascending, None);
+        Some(pids.into_iter().enumerate().map(|(i, x)| (x, i)).collect())
+    }
+
+    /// Processes in the baseline which are not matched with any current process.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn gone(proc: &[ProcessInfo]) -> Vec<(i32, String)> {
+        let baseline = if let Some(x) = crate::baseline::get() {
+            x
+        } else {
+            return Vec::new();
+        };
+        let boot_time = crate::baseline::boot_time_secs();
+        let samples: Vec<_> = proc
+            .iter()
+            .filter(|x| matches!(x.curr_proc, crate::process::ProcessTask::Process { .. }))
+            .map(|x| baseline.sample(x, boot_time))
+            .collect();
+        baseline
+            .compare(&samples)
+            .gone
+            .into_iter()
+            .map(|x| (x.pid, x.command.clone()))
+            .collect()
+    }
+
+    /// Inserts separators between adjacent columns of different groups unless they are separated already.
+    ///
+    /// The index of the sort column is shifted because it points a column in the configuration.
+    fn separate_groups(
+        columns: &mut Vec<ColumnInfo>,
+        sort_info: &mut SortInfo,
+        opt: &Opt,
+        config: &Config,
+    ) {
+        let style = config
+            .columns
+            .iter()
+            .find(|x| x.kind == ConfigColumnKind::Separator)
+            .map(|x| x.style.clone())
+            .unwrap_or_else(|| color_to_column_style(&config.style.header));
+        let mut i = 1;
+        while i < columns.len() {
+            let (prev, next) = (&columns[i - 1], &columns[i]);
+            let boundary = matches!((prev.group, next.group), (Some(x), Some(y)) if x != y)
+                && prev.kind != ConfigColumnKind::Separator
+                && next.kind != ConfigColumnKind::Separator;
+            if boundary {
+                let separator = ColumnInfo {
+                    column: Box::new(Separator::new(&config.display.separator)),
+                    kind: ConfigColumnKind::Separator,
+                    style: style.clone(),
+                    nonnumeric_search: false,
+                    numeric_search: false,
+                    align: ConfigColumnAlign::Left,
+                    max_width: None,
+                    min_width: None,
+                    visible: opt.only.is_none(),
+                    refresh: None,
+                    refreshed: Instant::now(),
+                    sort_empty: ConfigSortEmpty::Value,
+                    aggregate: ConfigAggregate::Disable,
+                    group: None,
+                };
+                columns.insert(i, separator);
+                if sort_info.idx >= i {
+                    sort_info.idx += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+        }
+    }
+
+    /// Header color of the column, which is distinct by the group if the column is grouped.
+    fn header_color<'a>(c: &ColumnInfo, config: &'a Config) -> &'a ConfigColorByTheme {
+        let colors = &config.style.group_headers;
+        match c.group {
+            Some(x) if !colors.is_empty() => &colors[x % colors.len()],
+            _ => &config.style.header,
+        }
     }
 
     fn get_sort_info(opt: &Opt, config: &Config, cols: &[ColumnInfo]) -> SortInfo {
         let (mut sort_idx, sort_order) = match (&opt.sorta, &opt.sortd) {
             (Some(sort), _) | (_, Some(sort)) => {
-                let mut idx = config.sort.column;
-                let mut order = config.sort.order.clone();
-                for (i, c) in cols.iter().enumerate() {
-                    let (kind, _) = KIND_LIST[&c.kind];
-                    if kind.to_lowercase().contains(&sort.to_lowercase()) {
-                        idx = i;
-                        order = if opt.sorta.is_some() {
-                            ConfigSortOrder::Ascending
-                        } else {
-                            ConfigSortOrder::Descending
-                        };
-                        break;
-                    }
+                let sort = sort.to_lowercase();
+                let kinds: Vec<_> = cols
+                    .iter()
+                    .map(|c| column_kind_name(&c.kind).to_lowercase())
+                    .collect();
+                // strict search at first ( ex. "OomScore" shouldn't match "OomScoreAdj" )
+                let found = kinds
+                    .iter()
+                    .position(|x| *x == sort)
+                    .or_else(|| kinds.iter().position(|x| x.contains(&sort)));
+                if let Some(idx) = found {
+                    let order = if opt.sorta.is_some() {
+                        ConfigSortOrder::Ascending
+                    } else {
+                        ConfigSortOrder::Descending
+                    };
+                    (idx, order)
+                } else {
+                    (config.sort.column.unwrap_or(0), config.sort.order.clone())
                 }
-                (idx, order)
             }
-            _ => (config.sort.column, config.sort.order.clone()),
+            _ => (config.sort.column.unwrap_or(0), config.sort.order.clone()),
         };
 
         if opt.tree {
@@ -1,33 +1,162 @@
+use crate::alert::Alerter;
 use crate::config::*;
+#[cfg(feature = "otel")]
+use crate::otel::OtelExporter;
+use crate::power::{get_power_info, PowerInfo};
+use crate::statsd::StatsdEmitter;
 use crate::term_info::TermInfo;
+use crate::thermal::{get_thermal_info, ThermalInfo};
 use crate::util::get_theme;
 use crate::view::View;
 use crate::Opt;
 use anyhow::Error;
 use chrono::offset::Local;
 use getch::Getch;
+use std::cmp;
 use std::collections::HashMap;
+#[cfg(not(target_os = "windows"))]
+use std::io::Write;
+#[cfg(not(target_os = "windows"))]
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Mutex;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+/// Held while rendering, so signals don't restore the terminal in the middle of a frame
+static RENDER: Mutex<()> = Mutex::new(());
+
+/// Set while the theme is detected, so the response of the terminal is not read as key input
+#[cfg(not(target_os = "windows"))]
+static PAUSE_INPUT: AtomicBool = AtomicBool::new(false);
+
+/// Upper bound of the interval of `--watch-adaptive` as a multiple of the given interval
+const ADAPTIVE_MAX_FACTOR: u64 = 16;
 
 enum Command {
     Wake,
-    Sleep,
+    Sleep(u64),
     Next,
     Prev,
     Ascending,
     Descending,
+    Resume,
     Quit,
 }
 
 pub struct Watcher;
 
+/// Terminal attributes of stdin
+#[cfg(not(target_os = "windows"))]
+#[derive(Clone, Copy)]
+struct TermMode(libc::termios);
+
+#[cfg(not(target_os = "windows"))]
+impl TermMode {
+    fn save() -> Option<Self> {
+        let mut termios = unsafe { std::mem::zeroed() };
+        if unsafe { libc::tcgetattr(0, &mut termios) } == 0 {
+            Some(TermMode(termios))
+        } else {
+            None
+        }
+    }
+
+    fn apply(&self) {
+        unsafe {
+            libc::tcsetattr(0, libc::TCSADRAIN, &self.0);
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[derive(Clone, Copy)]
+struct TermMode;
+
+#[cfg(target_os = "windows")]
+impl TermMode {
+    fn save() -> Option<Self> {
+        None
+    }
+
+    fn apply(&self) {}
+}
+
+/// Switches to the alternate screen and hides the cursor
+fn enter_screen() {
+    #[cfg(not(target_os = "windows"))]
+    {
+        let mut stdout = std::io::stdout();
+        let _ = stdout.write_all(b"\x1b[?1049h\x1b[?25l");
+        let _ = stdout.flush();
+    }
+}
+
+fn leave_screen(mode: &Option<TermMode>) {
+    #[cfg(not(target_os = "windows"))]
+    {
+        let mut stdout = std::io::stdout();
+        let _ = stdout.write_all(b"\x1b[?25h\x1b[?1049l");
+        let _ = stdout.flush();
+    }
+    if let Some(mode) = mode {
+        mode.apply();
+    }
+}
+
+/// Restores the terminal even if watch mode is finished by an error
+struct Screen(Option<TermMode>);
+
+impl Screen {
+    fn enter(mode: Option<TermMode>) -> Self {
+        enter_screen();
+        Screen(mode)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _render = RENDER.lock().unwrap_or_else(|x| x.into_inner());
+        leave_screen(&self.0);
+    }
+}
+
+/// Doubles the interval if a refresh takes more than a quarter of it, and halves it toward the base
+/// interval if a refresh takes less than a sixteenth of it, so that procs doesn't add much load to a busy system.
+fn adapt_interval(base: u64, current: u64, elapsed: u64) -> u64 {
+    if elapsed * 4 > current {
+        cmp::min(current * 2, base * ADAPTIVE_MAX_FACTOR)
+    } else if elapsed * 16 < current {
+        cmp::max(current / 2, base)
+    } else {
+        current
+    }
+}
+
+fn run_on_exit(opt: &Opt) {
+    if let Some(cmd) = &opt.on_exit {
+        #[cfg(not(target_os = "windows"))]
+        let status = std::process::Command::new("sh").arg("-c").arg(cmd).status();
+        #[cfg(target_os = "windows")]
+        let status = std::process::Command::new("cmd")
+            .arg("/C")
+            .arg(cmd)
+            .status();
+        if let Err(x) = status {
+            eprintln!("failed to execute on-exit command: {x}");
+        }
+    }
+}
+
 impl Watcher {
     fn spawn_cmd(tx: Sender<Command>) {
         let _ = thread::spawn(move || {
             let getch = Getch::new();
             loop {
+                #[cfg(not(target_os = "windows"))]
+                if !Watcher::wait_input() {
+                    continue;
+                }
                 match getch.getch() {
                     Ok(x) if char::from(x) == 'q' => {
                         let _ = tx.send(Command::Quit);
@@ -57,28 +186,121 @@ impl Watcher {
         });
     }
 
-    fn spawn_sleep(rx: Receiver<Command>, tx: Sender<Command>, interval: u64) {
+    fn detect_theme(opt: &Opt, config: &Config) -> ConfigTheme {
+        #[cfg(not(target_os = "windows"))]
+        PAUSE_INPUT.store(true, Ordering::SeqCst);
+        let theme = get_theme(opt, config);
+        #[cfg(not(target_os = "windows"))]
+        PAUSE_INPUT.store(false, Ordering::SeqCst);
+        theme
+    }
+
+    /// Waits until stdin is readable and input is not paused
+    #[cfg(not(target_os = "windows"))]
+    fn wait_input() -> bool {
+        if PAUSE_INPUT.load(Ordering::SeqCst) {
+            thread::sleep(Duration::from_millis(50));
+            return false;
+        }
+        let mut fds = libc::pollfd {
+            fd: 0,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        unsafe { libc::poll(&mut fds, 1, 100) > 0 }
+    }
+
+    /// Handles SIGINT, SIGTERM and SIGTSTP in a dedicated thread instead of signal handlers,
+    /// so the terminal can be restored and the on-exit command can be executed safely.
+    ///
+    /// This must be called before spawning other threads, because they inherit the signal mask.
+    #[cfg(not(target_os = "windows"))]
+    fn spawn_signal(mode: Option<TermMode>, opt: Opt, tx: Sender<Command>) {
+        let mut set: libc::sigset_t = unsafe { std::mem::zeroed() };
+        unsafe {
+            libc::sigemptyset(&mut set);
+            libc::sigaddset(&mut set, libc::SIGINT);
+            libc::sigaddset(&mut set, libc::SIGTERM);
+            libc::sigaddset(&mut set, libc::SIGTSTP);
+            libc::pthread_sigmask(libc::SIG_BLOCK, &set, std::ptr::null_mut());
+        }
+
         let _ = thread::spawn(move || loop {
-            if let Ok(Command::Quit) = rx.recv() {
-                break;
+            let mut sig = 0;
+            if unsafe { libc::sigwait(&set, &mut sig) } != 0 {
+                continue;
+            }
+            let _render = RENDER.lock().unwrap_or_else(|x| x.into_inner());
+            if sig == libc::SIGTSTP {
+                let current = TermMode::save();
+                leave_screen(&mode);
+                unsafe {
+                    libc::kill(libc::getpid(), libc::SIGSTOP);
+                }
+                // Resumed by SIGCONT
+                if let Some(current) = current {
+                    current.apply();
+                }
+                enter_screen();
+                let _ = console::Term::stdout().clear_screen();
+                let _ = tx.send(Command::Resume);
+            } else {
+                leave_screen(&mode);
+                run_on_exit(&opt);
+                std::process::exit(128 + sig);
+            }
+        });
+    }
+
+    fn spawn_sleep(rx: Receiver<Command>, tx: Sender<Command>) {
+        let _ = thread::spawn(move || {
+            while let Ok(Command::Sleep(interval)) = rx.recv() {
+                thread::sleep(Duration::from_millis(interval));
+                let _ = tx.send(Command::Wake);
             }
-            thread::sleep(Duration::from_millis(interval));
-            let _ = tx.send(Command::Wake);
         });
     }
 
-    fn display_header(term_info: &TermInfo, opt: &Opt, interval: u64) -> Result<(), Error> {
+    fn display_header(
+        term_info: &TermInfo,
+        opt: &Opt,
+        interval: u64,
+        thermal: &ThermalInfo,
+        throttled: Option<bool>,
+        power: &PowerInfo,
+    ) -> Result<(), Error> {
+        let interval = if opt.watch_adaptive {
+            format!("{interval}ms ( adaptive )")
+        } else {
+            format!("{interval}ms")
+        };
+        let mut context = String::new();
+        if let Some(temp) = thermal.temp {
+            context.push_str(&format!(", CPU Temp: {temp:.1}°C"));
+        }
+        if let Some(throttled) = throttled {
+            context.push_str(&format!(
+                ", Throttling: {}",
+                if throttled { "yes" } else { "no" }
+            ));
+        }
+        if let Some(power) = power.summary() {
+            context.push_str(&format!(", Power: {power}"));
+        }
+
         let header = if opt.tree {
             format!(
-                " Interval: {}ms, Last Updated: {} ( Quit: q or Ctrl-C )",
+                " Interval: {}, Last Updated: {}{} ( Quit: q or Ctrl-C )",
                 interval,
                 Local::now().format("%Y/%m/%d %H:%M:%S"),
+                context,
             )
         } else {
             format!(
-                " Interval: {}ms, Last Updated: {} ( Next: n, Prev: p, Ascending: a, Descending: d, Quit: q or Ctrl-C )",
+                " Interval: {}, Last Updated: {}{} ( Next: n, Prev: p, Ascending: a, Descending: d, Quit: q or Ctrl-C )",
                 interval,
                 Local::now().format("%Y/%m/%d %H:%M:%S"),
+                context,
             )
         };
         term_info.write_line(&format!(
@@ -90,16 +312,37 @@ impl Watcher {
         Ok(())
     }
 
-    pub fn start(opt: &mut Opt, config: &Config, interval: u64) -> Result<(), Error> {
-        let theme = get_theme(opt, config);
-
+    pub fn start(opt: &mut Opt, config: &Config, base_interval: u64) -> Result<(), Error> {
+        let mode = TermMode::save();
         let (tx_cmd, rx_cmd) = channel();
+        #[cfg(not(target_os = "windows"))]
+        Watcher::spawn_signal(mode, opt.clone(), tx_cmd.clone());
+
+        #[cfg(feature = "otel")]
+        let exporter = match opt.otel_endpoint.clone() {
+            Some(x) => Some(OtelExporter::new(&x, opt, config)?),
+            None => None,
+        };
+
+        let mut alerter = if config.alerts.is_empty() {
+            None
+        } else {
+            Some(Alerter::new(config)?)
+        };
+        let emitter = match opt.statsd.clone() {
+            Some(x) => Some(StatsdEmitter::new(&x, opt, config)?),
+            None => None,
+        };
+
+        let mut theme = get_theme(opt, config);
+
         Watcher::spawn_cmd(tx_cmd.clone());
 
         let (tx_sleep, rx_sleep) = channel();
-        Watcher::spawn_sleep(rx_sleep, tx_cmd, interval);
+        Watcher::spawn_sleep(rx_sleep, tx_cmd);
 
         let term_info = TermInfo::new(false, false)?;
+        let screen = Screen::enter(mode);
         term_info.clear_screen()?;
 
         let mut sort_idx = None;
@@ -107,8 +350,22 @@ impl Watcher {
         let mut min_widths = HashMap::new();
         let mut prev_term_width = 0;
         let mut prev_term_height = 0;
+        let mut prev_thermal = get_thermal_info();
+        let mut prev_view = None;
+        let mut sleeping = false;
+        let mut resumed = false;
+        let mut interval = base_interval;
         'outer: loop {
-            let mut view = View::new(opt, config, true)?;
+            // The terminal may be changed while procs is stopped by job control
+            if resumed {
+                theme = Watcher::detect_theme(opt, config);
+                prev_term_width = 0;
+                resumed = false;
+            }
+
+            let render = RENDER.lock().unwrap_or_else(|x| x.into_inner());
+            let started = Instant::now();
+            let mut view = View::renew(opt, config, prev_view.take())?;
 
             // Override sort_info by key
             if !opt.tree {
@@ -116,7 +373,18 @@ impl Watcher {
                 view.sort_info.order = sort_order.clone().unwrap_or(view.sort_info.order);
             }
 
+            if let Some(alerter) = &mut alerter {
+                alerter.evaluate(&mut view, opt, config)?;
+            }
             view.filter(opt, config);
+            view.limit_rows(opt, config);
+            #[cfg(feature = "otel")]
+            if let Some(exporter) = &exporter {
+                exporter.export(&mut view, config);
+            }
+            if let Some(emitter) = &emitter {
+                emitter.emit(&mut view, config);
+            }
             view.adjust(config, &min_widths);
             for (i, c) in view.columns.iter().enumerate() {
                 min_widths.insert(i, c.column.get_width());
@@ -127,14 +395,39 @@ impl Watcher {
             if resized {
                 term_info.clear_screen()?;
             }
-            Watcher::display_header(&view.term_info, opt, interval)?;
+            let thermal = get_thermal_info();
+            let throttled = match (prev_thermal.throttle_count, thermal.throttle_count) {
+                (Some(prev), Some(curr)) => Some(curr > prev),
+                _ => thermal.throttling,
+            };
+            Watcher::display_header(
+                &view.term_info,
+                opt,
+                interval,
+                &thermal,
+                throttled,
+                &get_power_info(),
+            )?;
+            prev_thermal = thermal;
 
             view.display(opt, config, &theme)?;
 
             view.term_info.clear_rest_lines()?;
             view.term_info.move_cursor_to(0, 0)?;
+            drop(render);
+
+            if opt.watch_adaptive {
+                // The sleep between two samples by --interval is not a cost of the update
+                let elapsed = started.elapsed().as_millis() as u64;
+                let elapsed = elapsed.saturating_sub(opt.interval);
+                interval = adapt_interval(base_interval, interval, elapsed);
+            }
 
-            tx_sleep.send(Command::Sleep)?;
+            // Key inputs cause redraw without waiting, so sleep is requested only if not in progress
+            if !sleeping {
+                tx_sleep.send(Command::Sleep(interval))?;
+                sleeping = true;
+            }
             let mut cmds = Vec::new();
             if let Ok(cmd) = rx_cmd.recv() {
                 cmds.push(cmd);
@@ -154,13 +447,41 @@ impl Watcher {
                     Command::Prev => sort_idx = Some(view.dec_sort_column()),
                     Command::Ascending => sort_order = Some(ConfigSortOrder::Ascending),
                     Command::Descending => sort_order = Some(ConfigSortOrder::Descending),
+                    Command::Wake => sleeping = false,
+                    Command::Resume => resumed = true,
                     _ => (),
                 }
             }
 
             prev_term_width = view.term_info.width;
             prev_term_height = view.term_info.height;
+            prev_view = Some(view);
+        }
+
+        drop(screen);
+        if let Some(x) = alerter.and_then(|x| x.last_error()) {
+            eprintln!("Warning: {x}");
         }
+        #[cfg(feature = "otel")]
+        if let Some(x) = exporter.and_then(|x| x.last_error()) {
+            eprintln!("Warning: {x}");
+        }
+        run_on_exit(opt);
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adapt_interval() {
+        assert_eq!(adapt_interval(1000, 1000, 100), 1000);
+        assert_eq!(adapt_interval(1000, 1000, 300), 2000);
+        assert_eq!(adapt_interval(1000, 2000, 300), 2000);
+        assert_eq!(adapt_interval(1000, 16000, 10000), 16000);
+        assert_eq!(adapt_interval(1000, 4000, 100), 2000);
+        assert_eq!(adapt_interval(1000, 2000, 50), 1000);
+    }
+}
@@ -0,0 +1,370 @@
+//! Runs procs against the captured procfs in `tests/fixtures`.
+//!
+//! The fixture has `prev` and `curr` snapshots taken at 1 second, and `etc` to resolve user names.
+#![cfg(any(target_os = "linux", target_os = "android"))]
+
+use std::process::{Command, Output};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+fn procs_with_config(config: &[&str], args: &[&str]) -> Output {
+    let fixture = format!("{FIXTURES}/proc");
+    Command::new(env!("CARGO_BIN_EXE_procs"))
+        .args(["--from-dir", &fixture, "--interval", "1000"])
+        .args(["--color", "disable", "--pager", "disable"])
+        .args(config)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn procs(args: &[&str]) -> Output {
+    procs_with_config(&["--use-config", "default"], args)
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = procs(args);
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Returns PIDs of rows, which are the first field of each line
+fn pids(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .skip(2)
+        .filter_map(|x| x.split_whitespace().next())
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn test_snapshots() {
+    // 300 exited, 400 started after the first snapshot, and 500 is reused by another process
+    assert_eq!(pids(&stdout(&[])), ["1", "2", "100", "200"]);
+    assert_eq!(
+        pids(&stdout(&["--thread"])),
+        ["1", "2", "100", "200", "[201]"]
+    );
+}
+
+#[test]
+fn test_columns() {
+    let out = stdout(&["--insert", "ReadRate", "--insert", "VmRss"]);
+    let worker = out.lines().find(|x| x.starts_with(" 200")).unwrap();
+    let fields: Vec<_> = worker.split_whitespace().collect();
+    assert_eq!(
+        fields,
+        [
+            "200",
+            "alice",
+            "│",
+            "pts/0",
+            "150.0",
+            "2.5",
+            "00:00:13",
+            "1.000M",
+            "195.312M",
+            "│",
+            "python3",
+            "worker.py",
+            "--jobs",
+            "2"
+        ]
+    );
+}
+
+#[test]
+fn test_search() {
+    assert_eq!(pids(&stdout(&["worker"])), ["200"]);
+    assert_eq!(pids(&stdout(&["alice"])), ["100", "200"]);
+    assert_eq!(pids(&stdout(&["--sortd", "cpu", "alice"])), ["200", "100"]);
+}
+
+#[test]
+fn test_tree() {
+    // Children follow their parent, and the deepest one is indented most
+    let out = stdout(&["--tree"]);
+    let lines: Vec<_> = ["/sbin/init", "-bash", "worker.py", "[kthreadd]"]
+        .iter()
+        .map(|x| out.lines().position(|l| l.contains(x)).unwrap())
+        .collect();
+    assert!(lines.windows(2).all(|x| x[0] < x[1]), "{out}");
+    let indent = |x| {
+        let line = out.lines().nth(x).unwrap();
+        line.chars().take_while(|c| *c != '─').count()
+    };
+    assert!(indent(lines[0]) < indent(lines[1]), "{out}");
+    assert!(indent(lines[1]) < indent(lines[2]), "{out}");
+}
+
+#[test]
+fn test_check() {
+    let output = procs(&[
+        "--check",
+        "UsageCpu(worker) == 150",
+        "--check",
+        "exists:bash",
+    ]);
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+
+    // Compared with the exact value instead of the rounded one ( 195.312M )
+    let output = procs(&["--insert", "VmRss", "--check", "VmRss(worker) == 200000K"]);
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+
+    let output = procs(&["--insert", "State", "--check", "State(worker) == 1"]);
+    assert_eq!(output.status.code(), Some(3), "{output:?}");
+
+    let output = procs(&["--check", "count(alice) > 2"]);
+    assert_eq!(output.status.code(), Some(2), "{output:?}");
+    let line = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        line.starts_with("PROCS CRITICAL - count(alice) = 2"),
+        "{line}"
+    );
+}
+
+#[test]
+fn test_sort_empty() {
+    // Processes without TTY are sorted by the empty string by default
+    assert_eq!(pids(&stdout(&["--sorta", "tty"])), ["1", "2", "100", "200"]);
+
+    let config = format!("{FIXTURES}/sort_empty.toml");
+    for sort in ["--sorta", "--sortd"] {
+        let output = procs_with_config(&["--load-config", &config], &[sort, "tty"]);
+        let out = String::from_utf8(output.stdout).unwrap();
+        assert_eq!(pids(&out), ["100", "200", "1", "2"], "{out}");
+    }
+}
+
+#[test]
+fn test_elapsed_time() {
+    // Elapsed time is as of the capture, so the newest process is sorted at first
+    let out = stdout(&[
+        "--insert",
+        "ElapsedTime",
+        "--insert",
+        "Elapsed",
+        "--sorta",
+        "Elapsed",
+    ]);
+    assert_eq!(pids(&out)[..2], ["200", "100"]);
+    let worker = out.lines().find(|x| x.starts_with(" 200")).unwrap();
+    assert!(worker.contains("2.8hours"), "{out}");
+    assert!(worker.contains("02:45:40"), "{out}");
+}
+
+#[test]
+fn test_usage_user_sys() {
+    // The worker spends 1s in user mode and 0.5s in kernel mode during the interval
+    let out = stdout(&[
+        "--insert",
+        "UsageUser",
+        "--insert",
+        "UsageSys",
+        "--insert",
+        "CpuTimeUser",
+        "--insert",
+        "CpuTimeSys",
+    ]);
+    let worker = out.lines().find(|x| x.starts_with(" 200")).unwrap();
+    assert!(
+        worker.contains(" 100.0    50.0  00:00:11 00:00:02 "),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_share() {
+    // Shares are relative to the matched processes
+    let out = stdout(&["--insert", "CpuShare", "--insert", "MemShare", "alice"]);
+    let worker = out.lines().find(|x| x.starts_with(" 200")).unwrap();
+    assert!(
+        worker.contains(" 100.0 ") && worker.contains(" 98.0 "),
+        "{out}"
+    );
+
+    let out = stdout(&["--insert", "CpuShare"]);
+    let worker = out.lines().find(|x| x.starts_with(" 200")).unwrap();
+    assert!(worker.contains(" 75.0 "), "{out}");
+}
+
+#[test]
+fn test_core_dump() {
+    // Dumpable state can't be told from a copied /proc tree, so only the limit is meaningful
+    let out = stdout(&["--insert", "CoreDump"]);
+    let worker = out.lines().find(|x| x.starts_with(" 200")).unwrap();
+    assert!(worker.contains(" yes unlimited "), "{out}");
+}
+
+#[test]
+fn test_pids() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    assert_eq!(pids(&stdout(&["--pids", "200,1"])), ["1", "200"]);
+
+    // PIDs from stdin are combined with keywords
+    let fixture = format!("{FIXTURES}/proc");
+    let mut child = Command::new(env!("CARGO_BIN_EXE_procs"))
+        .args(["--from-dir", &fixture, "--use-config", "default"])
+        .args(["--color", "disable", "--pager", "disable"])
+        .args(["--pids", "-", "root"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"1\n200\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(pids(&String::from_utf8(output.stdout).unwrap()), ["1"]);
+
+    assert!(!procs(&["--pids", "1,init"]).status.success());
+}
+
+#[test]
+fn test_parent_command() {
+    let out = stdout(&["--insert", "ParentCommand"]);
+    let worker = out.lines().find(|x| x.starts_with(" 200")).unwrap();
+    assert!(worker.contains(" -bash "), "{out}");
+    // The parent of init is not a process, so the column is empty
+    let init = out.lines().find(|x| x.starts_with(" 1 ")).unwrap();
+    assert!(
+        init.split('│').nth(1).unwrap().trim().ends_with("00:00:02"),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_groups() {
+    // Separators are inserted between groups, but not beside the configured separator
+    let config = format!("{FIXTURES}/groups.toml");
+    let output = procs_with_config(&["--load-config", &config], &[]);
+    let out = String::from_utf8(output.stdout).unwrap();
+    let header: Vec<_> = out.lines().next().unwrap().split_whitespace().collect();
+    assert_eq!(
+        header,
+        ["PID:▲", "User", "│", "CPU", "CPU", "Time", "│", "VmRSS", "│", "Command"],
+        "{out}"
+    );
+}
+
+#[test]
+fn test_baseline() {
+    // Fields between separators of the row, where the last three are columns of the baseline
+    fn fields<'a>(out: &'a str, pid: &str) -> Vec<&'a str> {
+        let row = out.lines().find(|x| x.starts_with(pid)).unwrap();
+        row.split('│').nth(1).unwrap().split_whitespace().collect()
+    }
+
+    // The baseline has the previous instance of init, the worker with less memory, and a gone nginx
+    let baseline = format!("{FIXTURES}/baseline.tsv");
+    let out = stdout(&["--baseline", &baseline]);
+    assert!(
+        fields(&out, " 1 ").ends_with(&["restarted", "-1000.000K", "-00:00:01"]),
+        "{out}"
+    );
+    assert!(
+        fields(&out, " 100 ").ends_with(&["00:00:00", "new"]),
+        "{out}"
+    );
+    assert!(
+        fields(&out, " 200 ").ends_with(&["00:00:13", "+97.656M", "+00:00:03"]),
+        "{out}"
+    );
+    assert!(out.contains("gone since the baseline: 300 nginx: worker process"));
+
+    // Gone processes are matched with keywords by the command
+    let out = stdout(&["--baseline", &baseline, "alice"]);
+    assert!(!out.contains("gone since the baseline"), "{out}");
+
+    // A saved baseline has no difference
+    let path = std::env::temp_dir().join(format!("procs-baseline-{}.tsv", std::process::id()));
+    let path = path.to_str().unwrap();
+    stdout(&["--save-baseline", path]);
+    let out = stdout(&["--baseline", path]);
+    std::fs::remove_file(path).unwrap();
+    assert!(
+        fields(&out, " 200 ").ends_with(&["00:00:13", "0", "00:00:00"]),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_tie_break() {
+    // Ties are sorted by PID by default
+    assert_eq!(
+        pids(&stdout(&["--sorta", "user"])),
+        ["100", "200", "1", "2"]
+    );
+
+    // The running worker is sorted before the sleeping shell by State
+    let config = format!("{FIXTURES}/tie_break.toml");
+    let output = procs_with_config(&["--load-config", &config], &["--sorta", "user"]);
+    let out = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(pids(&out), ["200", "100", "1", "2"], "{out}");
+}
+
+#[test]
+fn test_sort_kind() {
+    // "Pid" is inserted though "Ppid" in the configuration contains it
+    let config = format!("{FIXTURES}/sort_kind.toml");
+    let output = procs_with_config(&["--load-config", &config], &["--sortd", "pid"]);
+    let out = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(pids(&out), ["200", "100", "2", "1"], "{out}");
+}
+
+#[test]
+fn test_max_rows() {
+    let config = format!("{FIXTURES}/max_rows.toml");
+    let output = procs_with_config(&["--load-config", &config], &["--sortd", "pid"]);
+    let out = String::from_utf8(output.stdout).unwrap();
+    let rows: Vec<_> = out.lines().skip(2).map(str::trim).collect();
+    assert_eq!(rows.len(), 3, "{out}");
+    assert!(rows[0].starts_with("200"), "{out}");
+    assert!(rows[1].starts_with("100"), "{out}");
+    assert_eq!(rows[2], "… and 2 more matching processes");
+
+    // The footer isn't shown if all processes fit
+    let output = procs_with_config(&["--load-config", &config], &["alice"]);
+    let out = String::from_utf8(output.stdout).unwrap();
+    assert!(!out.contains("more matching processes"), "{out}");
+}
+
+#[test]
+fn test_aggregate() {
+    let out = stdout(&["--aggregate", "--insert", "VmRss", "alice"]);
+    let total = out
+        .lines()
+        .find(|x| x.trim_start().starts_with("Total"))
+        .unwrap();
+    let fields: Vec<_> = total.split_whitespace().collect();
+    // CPU Time has no unit, so it's not aggregated by default, and MEM is summed without rounding of each cell
+    assert_eq!(
+        fields,
+        ["Total", "│", "150.0", "2.5", "199.219M", "│"],
+        "{out}"
+    );
+
+    let config = format!("{FIXTURES}/aggregate.toml");
+    let output = procs_with_config(&["--load-config", &config], &["--aggregate"]);
+    let out = String::from_utf8(output.stdout).unwrap();
+    let total = out
+        .lines()
+        .find(|x| x.trim_start().starts_with("Total"))
+        .unwrap();
+    let fields: Vec<_> = total.split_whitespace().collect();
+    assert_eq!(fields, ["Total", "00:00:15", "51.758M"], "{out}");
+}
+
+#[test]
+fn test_deterministic() {
+    // Widths of columns don't depend on contents, so rows of the same process are the same
+    let all = stdout(&["--deterministic"]);
+    let one = stdout(&["--deterministic", "--pids", "1"]);
+    assert_eq!(all.lines().next(), one.lines().next(), "{all}\n{one}");
+    assert!(
+        all.lines().any(|x| Some(x) == one.lines().nth(2)),
+        "{all}\n{one}"
+    );
+}